@@ -0,0 +1,502 @@
+# The English text of the game, which every other locale falls back to.
+#
+# Each line gives the text for one key, as `key = text`. A `{ $name }` in the text is replaced with
+# the value of the same name, such as an amount of chips or a total.
+
+## Cards
+
+rank-ace = Ace
+rank-two = Two
+rank-three = Three
+rank-four = Four
+rank-five = Five
+rank-six = Six
+rank-seven = Seven
+rank-eight = Eight
+rank-nine = Nine
+rank-ten = Ten
+rank-jack = Jack
+rank-queen = Queen
+rank-king = King
+suit-spades = Spades
+suit-hearts = Hearts
+suit-diamonds = Diamonds
+suit-clubs = Clubs
+card-name = { $rank } of { $suit }
+face-down-card = a face-down card
+
+## Totals, read aloud
+
+number-0 = zero
+number-1 = one
+number-2 = two
+number-3 = three
+number-4 = four
+number-5 = five
+number-6 = six
+number-7 = seven
+number-8 = eight
+number-9 = nine
+number-10 = ten
+number-11 = eleven
+number-12 = twelve
+number-13 = thirteen
+number-14 = fourteen
+number-15 = fifteen
+number-16 = sixteen
+number-17 = seventeen
+number-18 = eighteen
+number-19 = nineteen
+number-20 = twenty
+number-21 = twenty-one
+number-22 = twenty-two
+number-23 = twenty-three
+number-24 = twenty-four
+number-25 = twenty-five
+number-26 = twenty-six
+number-27 = twenty-seven
+number-28 = twenty-eight
+number-29 = twenty-nine
+number-30 = thirty
+number-31 = thirty-one
+soft-total = soft { $total }
+
+## Choices at a decision, and the actions shown when a strategy plays
+
+choice-hit = hit
+choice-stand = stand
+choice-double = double
+choice-split = split
+choice-surrender = surrender
+choice-advice = advice
+choice-count = count
+choice-quit = quit
+choice-for-free = { $choice } for free
+action-bet = Bet({ $amount })
+action-hit = Hit
+action-stand = Stand
+action-double = Double
+action-split = Split
+action-surrender = Surrender
+action-switch = Switch
+action-keep-hands = Keep hands
+action-insure = Insure
+action-decline-insurance = No insurance
+action-continue = Continue
+action-sit-out = SitOut({ $others })
+
+## Betting
+
+bet-prompt = How many chips do you want to bet? (table limits: { $minimum } to { $maximum })
+quick-bets = Quick bets: (m)ax, presets { $presets }
+quick-bets-repeat = Quick bets: (r)epeat { $bet }, (m)ax, presets { $presets }
+presets-updated = Presets updated.
+presets-invalid = Please list the preset amounts as whole numbers, e.g. 'presets 10 25 100'.
+no-previous-bet = There is no previous bet to repeat.
+invalid-amount = Invalid amount, please enter a whole number of chips.
+invalid-bet = Invalid amount, please enter a whole number of chips or a quick bet.
+bankroll = Your bankroll: { $amount } ({ $chips })
+advice = Running count: { $running }, true count: { $true } ({ $decks } decks left). { $advisor } suggests { $bet }.
+advice-aces = Aces left: { $aces } ({ $surplus } against an even share), so bet from a true count of { $betting }.
+strategy-bets = { $name } bets { $bet }.
+progression-capped = { $name } wants to bet { $wanted }, but the table and your bankroll allow { $bet }.
+side-bet-prompt = How many chips on { $name }? (0 to skip)
+only-left = You only have { $amount } left.
+bet-below-minimum = The table minimum is { $chips } chips.
+bet-above-maximum = The table maximum is { $chips } chips.
+bet-insufficient-chips = You only have { $chips } chips to cover that bet.
+
+## Playing the hands
+
+you = You
+shuffling = Shuffling the shoe...
+hand-label = Hand { $hand } of { $hands }
+hand-shown = Hand:
+your-total = Your total: { $total }
+cards-switched = Cards switched.
+opponent-plays = { $name } plays: { $cards } (total: { $total })
+strategy-chooses = { $name } chooses { $action }.
+leaving-stand = You stand, as you are leaving the table.
+charlie-cards = Charlie! { $cards } cards without busting.
+player-busts = You bust! Your total is over 21.
+player-surrenders = You surrender, giving up half your bet.
+free-double = Free double! The house covers the extra wager.
+free-split = Free split! The house covers the wager on the new hand.
+dealer-peeks = The dealer peeks at the hole card and has blackjack!
+dealer-hits = Dealer hits...
+dealer-total = Dealer's total: { $total }
+decide-prompt = Do you want to { $choices }?
+decide-prompt-timed = Do you want to { $choices }? ({ $seconds } seconds left)
+time-up = Time is up, so you stand.
+invalid-choice = Invalid choice, please choose one of the options shown.
+quit-prompt = Quit now? Your hands stand and the round is settled before you leave. (y/n)
+quit-invalid = Invalid choice, please choose 'y' to quit or 'n' to keep playing.
+count-overlay = Running count { $running }, true count { $true }, { $decks } decks left
+count-hidden = The count is hidden.
+count-aces = { $aces } aces left ({ $surplus } against an even share)
+advice-play = Basic strategy says: { $action }
+hints = Hints:
+hint-bust-if-hit = Chance of busting if you hit: { $chance }
+hint-dealer = Dealer's likeliest totals: { $totals }
+hint-total = { $total } ({ $chance })
+hint-bust = bust
+hint-play = Basic strategy: { $action }
+chatter = The dealer says: "{ $line }"
+switch-prompt = Do you want to switch the second cards? (y/n)
+switch-invalid = Invalid choice, please choose 'y' to switch or 'n' to keep your hands.
+insurance-prompt = The dealer shows an Ace. Do you want insurance for half your bet? (y/n)
+insurance-invalid = Invalid choice, please choose 'y' to take insurance or 'n' to turn it down.
+
+## Results
+
+result-charlie = Charlie! You win.
+result-blackjack = Blackjack! You win, paid { $payout }.
+result-bonus = Bonus! You win, paid { $payout }.
+result-dealer-busts = Dealer busts! You win.
+result-win = You win!
+result-bust = You bust! Dealer wins.
+result-lose-tie = Dealer wins the tie.
+result-lose = Dealer wins.
+result-push-totals = Dealer has { $total }, it's a push.
+result-push = It's a tie!
+result-surrendered = You surrendered.
+outcome-won = won
+outcome-push = push
+outcome-lost = lost
+outcome-surrendered = surrendered
+hand-result = { $result } (bet: { $bet }, net: { $net })
+hand-result-labelled = { $label }: { $result } (bet: { $bet }, net: { $net })
+side-bet-wins = { $name }: { $combination } wins, paid { $payout } (net: { $net }).
+side-bet-loses = { $name }: loses (net: { $net }).
+combination-any-20 = Any 20
+combination-suited-20 = Suited 20
+combination-matched-20 = Matched 20
+combination-queen-of-hearts-pair = Queen of Hearts pair
+combination-queen-of-hearts-pair-with-dealer-blackjack = Queen of Hearts pair with dealer blackjack
+combination-suited = Suited
+combination-royal-match = Royal Match
+insurance-wins = Insurance: the dealer has blackjack, paid { $payout } (net: { $net }).
+insurance-loses = Insurance: the dealer has no blackjack (net: { $net }).
+opponent-result = { $name }: net { $net }, now has { $amount }.
+
+## Between rounds
+
+play-again = Do you want to play again? (y/n, or (s)ave & quit)
+save-prompt = Do you want to save your game to carry it on later? (y/n)
+game-over = ==================== GAME OVER ====================
+cannot-cover = You have { $amount }, which cannot cover the table minimum of { $minimum }.
+rounds-played = Rounds played: { $rounds }
+hands-record = Hands won / lost / pushed: { $won } / { $lost } / { $pushed }
+blackjacks = Blackjacks: { $count }
+peak-bankroll = Peak bankroll: { $amount } ({ $chips })
+rebuys = Rebuys: { $count }
+rebuy-prompt = Rebuy for { $amount }? (y/n)
+rebuy-invalid = Invalid choice, please choose 'y' to rebuy or 'n' to leave the table.
+session-summary = ================= SESSION SUMMARY =================
+bought-in = Bought in: { $amount } ({ $rebuys } rebuys)
+cashed-out = Cashed out: { $amount }
+net-result = Net result: { $amount }
+total-wagered = Total wagered: { $amount }
+return-to-player = Return to player: { $percentage }%
+return-to-player-none = Return to player: no bets placed
+hands-played = Hands played: { $hands }
+biggest-win = Biggest win: { $amount }
+biggest-loss = Biggest loss: { $amount }
+strategy-accuracy = Basic strategy plays: { $correct } of { $decisions } ({ $percentage }%)
+strategy-by-hand = By hand — { $groups }
+strategy-by-up-card = By dealer up card — { $groups }
+strategy-group = { $name }: { $correct }/{ $decisions } ({ $percentage }%)
+hand-kind-hard = hard
+hand-kind-soft = soft
+hand-kind-pairs = pairs
+time-played = Time played: { $minutes } min
+summary-end = ===================================================
+
+## Tournaments
+
+tournament-round = === Tournament round { $round } of { $rounds }: { $hands } hands, top { $advancing } advance ===
+tournament-sit-out = You can no longer cover the minimum bet and sit out the rest of the round.
+tournament-hand = --- Hand { $hand } of { $hands } ---
+tournament-leave = You leave the tournament.
+standings = Standings after round { $round }:
+standing = { $place }. { $name } - { $amount } ({ $status })
+standing-advances = advances
+standing-eliminated = eliminated
+tournament-eliminated = You finished in place { $place } and have been eliminated.
+tournament-won = You won the tournament!
+tournament-finished = You finished the tournament in place { $place }.
+tournament-advance = You advance to round { $round }!
+
+## The demo
+
+demo-start = Demo: a bot plays by basic strategy. Press any key to play yourself.
+demo-commentary = The bot has { $hand } against the dealer's { $up }: one more card busts it { $bust } of the time, and the dealer's likeliest total is { $total } ({ $chance }).
+demo-over = Your turn!
+
+## The table
+
+table-dealer = Dealer
+table-players = Players
+table-you = You
+table-you-hand = You, hand { $hand }
+table-you-hand-of = You, hand { $hand } of { $hands }
+table-showing = showing { $total }
+table-hand-detail = { $total }, bet { $bet }
+table-shoe = Bankroll: { $bankroll }, shoe: { $remaining } of { $size } cards left
+table-status = Shoe [{ $gauge }] { $remaining }/{ $size } | Discards { $discards } | Bankroll { $bankroll }
+table-trend = Bankroll trend { $trend }
+spoken-dealer-shows = Dealer shows { $cards }.
+spoken-dealer-shows-total = Dealer shows { $cards }, { $total }.
+spoken-dealer-hand = Dealer's hand: { $cards }, { $total }.
+spoken-your-hand = Your hand
+spoken-your-hand-of = Your hand { $hand } of { $hands }
+spoken-opponent-hand = { $name }'s hand
+spoken-hand = { $owner }: { $cards }, { $total }, bet { $bet }.
+spoken-bankroll = Your bankroll is { $bankroll }, and { $remaining } of { $size } cards are left in the shoe.
+status-line = You: { $you } | Dealer: { $dealer } | Bank: { $bankroll } | Bet: { $bet }
+status-count = RC { $running } TC { $true }
+status-aces = A { $aces }
+
+## Errors
+
+error-io = could not read input or write output: { $error }
+error-invalid-card = '{ $text }' is not a valid card, rank or suit
+error-invalid-action = '{ $text }' is not a valid action
+error-input-exhausted = the scripted input has no choices left
+error-too-many-seats = a table seats at most { $max } other players, not { $others }
+error-empty-deck = the deck is empty
+error-rules = the table rules cannot be played: { $error }
+error-snapshot = could not save or restore the game: { $error }
+error-unsupported-version = the file is in format version { $found }, but only versions up to { $supported } can be read
+error-unknown-variant = there is no variant called '{ $name }'
+error-undo-unavailable = decisions can only be undone in practice mode
+error-nothing-to-undo = there is no decision to undo
+error-engine-stopped = the game engine has stopped
+error-illegal-action = { $action } is not allowed right now, choose from: { $legal }
+error-no-actions = nothing
+
+## The main menu and profiles
+
+main-menu = Main menu: (1) Play, (2) Trainer, (3) Simulate, (4) Statistics, (5) Settings, (q)uit
+main-menu-invalid = Invalid choice, please enter a number from 1 to 5 or 'q'.
+bot-prompt = Choose a bot: (b)asic strategy, (c)ounting, (d)ealer mimic
+bot-invalid = Invalid choice, please choose 'b', 'c' or 'd'.
+mode-prompt = Choose a mode: (c)ash game, (t)ournament or (d)aily challenge
+mode-invalid = Invalid choice, please choose 'c', 't' or 'd'.
+config-unreadable = Could not read the config file: { $error }
+game-stopped = The game had to stop: { $error }
+profile-prompt = Choose a profile: { $profiles }, or (n)ew profile
+profile-invalid = Invalid choice, please enter a number from 1 to { $count } or 'n'.
+profile-name-prompt = Enter a name for your new profile:
+profile-name-invalid = Please use only letters, digits, '-' and '_' (up to 32 characters).
+profile-name-taken = Please use only letters, digits, '-' and '_' (up to 32 characters), and a name not already taken.
+profile-name-refused = '{ $name }' cannot be a profile name: use only letters, digits, '-' and '_' (up to 32 characters).
+welcome-back = Welcome back, { $name }! Lifetime: { $lifetime }.
+welcome = Welcome, { $name }!
+no-saved-game = There is no saved game for { $name }.
+saved-game-kept = You have a saved game, which is kept until you carry it on with --resume.
+playing-at = Playing { $variant } at a { $limits } table.
+saved-game-resumed = Carrying on your saved game with { $amount }.
+game-saved = Your game is saved. Carry it on with --resume.
+graded-sessions = Over every graded session:
+profile-unsaved = Could not save your profile: { $error }
+
+## Statistics
+
+lifetime = { $rounds } rounds, { $won } won, { $lost } lost, { $pushed } pushed, { $blackjacks } blackjacks
+profile-stats = { $name }: { $bankroll }, lifetime { $lifetime }.
+no-profiles = There are no profiles yet.
+no-profile = There is no profile named '{ $name }'.
+
+## Table settings
+
+number-invalid = Invalid choice, please enter a number from 1 to { $count }.
+game-prompt = Choose a game: (1) Classic, (2) Blackjack Switch, (3) Double Exposure, (4) Free Bet, (5) Super Fun 21
+lucky-ladies-prompt = Offer the Lucky Ladies side bet? (n)o, (c)lassic 1000/200/25/10/4, (r)educed 1000/125/19/9/4
+lucky-ladies-invalid = Invalid choice, please choose 'n', 'c' or 'r'.
+royal-match-prompt = Offer the Royal Match side bet? (n)o, (c)lassic 25:1/5:2, (e)asy match 10:1/3:1
+royal-match-invalid = Invalid choice, please choose 'n', 'c' or 'e'.
+table-prompt = Choose a table: { $tables }
+progression-prompt = Bet automatically with a progression? (n)o, (m)artingale, (p)aroli, (1)-3-2-6
+progression-invalid = Invalid choice, please choose 'n', 'm', 'p' or '1'.
+advisor-prompt = Show a count-based bet advisor? (n)o, (r)amp, (k)elly
+advisor-invalid = Invalid choice, please choose 'n', 'r' or 'k'.
+ramp-prompt = Enter the units to bet at true counts 0, 1, 2, ... (Enter for 1 1 2 4 6 8):
+ramp-invalid = Please enter whole numbers of units, e.g. '1 2 4 8'.
+kelly-prompt = Enter the Kelly fraction to bet, between 0 and 1 (Enter for 0.5):
+kelly-invalid = Please enter a number between 0 and 1, e.g. '0.25'.
+currency-prompt = Choose a currency format: (1) $1,250, (2) $1,250.00, (3) 1.250,00 €, (4) £1,250
+viewer-prompt = Choose how cards are drawn: (g)lyph A of ♠, (a)lpha Ace of Spades, a(s)cii A of S, (p)lain AS, (b)ox art, (u)nicode 🂡, (w)ords for screen readers, (f)itted to the terminal (Enter for { $default })
+viewer-invalid = Invalid choice, please choose 'g', 'a', 's', 'p', 'b', 'u', 'w' or 'f'.
+
+## The settings editor
+
+settings-menu = Settings: (1) rules: { $rules }, (2) viewer: { $viewer }, (3) starting bankroll: { $bankroll }, (4) colour-blind suits: { $colorblind }, (5) card back: { $back }; choose one to change, or (d)one
+settings-invalid = Invalid choice, please choose '1', '2', '3', '4', '5' or 'd'.
+setting-on = on
+setting-off = off
+setting-theme-back = the theme's
+setting-rules = rules
+setting-viewer = viewer
+setting-card-back = card back
+setting-choose = Choose the { $setting }: { $options } (Enter to keep it)
+setting-bankroll-prompt = Enter the chips a new profile starts with (Enter to keep it):
+setting-bankroll-invalid = Please enter a whole number of chips greater than 0.
+settings-saved = Saved to { $path }.
+config-exists = There is already a config file at { $path }; pass --force to replace it.
+config-written = Wrote a starter config file to { $path }.
+
+## The trainer
+
+trainer-start = Basic strategy trainer: choose how to play each hand, or enter 'q' to stop.
+trainer-hand = Dealer: { $dealer }, you: { $hand } ({ $total })
+trainer-correct = Correct.
+trainer-expected = Basic strategy says: { $action }.
+trainer-round = Dealer finishes with { $total }. Score: { $correct } of { $decisions }.
+trainer-score = You made { $correct } of { $decisions } plays correctly.
+play-prompt = Do you (h)it, (s)tand, (d)ouble, s(p)lit or su(r)render?
+play-invalid = Invalid choice, please choose 'h', 's', 'd', 'p', 'r' or 'q'.
+play-not-allowed = That play is not allowed with this hand.
+trainer-insurance-prompt = The dealer shows an Ace. Do you take insurance? (y/n)
+trainer-insurance-invalid = Invalid choice, please choose 'y', 'n' or 'q'.
+drill-start = Basic strategy drill: choose how to play each hand, or enter 'q' to stop.
+drill-hand = Dealer shows { $dealer }, you hold { $hand } ({ $total })
+drill-score = Score: { $correct } of { $asked }.
+drill-weakest = The hands you missed most:
+drill-missed = { $hand } against { $up_card }: missed { $missed } of { $asked }
+
+## The counting drill
+
+count-drill-start = Counting drill: keep the { $system } running count of the { $cards } cards dealt, or enter 'q' when asked to stop.
+count-drill-side-count = Count the Aces dealt on the side as well.
+count-drill-starts-from = The count starts from { $count }.
+count-drill-count-prompt = What is the running count?
+count-drill-count-invalid = Invalid count, please enter a whole number such as -3 or +2, or 'q'.
+count-drill-aces-prompt = How many Aces have been dealt?
+count-drill-aces-invalid = Invalid number, please enter a whole number such as 3, or 'q'.
+count-drill-correct = Correct, the count is { $count }.
+count-drill-answer = The count is { $count }.
+count-drill-correct-with-aces = Correct, the count is { $count } with { $dealt }.
+count-drill-answer-with-aces = The count is { $count } with { $dealt }.
+count-drill-one-ace = 1 Ace dealt
+count-drill-aces = { $aces } Aces dealt
+count-drill-score = You gave { $correct } of { $asked } counts correctly after { $cards } cards.
+count-drill-one-drill = { $system } drill
+count-drill-drills = { $drills } { $system } drills
+count-drill-report = Over your last { $drills }: { $correct } of { $asked } counts correct ({ $percentage }%), cards shown for { $pace } ms on average, { $seconds } seconds to answer.
+count-drill-best = Your fastest drill with every count correct showed cards for { $pace } ms.
+
+## The daily challenge
+
+daily-start = === Daily challenge for { $date }: { $hands } hands, finish with as many chips as you can ===
+daily-cannot-cover = You can no longer cover the minimum bet, so the challenge is over.
+daily-hand = --- Hand { $hand } of { $hands } ---
+daily-leave = You leave the daily challenge.
+daily-finished = You finished the { $date } challenge with { $amount }.
+daily-best = Your best today is { $amount }.
+daily-new-best = That's a new personal best for today!
+daily-unsaved = Could not save your daily record: { $error }
+daily-share = Share your result: { $code }
+
+## Scripts
+
+script-exhausted = The script has run out of choices.
+
+## The window
+
+gui-dealer = Dealer ({ $total })
+gui-hand = Your hand ({ $total }), bet { $bet }
+gui-hand-status = { $hand } - { $status }
+gui-to-play = to play
+gui-bankroll = Bankroll: { $amount }
+gui-round-net = This round: { $amount }
+gui-bet = Bet: { $amount }
+gui-clear = Clear
+gui-deal = Deal
+gui-dealer-plays = The dealer plays...
+
+## The full screen
+
+tui-table = Table
+tui-statistics = Statistics
+tui-actions = Actions
+tui-history = Hand history: PgUp/PgDn
+tui-messages = Messages
+tui-input = Your choice: Enter sends it, Ctrl-C leaves
+tui-bet = Bet
+tui-won = Won: { $count }
+tui-lost = Lost: { $count }
+tui-pushed = Pushed: { $count }
+tui-net = Net: { $amount }
+tui-trend = Bankroll trend:
+history-hand = Hand { $hand }: { $result } { $net }
+history-you = You: { $cards }
+history-dealer = Dealer: { $cards }
+
+## Back counting
+
+wong-start = Back counting: keep the { $system } count while you watch, and you sit in at a true count of { $enter } or more and leave below { $exit }. Enter 'q' at any question to stop.
+wong-sit-in = The true count is { $count }, so you sit in.
+wong-leave = The true count is { $count }, so you leave.
+wong-opponent = { $name }: { $cards } ({ $total })
+wong-dealer = Dealer: { $cards } ({ $total })
+wong-true-count = True count: { $count }
+wong-next = Press Enter for the next round, or enter 'q' to stop.
+wong-cannot-cover = You can no longer cover the table minimum.
+wong-bet-prompt = How many chips do you bet? ({ $minimum } to { $maximum }, Enter for { $last }, 'q' to stop)
+wong-hand = Dealer: { $dealer }, you: { $hand } ({ $total })
+wong-push = You push
+wong-lose = You lose { $chips } chips
+wong-win = You win { $chips } chips
+wong-round = Dealer finishes with { $cards } ({ $total }). { $result }, and have { $balance } left.
+wong-summary = You watched { $watched } rounds and played { $played }, finishing { $net } chips.
+
+## The strategy chart
+
+chart-title = Basic strategy for { $rules }
+chart-hard = Hard totals
+chart-soft = Soft totals
+chart-pairs = Pairs
+chart-hard-row = Hard { $total }
+chart-legend = H hit, S stand, P split, Dh double or else hit, Ds double or else stand, Rh surrender or else hit, Rs surrender or else stand
+chart-one-deck = 1 deck
+chart-decks = { $decks } decks
+chart-hits-soft-17 = dealer hits soft 17
+chart-stands-soft-17 = dealer stands on soft 17
+chart-double-after-split = double after split
+chart-no-double-after-split = no double after split
+chart-surrender = surrender
+chart-no-surrender = no surrender
+chart-dealer-peeks = dealer peeks
+chart-no-peek = no hole card peek
+
+## Replays
+
+replay-start = Replaying { $variant } dealt from seed { $seed }: { $actions } actions.
+replay-next = Press Enter for the next action, or enter 'q' to stop.
+replay-action = { $number }. { $action }
+replay-stopped = The replay stops at action { $number }: { $error }
+replay-end = The replay is over, with { $balance } chips left.
+replay-unavailable = No replay was written to { $file }: a resumed game was not dealt from its seed.
+replay-unsaved = The replay could not be written: { $error }
+
+## The simulator
+
+simulate-start = Simulating { $rounds } rounds: { $setup }.
+simulate-counting = counting { $system }
+simulate-ace-side-count = with an ace side count
+simulate-wonging = wonging in at { $enter } and out below { $exit }
+simulate-cannot-cover = The bot can no longer cover the table minimum.
+simulate-sat-out = Rounds sat out: { $rounds }.
+simulate-hands = Rounds played: { $rounds }, hands won: { $won }, lost: { $lost }, pushed: { $pushed }, blackjacks: { $blackjacks }.
+simulate-bankroll = Bankroll: { $balance } chips, from { $bankroll } (peak { $peak }).
+simulate-return = Wagered { $wagered } chips, returned { $returned } ({ $percentage }% to player).
+
+## The server
+
+serve-start = Serving blackjack on { $address }.
+serve-unaccepted = Could not accept a connection: { $error }
+serve-a-player = a player
+serve-sat-down = { $player } sat down.
+serve-left = { $player } left the table.
+serve-left-with-error = { $player } left the table: { $error }
@@ -0,0 +1,502 @@
+# El texto del juego en español. Las claves que falten se muestran en inglés.
+#
+# Cada línea da el texto de una clave, como `clave = texto`. Un `{ $nombre }` en el texto se
+# sustituye por el valor del mismo nombre, como una cantidad de fichas o un total.
+
+## Cartas
+
+rank-ace = As
+rank-two = Dos
+rank-three = Tres
+rank-four = Cuatro
+rank-five = Cinco
+rank-six = Seis
+rank-seven = Siete
+rank-eight = Ocho
+rank-nine = Nueve
+rank-ten = Diez
+rank-jack = Jota
+rank-queen = Reina
+rank-king = Rey
+suit-spades = Picas
+suit-hearts = Corazones
+suit-diamonds = Diamantes
+suit-clubs = Tréboles
+card-name = { $rank } de { $suit }
+face-down-card = una carta boca abajo
+
+## Totales, leídos en voz alta
+
+number-0 = cero
+number-1 = uno
+number-2 = dos
+number-3 = tres
+number-4 = cuatro
+number-5 = cinco
+number-6 = seis
+number-7 = siete
+number-8 = ocho
+number-9 = nueve
+number-10 = diez
+number-11 = once
+number-12 = doce
+number-13 = trece
+number-14 = catorce
+number-15 = quince
+number-16 = dieciséis
+number-17 = diecisiete
+number-18 = dieciocho
+number-19 = diecinueve
+number-20 = veinte
+number-21 = veintiuno
+number-22 = veintidós
+number-23 = veintitrés
+number-24 = veinticuatro
+number-25 = veinticinco
+number-26 = veintiséis
+number-27 = veintisiete
+number-28 = veintiocho
+number-29 = veintinueve
+number-30 = treinta
+number-31 = treinta y uno
+soft-total = { $total } blando
+
+## Opciones en cada decisión, y las acciones que se muestran cuando juega una estrategia
+
+choice-hit = pedir
+choice-stand = plantarse
+choice-double = doblar
+choice-split = separar
+choice-surrender = rendirse
+choice-advice = consejo
+choice-count = cuenta
+choice-quit = salir
+choice-for-free = { $choice } gratis
+action-bet = Apuesta({ $amount })
+action-hit = Pedir
+action-stand = Plantarse
+action-double = Doblar
+action-split = Separar
+action-surrender = Rendirse
+action-switch = Cambiar
+action-keep-hands = Mantener
+action-insure = Asegurar
+action-decline-insurance = Sin seguro
+action-continue = Continuar
+action-sit-out = NoJuega({ $others })
+
+## Apuestas
+
+bet-prompt = ¿Cuántas fichas quieres apostar? (límites de la mesa: { $minimum } a { $maximum })
+quick-bets = Apuestas rápidas: (m)áximo, predefinidas { $presets }
+quick-bets-repeat = Apuestas rápidas: (r)epetir { $bet }, (m)áximo, predefinidas { $presets }
+presets-updated = Apuestas predefinidas actualizadas.
+presets-invalid = Escribe las apuestas predefinidas como números enteros, p. ej. 'presets 10 25 100'.
+no-previous-bet = No hay una apuesta anterior que repetir.
+invalid-amount = Cantidad no válida, escribe un número entero de fichas.
+invalid-bet = Cantidad no válida, escribe un número entero de fichas o una apuesta rápida.
+bankroll = Tu saldo: { $amount } ({ $chips })
+advice = Cuenta corrida: { $running }, cuenta real: { $true } (quedan { $decks } barajas). { $advisor } sugiere { $bet }.
+advice-aces = Quedan { $aces } ases ({ $surplus } respecto a la proporción justa), así que apuesta según una cuenta real de { $betting }.
+strategy-bets = { $name } apuesta { $bet }.
+progression-capped = { $name } quiere apostar { $wanted }, pero la mesa y tu saldo permiten { $bet }.
+side-bet-prompt = ¿Cuántas fichas en { $name }? (0 para no apostar)
+only-left = Solo te quedan { $amount }.
+bet-below-minimum = El mínimo de la mesa es { $chips } fichas.
+bet-above-maximum = El máximo de la mesa es { $chips } fichas.
+bet-insufficient-chips = Solo tienes { $chips } fichas para cubrir esa apuesta.
+
+## Jugar las manos
+
+you = Tú
+shuffling = Barajando el zapato...
+hand-label = Mano { $hand } de { $hands }
+hand-shown = Mano:
+your-total = Tu total: { $total }
+cards-switched = Cartas cambiadas.
+opponent-plays = { $name } juega: { $cards } (total: { $total })
+strategy-chooses = { $name } elige { $action }.
+leaving-stand = Te plantas, ya que dejas la mesa.
+charlie-cards = ¡Charlie! { $cards } cartas sin pasarte.
+player-busts = ¡Te pasas! Tu total supera 21.
+player-surrenders = Te rindes y pierdes la mitad de tu apuesta.
+free-double = ¡Doble gratis! La casa cubre la apuesta adicional.
+free-split = ¡Separación gratis! La casa cubre la apuesta de la nueva mano.
+dealer-peeks = ¡El crupier mira la carta oculta y tiene blackjack!
+dealer-hits = El crupier pide...
+dealer-total = Total del crupier: { $total }
+decide-prompt = ¿Quieres { $choices }?
+decide-prompt-timed = ¿Quieres { $choices }? (quedan { $seconds } segundos)
+time-up = Se acabó el tiempo, así que te plantas.
+invalid-choice = Opción no válida, elige una de las opciones mostradas.
+quit-prompt = ¿Salir ahora? Tus manos se plantan y la ronda se liquida antes de que te vayas. (y/n)
+quit-invalid = Opción no válida, elige 'y' para salir o 'n' para seguir jugando.
+count-overlay = Cuenta corrida { $running }, cuenta real { $true }, quedan { $decks } barajas
+count-hidden = La cuenta está oculta.
+count-aces = quedan { $aces } ases ({ $surplus } respecto a la proporción justa)
+hints = Pistas:
+advice-play = La estrategia básica dice: { $action }
+hint-bust-if-hit = Probabilidad de pasarte si pides: { $chance }
+hint-dealer = Totales más probables del crupier: { $totals }
+hint-total = { $total } ({ $chance })
+hint-bust = se pasa
+hint-play = Estrategia básica: { $action }
+chatter = El crupier dice: «{ $line }»
+switch-prompt = ¿Quieres cambiar las segundas cartas? (y/n)
+switch-invalid = Opción no válida, elige 'y' para cambiar o 'n' para mantener tus manos.
+insurance-prompt = El crupier muestra un As. ¿Quieres un seguro por la mitad de tu apuesta? (y/n)
+insurance-invalid = Opción no válida, elige 'y' para tomar el seguro o 'n' para rechazarlo.
+
+## Resultados
+
+result-charlie = ¡Charlie! Ganas.
+result-blackjack = ¡Blackjack! Ganas, se paga { $payout }.
+result-bonus = ¡Bonificación! Ganas, se paga { $payout }.
+result-dealer-busts = ¡El crupier se pasa! Ganas.
+result-win = ¡Ganas!
+result-bust = ¡Te pasas! Gana el crupier.
+result-lose-tie = El crupier gana el empate.
+result-lose = Gana el crupier.
+result-push-totals = El crupier tiene { $total }, es un empate.
+result-push = ¡Empate!
+result-surrendered = Te rendiste.
+outcome-won = ganada
+outcome-push = empate
+outcome-lost = perdida
+outcome-surrendered = rendida
+hand-result = { $result } (apuesta: { $bet }, neto: { $net })
+hand-result-labelled = { $label }: { $result } (apuesta: { $bet }, neto: { $net })
+side-bet-wins = { $name }: gana { $combination }, se paga { $payout } (neto: { $net }).
+side-bet-loses = { $name }: pierde (neto: { $net }).
+combination-any-20 = Cualquier 20
+combination-suited-20 = 20 del mismo palo
+combination-matched-20 = 20 idéntico
+combination-queen-of-hearts-pair = Pareja de Reinas de Corazones
+combination-queen-of-hearts-pair-with-dealer-blackjack = Pareja de Reinas de Corazones con blackjack del crupier
+combination-suited = Mismo palo
+combination-royal-match = Royal Match
+insurance-wins = Seguro: el crupier tiene blackjack, se paga { $payout } (neto: { $net }).
+insurance-loses = Seguro: el crupier no tiene blackjack (neto: { $net }).
+opponent-result = { $name }: neto { $net }, ahora tiene { $amount }.
+
+## Entre rondas
+
+play-again = ¿Quieres jugar otra vez? (y/n, o (s) guardar y salir)
+save-prompt = ¿Quieres guardar la partida para continuarla más tarde? (y/n)
+game-over = ================== FIN DEL JUEGO ==================
+cannot-cover = Tienes { $amount }, que no cubre el mínimo de la mesa de { $minimum }.
+rounds-played = Rondas jugadas: { $rounds }
+hands-record = Manos ganadas / perdidas / empatadas: { $won } / { $lost } / { $pushed }
+blackjacks = Blackjacks: { $count }
+peak-bankroll = Saldo máximo: { $amount } ({ $chips })
+rebuys = Recompras: { $count }
+rebuy-prompt = ¿Recomprar por { $amount }? (y/n)
+rebuy-invalid = Opción no válida, elige 'y' para recomprar o 'n' para dejar la mesa.
+session-summary = ============== RESUMEN DE LA SESIÓN ===============
+bought-in = Compra inicial: { $amount } ({ $rebuys } recompras)
+cashed-out = Retirado: { $amount }
+net-result = Resultado neto: { $amount }
+total-wagered = Total apostado: { $amount }
+return-to-player = Retorno al jugador: { $percentage } %
+return-to-player-none = Retorno al jugador: no hubo apuestas
+hands-played = Manos jugadas: { $hands }
+biggest-win = Mayor ganancia: { $amount }
+biggest-loss = Mayor pérdida: { $amount }
+strategy-accuracy = Jugadas de estrategia básica: { $correct } de { $decisions } ({ $percentage }%)
+strategy-by-hand = Por mano — { $groups }
+strategy-by-up-card = Por carta del crupier — { $groups }
+strategy-group = { $name }: { $correct }/{ $decisions } ({ $percentage }%)
+hand-kind-hard = duras
+hand-kind-soft = blandas
+hand-kind-pairs = pares
+time-played = Tiempo de juego: { $minutes } min
+summary-end = ===================================================
+
+## Torneos
+
+tournament-round = === Ronda { $round } de { $rounds } del torneo: { $hands } manos, pasan los { $advancing } primeros ===
+tournament-sit-out = Ya no puedes cubrir la apuesta mínima y te quedas fuera el resto de la ronda.
+tournament-hand = --- Mano { $hand } de { $hands } ---
+tournament-leave = Dejas el torneo.
+standings = Clasificación tras la ronda { $round }:
+standing = { $place }. { $name } - { $amount } ({ $status })
+standing-advances = pasa
+standing-eliminated = eliminado
+tournament-eliminated = Terminaste en el puesto { $place } y quedas eliminado.
+tournament-won = ¡Ganaste el torneo!
+tournament-finished = Terminaste el torneo en el puesto { $place }.
+tournament-advance = ¡Pasas a la ronda { $round }!
+
+## La demostración
+
+demo-start = Demostración: un bot juega con la estrategia básica. Pulsa cualquier tecla para jugar tú.
+demo-commentary = El bot tiene { $hand } contra { $up } del crupier: una carta más se pasa el { $bust } de las veces, y el total más probable del crupier es { $total } ({ $chance }).
+demo-over = ¡Te toca!
+
+## La mesa
+
+table-dealer = Crupier
+table-players = Jugadores
+table-you = Tú
+table-you-hand = Tú, mano { $hand }
+table-you-hand-of = Tú, mano { $hand } de { $hands }
+table-showing = muestra { $total }
+table-hand-detail = { $total }, apuesta { $bet }
+table-shoe = Saldo: { $bankroll }, zapato: quedan { $remaining } de { $size } cartas
+table-status = Zapato [{ $gauge }] { $remaining }/{ $size } | Descartes { $discards } | Saldo { $bankroll }
+table-trend = Evolución del saldo { $trend }
+spoken-dealer-shows = El crupier muestra { $cards }.
+spoken-dealer-shows-total = El crupier muestra { $cards }, { $total }.
+spoken-dealer-hand = Mano del crupier: { $cards }, { $total }.
+spoken-your-hand = Tu mano
+spoken-your-hand-of = Tu mano { $hand } de { $hands }
+spoken-opponent-hand = Mano de { $name }
+spoken-hand = { $owner }: { $cards }, { $total }, apuesta { $bet }.
+spoken-bankroll = Tu saldo es { $bankroll }, y quedan { $remaining } de { $size } cartas en el zapato.
+status-line = Tú: { $you } | Crupier: { $dealer } | Saldo: { $bankroll } | Apuesta: { $bet }
+status-count = CC { $running } CR { $true }
+status-aces = A { $aces }
+
+## Errores
+
+error-io = no se pudo leer la entrada o escribir la salida: { $error }
+error-invalid-card = '{ $text }' no es una carta, un valor o un palo válido
+error-invalid-action = '{ $text }' no es una acción válida
+error-input-exhausted = la entrada del guion no tiene más opciones
+error-too-many-seats = una mesa tiene como mucho { $max } jugadores más, no { $others }
+error-empty-deck = la baraja está vacía
+error-rules = las reglas de la mesa no se pueden jugar: { $error }
+error-snapshot = no se pudo guardar o recuperar la partida: { $error }
+error-unsupported-version = el archivo tiene la versión de formato { $found }, pero solo se pueden leer versiones hasta la { $supported }
+error-unknown-variant = no hay ninguna variante llamada '{ $name }'
+error-undo-unavailable = las decisiones solo se pueden deshacer en el modo de práctica
+error-nothing-to-undo = no hay ninguna decisión que deshacer
+error-engine-stopped = el motor del juego se ha detenido
+error-illegal-action = { $action } no está permitido ahora, elige entre: { $legal }
+error-no-actions = nada
+
+## El menú principal y los perfiles
+
+main-menu = Menú principal: (1) Jugar, (2) Entrenador, (3) Simular, (4) Estadísticas, (5) Ajustes, (q) salir
+main-menu-invalid = Opción no válida, escribe un número del 1 al 5 o 'q'.
+bot-prompt = Elige un bot: (b) estrategia básica, (c) contador, (d) imitador del crupier
+bot-invalid = Opción no válida, elige 'b', 'c' o 'd'.
+mode-prompt = Elige un modo: (c) partida con dinero, (t) torneo o (d) reto diario
+mode-invalid = Opción no válida, elige 'c', 't' o 'd'.
+config-unreadable = No se pudo leer el archivo de configuración: { $error }
+game-stopped = El juego tuvo que detenerse: { $error }
+profile-prompt = Elige un perfil: { $profiles }, o (n) perfil nuevo
+profile-invalid = Opción no válida, escribe un número del 1 al { $count } o 'n'.
+profile-name-prompt = Escribe un nombre para tu nuevo perfil:
+profile-name-invalid = Usa solo letras, dígitos, '-' y '_' (hasta 32 caracteres).
+profile-name-taken = Usa solo letras, dígitos, '-' y '_' (hasta 32 caracteres), y un nombre que no esté ya usado.
+profile-name-refused = '{ $name }' no puede ser un nombre de perfil: usa solo letras, dígitos, '-' y '_' (hasta 32 caracteres).
+welcome-back = ¡Bienvenido de nuevo, { $name }! En total: { $lifetime }.
+welcome = ¡Bienvenido, { $name }!
+no-saved-game = No hay ninguna partida guardada de { $name }.
+saved-game-kept = Tienes una partida guardada, que se conserva hasta que la continúes con --resume.
+playing-at = Juegas a { $variant } en una mesa de { $limits }.
+saved-game-resumed = Continúas tu partida guardada con { $amount }.
+game-saved = Tu partida está guardada. Continúala con --resume.
+graded-sessions = En todas las sesiones evaluadas:
+profile-unsaved = No se pudo guardar tu perfil: { $error }
+
+## Estadísticas
+
+lifetime = { $rounds } rondas, { $won } ganadas, { $lost } perdidas, { $pushed } empatadas, { $blackjacks } blackjacks
+profile-stats = { $name }: { $bankroll }, en total { $lifetime }.
+no-profiles = Todavía no hay perfiles.
+no-profile = No hay ningún perfil llamado '{ $name }'.
+
+## Ajustes de la mesa
+
+number-invalid = Opción no válida, escribe un número del 1 al { $count }.
+game-prompt = Elige un juego: (1) Clásico, (2) Blackjack Switch, (3) Double Exposure, (4) Free Bet, (5) Super Fun 21
+lucky-ladies-prompt = ¿Ofrecer la apuesta lateral Lucky Ladies? (n)o, (c) clásica 1000/200/25/10/4, (r) reducida 1000/125/19/9/4
+lucky-ladies-invalid = Opción no válida, elige 'n', 'c' o 'r'.
+royal-match-prompt = ¿Ofrecer la apuesta lateral Royal Match? (n)o, (c) clásica 25:1/5:2, (e) Easy Match 10:1/3:1
+royal-match-invalid = Opción no válida, elige 'n', 'c' o 'e'.
+table-prompt = Elige una mesa: { $tables }
+progression-prompt = ¿Apostar automáticamente con una progresión? (n)o, (m) Martingala, (p) Paroli, (1)-3-2-6
+progression-invalid = Opción no válida, elige 'n', 'm', 'p' o '1'.
+advisor-prompt = ¿Mostrar un asesor de apuestas según la cuenta? (n)o, (r) rampa, (k) Kelly
+advisor-invalid = Opción no válida, elige 'n', 'r' o 'k'.
+ramp-prompt = Escribe las unidades que apostar con cuentas reales de 0, 1, 2, ... (Intro para 1 1 2 4 6 8):
+ramp-invalid = Escribe números enteros de unidades, p. ej. '1 2 4 8'.
+kelly-prompt = Escribe la fracción de Kelly que apostar, entre 0 y 1 (Intro para 0.5):
+kelly-invalid = Escribe un número entre 0 y 1, p. ej. '0.25'.
+currency-prompt = Elige un formato de moneda: (1) $1,250, (2) $1,250.00, (3) 1.250,00 €, (4) £1,250
+viewer-prompt = Elige cómo se dibujan las cartas: (g) glifos A de ♠, (a) letras As de Picas, a(s)cii A of S, (p) simple AS, (b) recuadros, (u) unicode 🂡, (w) palabras para lectores de pantalla, (f) ajustado a la terminal (Intro para { $default })
+viewer-invalid = Opción no válida, elige 'g', 'a', 's', 'p', 'b', 'u', 'w' o 'f'.
+
+## El editor de ajustes
+
+settings-menu = Ajustes: (1) reglas: { $rules }, (2) cartas: { $viewer }, (3) saldo inicial: { $bankroll }, (4) palos para daltónicos: { $colorblind }, (5) reverso: { $back }; elige uno para cambiarlo, o (d) listo
+settings-invalid = Opción no válida, elige '1', '2', '3', '4', '5' o 'd'.
+setting-on = sí
+setting-off = no
+setting-theme-back = el del tema
+setting-rules = las reglas
+setting-viewer = el dibujo de las cartas
+setting-card-back = el reverso
+setting-choose = Elige { $setting }: { $options } (Intro para conservarlo)
+setting-bankroll-prompt = Escribe las fichas con las que empieza un perfil nuevo (Intro para conservarlas):
+setting-bankroll-invalid = Escribe un número entero de fichas mayor que 0.
+settings-saved = Guardado en { $path }.
+config-exists = Ya hay un archivo de configuración en { $path }; usa --force para reemplazarlo.
+config-written = Se escribió un archivo de configuración inicial en { $path }.
+
+## El entrenador
+
+trainer-start = Entrenador de estrategia básica: elige cómo jugar cada mano, o escribe 'q' para parar.
+trainer-hand = Crupier: { $dealer }, tú: { $hand } ({ $total })
+trainer-correct = Correcto.
+trainer-expected = La estrategia básica dice: { $action }.
+trainer-round = El crupier termina con { $total }. Puntuación: { $correct } de { $decisions }.
+trainer-score = Hiciste { $correct } de { $decisions } jugadas correctamente.
+play-prompt = ¿Quieres (h) pedir, (s) plantarte, (d) doblar, se(p)arar o (r)endirte?
+play-invalid = Opción no válida, elige 'h', 's', 'd', 'p', 'r' o 'q'.
+play-not-allowed = Esa jugada no está permitida con esta mano.
+trainer-insurance-prompt = El crupier muestra un As. ¿Tomas un seguro? (y/n)
+trainer-insurance-invalid = Opción no válida, elige 'y', 'n' o 'q'.
+drill-start = Práctica de estrategia básica: elige cómo jugar cada mano, o escribe 'q' para parar.
+drill-hand = El crupier muestra { $dealer }, tienes { $hand } ({ $total })
+drill-score = Puntuación: { $correct } de { $asked }.
+drill-weakest = Las manos que más has fallado:
+drill-missed = { $hand } contra { $up_card }: fallada { $missed } de { $asked }
+
+## La práctica de conteo
+
+count-drill-start = Práctica de conteo: lleva la cuenta corriente { $system } de las { $cards } cartas repartidas, o escribe 'q' cuando se te pregunte para parar.
+count-drill-side-count = Cuenta también aparte los Ases repartidos.
+count-drill-starts-from = La cuenta empieza en { $count }.
+count-drill-count-prompt = ¿Cuál es la cuenta corriente?
+count-drill-count-invalid = Cuenta no válida, escribe un número entero como -3 o +2, o 'q'.
+count-drill-aces-prompt = ¿Cuántos Ases se han repartido?
+count-drill-aces-invalid = Número no válido, escribe un número entero como 3, o 'q'.
+count-drill-correct = Correcto, la cuenta es { $count }.
+count-drill-answer = La cuenta es { $count }.
+count-drill-correct-with-aces = Correcto, la cuenta es { $count } con { $dealt }.
+count-drill-answer-with-aces = La cuenta es { $count } con { $dealt }.
+count-drill-one-ace = 1 As repartido
+count-drill-aces = { $aces } Ases repartidos
+count-drill-score = Has dado { $correct } de { $asked } cuentas correctas tras { $cards } cartas.
+count-drill-one-drill = tu última práctica de { $system }
+count-drill-drills = tus últimas { $drills } prácticas de { $system }
+count-drill-report = En { $drills }: { $correct } de { $asked } cuentas correctas ({ $percentage }%), cartas mostradas { $pace } ms de media, { $seconds } segundos para responder.
+count-drill-best = Tu práctica más rápida con todas las cuentas correctas mostró las cartas { $pace } ms.
+
+## El reto diario
+
+daily-start = === Reto diario del { $date }: { $hands } manos, termina con tantas fichas como puedas ===
+daily-cannot-cover = Ya no puedes cubrir la apuesta mínima, así que el reto ha terminado.
+daily-hand = --- Mano { $hand } de { $hands } ---
+daily-leave = Dejas el reto diario.
+daily-finished = Terminaste el reto del { $date } con { $amount }.
+daily-best = Tu mejor resultado de hoy es { $amount }.
+daily-new-best = ¡Es tu nuevo récord personal de hoy!
+daily-unsaved = No se pudo guardar tu récord diario: { $error }
+daily-share = Comparte tu resultado: { $code }
+
+## Guiones
+
+script-exhausted = El guion se ha quedado sin opciones.
+
+## La ventana
+
+gui-dealer = Crupier ({ $total })
+gui-hand = Tu mano ({ $total }), apuesta { $bet }
+gui-hand-status = { $hand } - { $status }
+gui-to-play = por jugar
+gui-bankroll = Saldo: { $amount }
+gui-round-net = Esta ronda: { $amount }
+gui-bet = Apuesta: { $amount }
+gui-clear = Borrar
+gui-deal = Repartir
+gui-dealer-plays = El crupier juega...
+
+## La pantalla completa
+
+tui-table = Mesa
+tui-statistics = Estadísticas
+tui-actions = Acciones
+tui-history = Historial de manos: RePág/AvPág
+tui-messages = Mensajes
+tui-input = Tu elección: Intro la envía, Ctrl-C sale
+tui-bet = Apostar
+tui-won = Ganadas: { $count }
+tui-lost = Perdidas: { $count }
+tui-pushed = Empatadas: { $count }
+tui-net = Neto: { $amount }
+tui-trend = Evolución del saldo:
+history-hand = Mano { $hand }: { $result } { $net }
+history-you = Tú: { $cards }
+history-dealer = Crupier: { $cards }
+
+## Contar desde fuera
+
+wong-start = Contar desde fuera: lleva la cuenta { $system } mientras miras; te sientas con una cuenta real de { $enter } o más y te levantas por debajo de { $exit }. Escribe 'q' en cualquier pregunta para parar.
+wong-sit-in = La cuenta real es { $count }, así que te sientas.
+wong-leave = La cuenta real es { $count }, así que te levantas.
+wong-opponent = { $name }: { $cards } ({ $total })
+wong-dealer = Crupier: { $cards } ({ $total })
+wong-true-count = Cuenta real: { $count }
+wong-next = Pulsa Intro para la siguiente ronda, o escribe 'q' para parar.
+wong-cannot-cover = Ya no puedes cubrir el mínimo de la mesa.
+wong-bet-prompt = ¿Cuántas fichas apuestas? ({ $minimum } a { $maximum }, Intro para { $last }, 'q' para parar)
+wong-hand = Crupier: { $dealer }, tú: { $hand } ({ $total })
+wong-push = Empatas
+wong-lose = Pierdes { $chips } fichas
+wong-win = Ganas { $chips } fichas
+wong-round = El crupier termina con { $cards } ({ $total }). { $result }, y te quedan { $balance }.
+wong-summary = Miraste { $watched } rondas y jugaste { $played }, terminando con { $net } fichas.
+
+## La tabla de estrategia
+
+chart-title = Estrategia básica para { $rules }
+chart-hard = Duros
+chart-soft = Blandos
+chart-pairs = Parejas
+chart-hard-row = Duro { $total }
+chart-legend = H pedir, S plantarse, P separar, Dh doblar o si no pedir, Ds doblar o si no plantarse, Rh rendirse o si no pedir, Rs rendirse o si no plantarse
+chart-one-deck = 1 baraja
+chart-decks = { $decks } barajas
+chart-hits-soft-17 = el crupier pide con 17 blando
+chart-stands-soft-17 = el crupier se planta con 17 blando
+chart-double-after-split = doblar tras separar
+chart-no-double-after-split = sin doblar tras separar
+chart-surrender = rendición
+chart-no-surrender = sin rendición
+chart-dealer-peeks = el crupier mira su carta oculta
+chart-no-peek = sin mirar la carta oculta
+
+## Repeticiones
+
+replay-start = Repitiendo { $variant } repartido con la semilla { $seed }: { $actions } acciones.
+replay-next = Pulsa Intro para la siguiente acción, o escribe 'q' para parar.
+replay-action = { $number }. { $action }
+replay-stopped = La repetición se detiene en la acción { $number }: { $error }
+replay-end = La repetición ha terminado, con { $balance } fichas.
+replay-unavailable = No se escribió ninguna repetición en { $file }: una partida reanudada no se repartió con su semilla.
+replay-unsaved = No se pudo escribir la repetición: { $error }
+
+## El simulador
+
+simulate-start = Simulando { $rounds } rondas: { $setup }.
+simulate-counting = contando con { $system }
+simulate-ace-side-count = con un conteo aparte de ases
+simulate-wonging = entrando con { $enter } y saliendo por debajo de { $exit }
+simulate-cannot-cover = El bot ya no puede cubrir el mínimo de la mesa.
+simulate-sat-out = Rondas sin jugar: { $rounds }.
+simulate-hands = Rondas jugadas: { $rounds }, manos ganadas: { $won }, perdidas: { $lost }, empatadas: { $pushed }, blackjacks: { $blackjacks }.
+simulate-bankroll = Fichas: { $balance }, de { $bankroll } al empezar (máximo { $peak }).
+simulate-return = Apostadas { $wagered } fichas, devueltas { $returned } ({ $percentage }% para el jugador).
+
+## El servidor
+
+serve-start = Sirviendo blackjack en { $address }.
+serve-unaccepted = No se pudo aceptar una conexión: { $error }
+serve-a-player = un jugador
+serve-sat-down = { $player } se ha sentado.
+serve-left = { $player } se ha levantado de la mesa.
+serve-left-with-error = { $player } se ha levantado de la mesa: { $error }
@@ -0,0 +1,310 @@
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use crate::error::BlackjackError;
+use crate::prelude::*;
+
+/// The rank of a playing card, from Ace through King.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Rank {
+    Ace,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+}
+
+impl Rank {
+    /// Every rank, from Ace through King.
+    pub const ALL: [Rank; 13] = [
+        Rank::Ace,
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+    ];
+
+    /// Returns an iterator over every rank, from Ace through King.
+    pub fn iter() -> impl Iterator<Item = Rank> {
+        Rank::ALL.into_iter()
+    }
+
+    /// Returns the blackjack value of the rank: 1 for an Ace, 10 for a ten or face card,
+    /// and the pip count otherwise. Whether an Ace counts as 11 is decided by the hand.
+    pub fn value(self) -> u32 {
+        match self {
+            Rank::Ace => 1,
+            Rank::Jack | Rank::Queen | Rank::King => 10,
+            rank => rank as u32 + 1,
+        }
+    }
+
+    /// Returns the short symbol printed in the corner of the card, such as "A", "7" or "K".
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Rank::Ace => "A",
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+        }
+    }
+
+    /// Returns the rank's name in words, such as "Ace", "Seven" or "King", as it is read aloud.
+    pub fn name(self) -> &'static str {
+        match self {
+            Rank::Ace => "Ace",
+            Rank::Two => "Two",
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+        }
+    }
+
+    /// Returns true for the Jack, Queen and King.
+    pub fn is_face(self) -> bool {
+        matches!(self, Rank::Jack | Rank::Queen | Rank::King)
+    }
+}
+
+/// Shows the rank's name, such as "Ace", "7" or "King".
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rank::Ace => write!(f, "Ace"),
+            Rank::Jack => write!(f, "Jack"),
+            Rank::Queen => write!(f, "Queen"),
+            Rank::King => write!(f, "King"),
+            rank => write!(f, "{}", rank.symbol()),
+        }
+    }
+}
+
+/// Parses a rank from its symbol or name, ignoring case, such as "A", "ace", "10", "T" or "king".
+impl FromStr for Rank {
+    type Err = BlackjackError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        if text.eq_ignore_ascii_case("t") {
+            return Ok(Rank::Ten);
+        }
+        Rank::iter()
+            .find(|rank| {
+                text.eq_ignore_ascii_case(rank.symbol())
+                    || text.eq_ignore_ascii_case(&rank.to_string())
+            })
+            .ok_or_else(|| BlackjackError::InvalidCard(text.to_string()))
+    }
+}
+
+/// The suit of a playing card.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Suit {
+    Hearts,
+    Diamonds,
+    Spades,
+    Clubs,
+}
+
+impl Suit {
+    /// Every suit, in the order a new deck is sorted.
+    pub const ALL: [Suit; 4] = [Suit::Hearts, Suit::Diamonds, Suit::Spades, Suit::Clubs];
+
+    /// Returns an iterator over every suit.
+    pub fn iter() -> impl Iterator<Item = Suit> {
+        Suit::ALL.into_iter()
+    }
+
+    /// Returns the suit's symbol: ♥, ♦, ♠ or ♣.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Suit::Hearts => "♥",
+            Suit::Diamonds => "♦",
+            Suit::Spades => "♠",
+            Suit::Clubs => "♣",
+        }
+    }
+
+    /// Returns the single letter used for the suit in short card names, such as "H" in "KH".
+    pub fn letter(self) -> &'static str {
+        match self {
+            Suit::Hearts => "H",
+            Suit::Diamonds => "D",
+            Suit::Spades => "S",
+            Suit::Clubs => "C",
+        }
+    }
+
+    /// Returns true for Hearts and Diamonds.
+    pub fn is_red(self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Diamonds)
+    }
+}
+
+/// Shows the suit's name, such as "Hearts".
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Suit::Hearts => "Hearts",
+            Suit::Diamonds => "Diamonds",
+            Suit::Spades => "Spades",
+            Suit::Clubs => "Clubs",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parses a suit from its letter, symbol or name, ignoring case, such as "H", "♥" or "hearts".
+impl FromStr for Suit {
+    type Err = BlackjackError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        Suit::iter()
+            .find(|suit| {
+                text.eq_ignore_ascii_case(suit.letter())
+                    || text == suit.symbol()
+                    || text.eq_ignore_ascii_case(&suit.to_string())
+            })
+            .ok_or_else(|| BlackjackError::InvalidCard(text.to_string()))
+    }
+}
+
+/// A playing card with a rank and suit.
+///
+/// The `Card` struct represents a standard playing card with a `Rank` (Ace through King) and a `Suit` (Hearts, Diamonds, Spades, or Clubs).
+/// Cards are small values that can be copied, compared, hashed and sorted, by rank and then by suit.
+/// They are shown in short form, such as "K♥" or "10♦", and can be parsed back from that form,
+/// from letters such as "KH" or "10d", or from names such as "King of Hearts".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Card {
+    pub rank: Rank,
+    pub suit: Suit,
+}
+
+impl Card {
+    /// Creates a card of the given rank and suit.
+    pub fn new(rank: Rank, suit: Suit) -> Self {
+        Card { rank, suit }
+    }
+}
+
+/// Shows the card in short form, such as "K♥" or "10♦".
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.rank.symbol(), self.suit.symbol())
+    }
+}
+
+/// Parses a card in short form, such as "KH", "10♦" or "ts", or by name, such as "King of Hearts".
+impl FromStr for Card {
+    type Err = BlackjackError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let invalid = || BlackjackError::InvalidCard(text.to_string());
+
+        let (rank, suit) = match text.split_once(" of ") {
+            Some(parts) => parts,
+            None => {
+                let suit_start = text.char_indices().last().ok_or_else(invalid)?.0;
+                text.split_at(suit_start)
+            }
+        };
+        Ok(Card {
+            rank: rank.parse().map_err(|_| invalid())?,
+            suit: suit.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_card_parses_back_from_its_short_form() {
+        for rank in Rank::iter() {
+            for suit in Suit::iter() {
+                let card = Card::new(rank, suit);
+                assert_eq!(card.to_string().parse::<Card>().unwrap(), card);
+            }
+        }
+    }
+
+    #[test]
+    fn every_card_parses_back_from_its_name() {
+        for rank in Rank::iter() {
+            for suit in Suit::iter() {
+                let card = Card::new(rank, suit);
+                let name = format!("{} of {}", rank, suit);
+                assert_eq!(name.parse::<Card>().unwrap(), card);
+            }
+        }
+    }
+
+    #[test]
+    fn cards_parse_from_letters_in_any_case() {
+        assert_eq!(
+            "KH".parse::<Card>().unwrap(),
+            Card::new(Rank::King, Suit::Hearts)
+        );
+        assert_eq!(
+            "10d".parse::<Card>().unwrap(),
+            Card::new(Rank::Ten, Suit::Diamonds)
+        );
+        assert_eq!(
+            "ts".parse::<Card>().unwrap(),
+            Card::new(Rank::Ten, Suit::Spades)
+        );
+        assert_eq!(
+            " ace of clubs ".parse::<Card>().unwrap(),
+            Card::new(Rank::Ace, Suit::Clubs)
+        );
+    }
+
+    #[test]
+    fn malformed_cards_are_refused() {
+        for text in ["", "K", "1H", "KX", "King of", "11 of Hearts"] {
+            assert!(
+                matches!(text.parse::<Card>(), Err(BlackjackError::InvalidCard(_))),
+                "{:?} was read as a card",
+                text
+            );
+        }
+    }
+}
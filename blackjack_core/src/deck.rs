@@ -0,0 +1,64 @@
+use crate::card::{Card, Rank, Suit};
+use crate::entropy::EntropySource;
+use crate::error::BlackjackError;
+use crate::prelude::*;
+
+/// The `Deck` struct represents a standard deck of 52 playing cards.
+///
+/// The `Deck` struct contains a `Vec` of `Card` instances, representing the cards in the deck.
+/// The `new()` method creates a new deck with all 52 cards, the `shuffle()` method shuffles the deck,
+/// and the `deal_card()` method removes and returns the top card from the deck.
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// Creates a new deck of 52 shuffled playing cards.
+    pub fn new() -> Self {
+        let mut cards = Vec::new();
+
+        for suit in Suit::iter() {
+            for rank in Rank::iter() {
+                cards.push(Card { rank, suit });
+            }
+        }
+
+        Deck { cards }
+    }
+
+    /// Returns the cards left in the deck, the top card last.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns the number of cards left in the deck.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if every card has been dealt.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Takes the cards out of the deck, the top card last, to fill a shoe.
+    pub fn into_cards(self) -> Vec<Card> {
+        self.cards
+    }
+
+    /// Shuffles the deck with the given entropy source.
+    pub fn shuffle(&mut self, entropy: &mut dyn EntropySource) {
+        entropy.shuffle(&mut self.cards);
+    }
+
+    /// Deals the top card from the deck and removes it, or returns an error if the deck is empty.
+    pub fn deal_card(&mut self) -> Result<Card, BlackjackError> {
+        self.cards.pop().ok_or(BlackjackError::EmptyDeck)
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
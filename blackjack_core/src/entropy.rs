@@ -0,0 +1,202 @@
+//! Sources of randomness for shuffling the shoe and for the choices bots make.
+//!
+//! Everything random in the game is drawn from an `EntropySource`, so an integrator can supply
+//! their own, such as a hardware random number generator, a provably fair server seed or a
+//! deterministic source for tests. With the `rand` feature, which is on by default, every random
+//! number generator from the `rand` crate that can be cloned is an entropy source, and seeded
+//! games are dealt from `rand`'s `StdRng`. Without it, seeded games are dealt from the built-in
+//! `SplitMix64`, so a seed only deals the same cards in builds with the same `rand` setting.
+
+#[cfg(feature = "rand")]
+use rand::rngs::StdRng;
+#[cfg(feature = "rand")]
+use rand::seq::SliceRandom;
+#[cfg(feature = "rand")]
+use rand::{Rng, RngCore, SeedableRng};
+
+use crate::prelude::*;
+
+/// The `EntropySource` trait supplies the random bits behind every shuffle and random choice.
+///
+/// The `EntropySource` trait provides the following methods:
+/// - `next_u64(&mut self) -> u64`: Returns the next 64 random bits.
+/// - `next_u32(&mut self) -> u32`: Returns the next 32 random bits, which by default are the high
+///   half of `next_u64()`.
+/// - `fork(&self) -> Option<Box<dyn EntropySource>>`: Returns a copy of the source that goes on to
+///   produce the same bits as the source itself will, or `None`, the default, for a source that
+///   cannot be copied, such as a hardware generator. The engine keeps a fork with each decision
+///   made in practice mode, so that a decision taken back is dealt the same cards again.
+///
+/// A source only has to produce uniformly random bits; shuffling and picking numbers in a range are
+/// built on top of it by `shuffle()` and `range()`.
+pub trait EntropySource {
+    fn next_u64(&mut self) -> u64;
+
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn fork(&self) -> Option<Box<dyn EntropySource>> {
+        None
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<R: RngCore + Clone + 'static> EntropySource for R {
+    fn next_u64(&mut self) -> u64 {
+        RngCore::next_u64(self)
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        RngCore::next_u32(self)
+    }
+
+    fn fork(&self) -> Option<Box<dyn EntropySource>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+impl dyn EntropySource + '_ {
+    /// Shuffles `items` into a uniformly random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        #[cfg(feature = "rand")]
+        items.shuffle(&mut RandAdapter(self));
+        #[cfg(not(feature = "rand"))]
+        for index in (1..items.len()).rev() {
+            let other = self.below(index as u32 + 1);
+            items.swap(index, other as usize);
+        }
+    }
+
+    /// Returns a uniformly random number from `low` to `high`, inclusive.
+    #[cfg(feature = "rand")]
+    pub fn range(&mut self, low: u32, high: u32) -> u32 {
+        RandAdapter(self).gen_range(low..=high)
+    }
+
+    /// Returns a uniformly random number from `low` to `high`, inclusive.
+    #[cfg(not(feature = "rand"))]
+    pub fn range(&mut self, low: u32, high: u32) -> u32 {
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.below(span),
+            None => self.next_u32(),
+        }
+    }
+
+    /// Returns a uniformly random number below `bound`, which must not be zero, using Lemire's
+    /// multiply-and-reject method.
+    #[cfg(not(feature = "rand"))]
+    fn below(&mut self, bound: u32) -> u32 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = u64::from(self.next_u32()) * u64::from(bound);
+            if product as u32 >= threshold {
+                return (product >> 32) as u32;
+            }
+        }
+    }
+}
+
+/// Lets `rand`'s shuffling and sampling draw from any entropy source, so sources from the `rand`
+/// crate give exactly the same results as they would used directly.
+#[cfg(feature = "rand")]
+struct RandAdapter<'a, 'b>(&'a mut (dyn EntropySource + 'b));
+
+#[cfg(feature = "rand")]
+impl RngCore for RandAdapter<'_, '_> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.0.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// A small, fast deterministic source that produces the same bits for the same seed on every
+/// platform. It is not suitable where players could profit from predicting the cards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a source that starts from `seed`.
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+}
+
+impl EntropySource for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut bits = self.state;
+        bits = (bits ^ (bits >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        bits = (bits ^ (bits >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        bits ^ (bits >> 31)
+    }
+
+    fn fork(&self) -> Option<Box<dyn EntropySource>> {
+        Some(Box::new(self.clone()))
+    }
+}
+
+/// Returns the source that seeded games are dealt from, starting from `seed`.
+pub fn seeded(seed: u64) -> Box<dyn EntropySource> {
+    #[cfg(feature = "rand")]
+    let source = StdRng::seed_from_u64(seed);
+    #[cfg(not(feature = "rand"))]
+    let source = SplitMix64::new(seed);
+    Box::new(source)
+}
+
+/// Returns a source seeded by the operating system, for games that should not be repeatable.
+#[cfg(feature = "std")]
+pub fn from_system() -> Box<dyn EntropySource> {
+    #[cfg(feature = "rand")]
+    let source = StdRng::from_entropy();
+    // Without `rand`, the standard library's randomly keyed hasher is the operating system's entropy
+    #[cfg(not(feature = "rand"))]
+    let source = {
+        use std::hash::{BuildHasher, Hasher};
+        let hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        SplitMix64::new(hasher.finish())
+    };
+    Box::new(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_gives_the_same_bits() {
+        let (mut first, mut second) = (seeded(42), seeded(42));
+        for _ in 0..16 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+    }
+
+    #[test]
+    fn a_fork_carries_on_where_its_source_is() {
+        for mut source in [seeded(7), Box::new(SplitMix64::new(7))] {
+            source.next_u64();
+            let mut fork = source.fork().unwrap();
+            for _ in 0..16 {
+                assert_eq!(fork.next_u64(), source.next_u64());
+            }
+        }
+    }
+}
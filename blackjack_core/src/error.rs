@@ -0,0 +1,81 @@
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "std")]
+use crate::game::BuildError;
+use crate::game::{Action, BetError, RuleError, MAX_SEATS};
+use crate::prelude::*;
+
+/// The errors that can interrupt a game of blackjack.
+///
+/// Library functions return these instead of panicking, so an embedder or the command-line
+/// program can report the problem and recover, for example by saving the player's progress.
+#[derive(Debug, thiserror::Error)]
+pub enum BlackjackError {
+    /// The player's input could not be read, or the game's output could not be written.
+    #[cfg(feature = "std")]
+    #[error("could not read input or write output: {0}")]
+    Io(#[from] io::Error),
+    /// Text could not be read as a card, rank or suit.
+    #[error("'{0}' is not a valid card, rank or suit")]
+    InvalidCard(String),
+    /// Text could not be read as an action.
+    #[error("'{0}' is not a valid action")]
+    InvalidAction(String),
+    /// A scripted input was asked for a choice after its last one had been used.
+    #[error("the scripted input has no choices left")]
+    InputExhausted,
+    /// A round was sat out to be dealt to more other players than the table has seats for; holds
+    /// the players asked for.
+    #[error("a table seats at most {max} other players, not {0}", max = MAX_SEATS - 1)]
+    TooManySeats(usize),
+    /// A card was drawn from a deck with no cards left.
+    #[error("the deck is empty")]
+    EmptyDeck,
+    /// A bet was refused by the table or the bankroll.
+    #[error(transparent)]
+    Bet(#[from] BetError),
+    /// A game could not be built from the chosen settings.
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    Build(#[from] BuildError),
+    /// The table rules cannot be played together.
+    #[error("the table rules cannot be played: {0}")]
+    Rules(#[from] RuleError),
+    /// A snapshot of the game could not be written as JSON, or read back.
+    #[error("could not save or restore the game: {0}")]
+    Snapshot(#[from] serde_json::Error),
+    /// A saved game or replay was written in a newer format than this version of the game can read.
+    #[error(
+        "the file is in format version {found}, but only versions up to {supported} can be read"
+    )]
+    UnsupportedVersion { found: u32, supported: u32 },
+    /// A replay was recorded at a variant this version of the game does not know; holds its name.
+    #[error("there is no variant called '{0}'")]
+    UnknownVariant(String),
+    /// An undo was asked for outside practice mode.
+    #[error("decisions can only be undone in practice mode")]
+    UndoUnavailable,
+    /// An undo was asked for with no decision of the round left to take back.
+    #[error("there is no decision to undo")]
+    NothingToUndo,
+    /// The thread running an `AsyncEngine` has stopped, so nothing more can be sent to it.
+    #[error("the game engine has stopped")]
+    EngineStopped,
+    /// An action was submitted that is not allowed at this point of the round. The actions that
+    /// were allowed are listed in `legal`.
+    #[error("{action:?} is not allowed right now, choose from: {}", describe_actions(.legal))]
+    IllegalAction { action: Action, legal: Vec<Action> },
+}
+
+/// Lists actions for an error message, or says there are none.
+fn describe_actions(actions: &[Action]) -> String {
+    if actions.is_empty() {
+        return "nothing".to_string();
+    }
+    actions
+        .iter()
+        .map(|action| format!("{:?}", action))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
@@ -0,0 +1,290 @@
+use alloc::collections::BTreeMap;
+
+use crate::prelude::*;
+
+/// The `BetAdvisor` trait defines a counting practice aid that suggests a bet from the true count.
+///
+/// The `BetAdvisor` trait provides the following methods:
+/// - `name(&self) -> String`: Returns a description of the advisor and its configuration.
+/// - `suggest(&self, true_count: f64, unit: u32, bankroll: u32) -> u32`: Returns the suggested bet
+///   for the given Hi-Lo true count, table minimum unit and available bankroll.
+pub trait BetAdvisor {
+    fn name(&self) -> String;
+    fn suggest(&self, true_count: f64, unit: u32, bankroll: u32) -> u32;
+}
+
+/// A bet ramp that bets a fixed number of units for each true count.
+///
+/// `units[0]` is bet at a true count of zero or below, `units[1]` at a true count of one, and so on,
+/// with the last entry used for every higher count. A ramp with no entries bets a single unit.
+pub struct BetRamp {
+    pub units: Vec<u32>,
+}
+
+impl BetAdvisor for BetRamp {
+    fn name(&self) -> String {
+        let units = self
+            .units
+            .iter()
+            .map(|units| units.to_string())
+            .collect::<Vec<String>>()
+            .join("-");
+        format!("Bet ramp {}", units)
+    }
+
+    fn suggest(&self, true_count: f64, unit: u32, _bankroll: u32) -> u32 {
+        let index = (true_count.max(0.0) as usize).min(self.units.len().saturating_sub(1));
+        self.units
+            .get(index)
+            .map_or(unit, |units| units.saturating_mul(unit))
+    }
+}
+
+/// When a back counter sits in and out of a shoe: they watch without betting until the true count
+/// reaches `enter`, and play every round from then on until it falls below `exit`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wonging {
+    pub enter: f64,
+    pub exit: f64,
+}
+
+impl Wonging {
+    /// Returns true if the next round is played at `true_count`, given whether the last one was.
+    pub fn plays(&self, seated: bool, true_count: f64) -> bool {
+        match seated {
+            true => true_count >= self.exit,
+            false => true_count >= self.enter,
+        }
+    }
+}
+
+/// The highest true count a bet ramp file can give units for; every count above it is bet the same.
+pub const MAX_RAMP_COUNT: usize = 20;
+
+/// The reasons a bet ramp file cannot be read.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum RampError {
+    /// A line is not a comment and does not give a count or wonging point and its value.
+    #[error("line {0} should be `count = units`, `wong_in = count` or `wong_out = count`")]
+    Malformed(usize),
+    /// A line gives a true count that is not a whole number from zero to `MAX_RAMP_COUNT`.
+    #[error("line {0}: '{1}' is not a true count, give a whole number from 0 to {max}", max = MAX_RAMP_COUNT)]
+    InvalidCount(usize, String),
+    /// A line gives the units for a count as something other than a whole number.
+    #[error("line {0}: '{1}' is not a number of units")]
+    InvalidUnits(usize, String),
+    /// A line gives a wonging point that is not a finite number.
+    #[error("line {0}: '{1}' is not a true count to wong at")]
+    InvalidPoint(usize, String),
+    /// The file gives no units for any count.
+    #[error("no bets are given, add a line such as `0 = 1`")]
+    NoBets,
+    /// The file gives a point to leave at without one to sit in at.
+    #[error("wong_out is given without wong_in")]
+    WongOutOnly,
+    /// The file gives a point to leave at above the point to sit in at; holds both points.
+    #[error(
+        "wong_out of {1:+} is above wong_in of {0:+}, leave at or below the count you sit in at"
+    )]
+    WongOutAboveIn(f64, f64),
+}
+
+/// A bet plan read from a ramp file: the `ramp` of units bet at each true count, and the points a
+/// back counter sits in and leaves at, if the file gives them.
+///
+/// Each line of the file gives the units bet at a true count as `count = units`, such as `3 = 8`,
+/// where a unit is the table minimum. Counts go up to `MAX_RAMP_COUNT`. A count not listed is bet
+/// as the count below it is, and the lowest count listed is bet below it too, down to zero and
+/// every negative count. `wong_in = 1` sits out every round until the true count reaches +1, and
+/// `wong_out = -1` then leaves once it falls below -1, which can be no higher than `wong_in`;
+/// without `wong_out` the counter leaves as soon as the count falls below `wong_in`. Lines
+/// starting with `#` are comments.
+pub struct BetPlan {
+    pub ramp: BetRamp,
+    pub wonging: Option<Wonging>,
+}
+
+impl BetPlan {
+    /// Reads the bet plan in the ramp file `text`.
+    pub fn parse(text: &str) -> Result<BetPlan, RampError> {
+        let mut bets: BTreeMap<usize, u32> = BTreeMap::new();
+        let (mut enter, mut exit) = (None, None);
+        for (number, line) in text.lines().enumerate() {
+            let number = number + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(RampError::Malformed(number));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let point = || {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|point| point.is_finite())
+                    .ok_or_else(|| RampError::InvalidPoint(number, value.to_string()))
+            };
+            match key {
+                "wong_in" => enter = Some(point()?),
+                "wong_out" => exit = Some(point()?),
+                _ => {
+                    let count = key
+                        .parse()
+                        .ok()
+                        .filter(|&count| count <= MAX_RAMP_COUNT)
+                        .ok_or_else(|| RampError::InvalidCount(number, key.to_string()))?;
+                    let units = value
+                        .parse()
+                        .map_err(|_| RampError::InvalidUnits(number, value.to_string()))?;
+                    bets.insert(count, units);
+                }
+            }
+        }
+
+        let (&highest, _) = bets.last_key_value().ok_or(RampError::NoBets)?;
+        let lowest = bets.values().next().copied().unwrap_or(1);
+        let units = (0..=highest)
+            .map(|count| {
+                bets.range(..=count)
+                    .next_back()
+                    .map_or(lowest, |(_, &units)| units)
+            })
+            .collect();
+        let wonging = match (enter, exit) {
+            (Some(enter), Some(exit)) if exit > enter => {
+                return Err(RampError::WongOutAboveIn(enter, exit))
+            }
+            (Some(enter), exit) => Some(Wonging {
+                enter,
+                exit: exit.unwrap_or(enter),
+            }),
+            (None, Some(_)) => return Err(RampError::WongOutOnly),
+            (None, None) => None,
+        };
+        Ok(BetPlan {
+            ramp: BetRamp { units },
+            wonging,
+        })
+    }
+}
+
+/// An advisor that sizes bets as a fraction of the Kelly criterion for the current advantage.
+///
+/// The player's edge is estimated as -0.5% off the top plus 0.5% per true count, with a variance
+/// of 1.3 per hand. With no advantage the advisor suggests the table minimum.
+pub struct KellyAdvisor {
+    pub fraction: f64,
+}
+
+impl BetAdvisor for KellyAdvisor {
+    fn name(&self) -> String {
+        format!("Kelly x{}", self.fraction)
+    }
+
+    fn suggest(&self, true_count: f64, unit: u32, bankroll: u32) -> u32 {
+        let edge = -0.005 + 0.005 * true_count;
+        if edge <= 0.0 {
+            return unit;
+        }
+        let bet = bankroll as f64 * self.fraction * edge / 1.3;
+        (bet as u32).max(unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the reason `text` cannot be read as a bet plan.
+    fn refused(text: &str) -> RampError {
+        match BetPlan::parse(text) {
+            Ok(plan) => panic!("{:?} was read as the ramp {}", text, plan.ramp.name()),
+            Err(error) => error,
+        }
+    }
+
+    #[test]
+    fn a_bet_ramp_bets_the_units_for_the_true_count() {
+        let ramp = BetRamp {
+            units: vec![1, 2, 4],
+        };
+
+        assert_eq!(ramp.suggest(-3.0, 10, 1000), 10);
+        assert_eq!(ramp.suggest(1.5, 10, 1000), 20);
+        assert_eq!(ramp.suggest(9.0, 10, 1000), 40);
+    }
+
+    #[test]
+    fn an_empty_bet_ramp_bets_a_single_unit() {
+        let ramp = BetRamp { units: Vec::new() };
+        assert_eq!(ramp.suggest(4.0, 25, 1000), 25);
+    }
+
+    #[test]
+    fn a_bet_ramp_bet_too_large_to_count_is_capped() {
+        let ramp = BetRamp {
+            units: vec![u32::MAX],
+        };
+        assert_eq!(ramp.suggest(0.0, 10, 1000), u32::MAX);
+    }
+
+    #[test]
+    fn counts_not_listed_are_bet_as_the_count_below() {
+        let plan = BetPlan::parse("# a spread of 1 to 8\n1 = 2\n\n3 = 8\n").unwrap();
+        assert_eq!(plan.ramp.units, [2, 2, 2, 8]);
+        assert_eq!(plan.wonging, None);
+    }
+
+    #[test]
+    fn wong_out_defaults_to_wong_in() {
+        let plan = BetPlan::parse("0 = 1\nwong_in = 1.5").unwrap();
+        assert_eq!(
+            plan.wonging,
+            Some(Wonging {
+                enter: 1.5,
+                exit: 1.5
+            })
+        );
+        let plan = BetPlan::parse("0 = 1\nwong_in = 2\nwong_out = -1").unwrap();
+        assert_eq!(
+            plan.wonging,
+            Some(Wonging {
+                enter: 2.0,
+                exit: -1.0
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_lines_are_refused_with_their_number() {
+        assert_eq!(refused("0 = 1\n2 4"), RampError::Malformed(2));
+        assert_eq!(
+            refused("21 = 1"),
+            RampError::InvalidCount(1, "21".to_string())
+        );
+        assert_eq!(
+            refused("-1 = 1"),
+            RampError::InvalidCount(1, "-1".to_string())
+        );
+        assert_eq!(
+            refused("0 = lots"),
+            RampError::InvalidUnits(1, "lots".to_string())
+        );
+        assert_eq!(
+            refused("0 = 1\nwong_in = inf"),
+            RampError::InvalidPoint(2, "inf".to_string())
+        );
+    }
+
+    #[test]
+    fn plans_that_cannot_be_played_are_refused() {
+        assert_eq!(refused("# nothing but comments"), RampError::NoBets);
+        assert_eq!(refused("0 = 1\nwong_out = 0"), RampError::WongOutOnly);
+        assert_eq!(
+            refused("0 = 1\nwong_in = 1\nwong_out = 2"),
+            RampError::WongOutAboveIn(1.0, 2.0)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::mpsc as std_mpsc;
+use std::thread;
+
+use tokio::sync::{mpsc, oneshot, watch};
+
+use super::{Action, Engine, GameState, GameView, Snapshot};
+use crate::error::BlackjackError;
+
+/// A request sent to the thread running the engine, with the channel to answer it on.
+enum Request {
+    Step(Action, oneshot::Sender<Result<GameState, BlackjackError>>),
+    View(oneshot::Sender<GameView>),
+    Snapshot(oneshot::Sender<Snapshot>),
+}
+
+/// An awaitable handle to an `Engine`, for embedding the game in async servers, bots and chat
+/// integrations. It is only available with the `async` feature.
+///
+/// The engine runs on a thread of its own, so playing never blocks an async task: actions are
+/// submitted with `submit()`, and any task holding a clone of the handle can wait for the round to
+/// move on with `next_state()`. The engine's thread stops once every handle has been dropped, and a
+/// handle whose engine has stopped returns `BlackjackError::EngineStopped`.
+#[derive(Clone)]
+pub struct AsyncEngine {
+    requests: mpsc::UnboundedSender<Request>,
+    states: watch::Receiver<GameState>,
+}
+
+impl AsyncEngine {
+    /// Starts the engine made by `make` on a thread of its own and returns a handle to it. The
+    /// engine is made on its thread, so its variant and observers need not be `Send`; the caller
+    /// only waits while it is made.
+    pub fn spawn<F>(make: F) -> Result<Self, BlackjackError>
+    where
+        F: FnOnce() -> Engine + Send + 'static,
+    {
+        let (requests, mut receiver) = mpsc::unbounded_channel();
+        let (ready, started) = std_mpsc::channel();
+        thread::spawn(move || {
+            let mut engine = make();
+            let (publisher, states) = watch::channel(engine.state().clone());
+            if ready.send(states).is_err() {
+                return;
+            }
+            while let Some(request) = receiver.blocking_recv() {
+                match request {
+                    Request::Step(action, reply) => {
+                        let result = engine.step(action);
+                        if let Ok(state) = &result {
+                            publisher.send_replace(state.clone());
+                        }
+                        let _ = reply.send(result);
+                    }
+                    Request::View(reply) => {
+                        let _ = reply.send(engine.view());
+                    }
+                    Request::Snapshot(reply) => {
+                        let _ = reply.send(engine.snapshot());
+                    }
+                }
+            }
+        });
+        let states = started.recv().map_err(|_| BlackjackError::EngineStopped)?;
+        Ok(AsyncEngine { requests, states })
+    }
+
+    /// Returns the point the round has reached, as last seen by the engine.
+    pub fn state(&self) -> GameState {
+        self.states.borrow().clone()
+    }
+
+    /// Waits until the round moves on from the state this handle last returned from `next_state()`,
+    /// and returns the new state.
+    pub async fn next_state(&mut self) -> Result<GameState, BlackjackError> {
+        self.states
+            .changed()
+            .await
+            .map_err(|_| BlackjackError::EngineStopped)?;
+        Ok(self.states.borrow_and_update().clone())
+    }
+
+    /// Submits the player's next action and waits for the state the engine moves to, as `step()` does.
+    pub async fn submit(&self, action: Action) -> Result<GameState, BlackjackError> {
+        self.request(|reply| Request::Step(action, reply)).await?
+    }
+
+    /// Returns what the player can see of the table right now.
+    pub async fn view(&self) -> Result<GameView, BlackjackError> {
+        self.request(Request::View).await
+    }
+
+    /// Takes a snapshot of the whole game, which can be saved and later given to `Engine::restore()`.
+    pub async fn snapshot(&self) -> Result<Snapshot, BlackjackError> {
+        self.request(Request::Snapshot).await
+    }
+
+    /// Sends a request to the engine's thread and waits for the answer.
+    async fn request<T>(
+        &self,
+        request: impl FnOnce(oneshot::Sender<T>) -> Request,
+    ) -> Result<T, BlackjackError> {
+        let (reply, answer) = oneshot::channel();
+        self.requests
+            .send(request(reply))
+            .map_err(|_| BlackjackError::EngineStopped)?;
+        answer.await.map_err(|_| BlackjackError::EngineStopped)
+    }
+}
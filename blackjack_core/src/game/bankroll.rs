@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// The number of chips the player starts a session with.
+pub const STARTING_BANKROLL: u32 = 1000;
+
+/// The `Bankroll` struct holds the chips the player has available to bet.
+///
+/// Chips are taken from the bankroll with `withdraw()` when a wager is placed, and handed back
+/// with `deposit()` when a hand is settled, so the balance never includes chips in action.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bankroll {
+    pub chips: u32,
+}
+
+impl Bankroll {
+    /// Creates a bankroll holding the given number of chips.
+    pub fn new(chips: u32) -> Self {
+        Bankroll { chips }
+    }
+
+    /// Returns the number of chips available to bet.
+    pub fn balance(&self) -> u32 {
+        self.chips
+    }
+
+    /// Takes `amount` chips out of the bankroll, returning false if there are not enough.
+    pub fn withdraw(&mut self, amount: u32) -> bool {
+        if amount > self.chips {
+            return false;
+        }
+        self.chips -= amount;
+        true
+    }
+
+    /// Adds `amount` chips to the bankroll, up to as many as a `u32` holds.
+    pub fn deposit(&mut self, amount: u32) {
+        self.chips = self.chips.saturating_add(amount);
+    }
+}
@@ -0,0 +1,173 @@
+use super::{
+    index_play_for, Action, BetAdvisor, CountingSystem, GameView, PlayerStrategy, RuleSet,
+    StrategySolver,
+};
+use crate::card::Card;
+use crate::entropy::{self, EntropySource};
+use crate::error::BlackjackError;
+use crate::hand::Hand;
+use crate::prelude::*;
+
+/// A bot that plays its hand the way the dealer does, and bets a random amount between one and three
+/// times the table minimum. It sits out once it cannot cover the minimum.
+pub struct DealerMimic {
+    pub entropy: Box<dyn EntropySource>,
+}
+
+impl DealerMimic {
+    /// Creates a bot whose bets are drawn from an entropy source seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        DealerMimic {
+            entropy: entropy::seeded(seed),
+        }
+    }
+}
+
+impl PlayerStrategy for DealerMimic {
+    fn name(&self) -> String {
+        "Plays like the dealer".to_string()
+    }
+
+    fn bet(&mut self, view: &GameView) -> Result<u32, BlackjackError> {
+        let most = (view.limits.minimum * 3).min(view.max_bet());
+        if most < view.limits.minimum {
+            return Ok(0);
+        }
+        Ok(self.entropy.range(view.limits.minimum, most))
+    }
+
+    fn decide(&mut self, view: &GameView) -> Result<Action, BlackjackError> {
+        // The dealer never insures
+        if view.legal_actions.contains(&Action::DeclineInsurance) {
+            return Ok(Action::DeclineInsurance);
+        }
+        let hits = view
+            .hand
+            .as_ref()
+            .is_some_and(|hand| view.rules.dealer_policy().hits(hand));
+        Ok(if hits { Action::Hit } else { Action::Stand })
+    }
+}
+
+/// A bot that flat bets the table minimum and plays by basic strategy, worked out for the table's
+/// rules by a `StrategySolver`.
+pub struct BasicStrategy;
+
+impl BasicStrategy {
+    /// Returns the basic strategy play under `rules` for `hand` against the dealer's `up_card`,
+    /// chosen from the `legal` actions. Where the best play is not allowed, such as a double on a
+    /// third card, the next best play is made instead. Insurance is always turned down.
+    pub fn play(hand: &Hand, up_card: Card, rules: &RuleSet, legal: &[Action]) -> Action {
+        if legal.contains(&Action::DeclineInsurance) {
+            return Action::DeclineInsurance;
+        }
+        StrategySolver::new(rules, up_card, hand.cards()).play(hand, legal)
+    }
+
+    /// Returns the play for `hand` against `up_card` as `play()` does, but departing from basic
+    /// strategy by the Illustrious 18 and Fab 4 index plays at the Hi-Lo `true_count`. At or above
+    /// its index an index play is made; below it, the play is not made even where basic strategy
+    /// would make it, and the next best play is made instead.
+    pub fn play_by_count(
+        hand: &Hand,
+        up_card: Card,
+        rules: &RuleSet,
+        legal: &[Action],
+        true_count: f64,
+    ) -> Action {
+        let mut solver = StrategySolver::new(rules, up_card, hand.cards());
+        let basic = match legal.contains(&Action::DeclineInsurance) {
+            true => Action::DeclineInsurance,
+            false => solver.play(hand, legal),
+        };
+        let Some(index_play) = index_play_for(hand, up_card, basic, legal) else {
+            return basic;
+        };
+        if true_count >= index_play.index as f64 {
+            index_play.play
+        } else if basic == index_play.play {
+            let rest: Vec<Action> = legal
+                .iter()
+                .copied()
+                .filter(|&action| action != index_play.play)
+                .collect();
+            solver.play(hand, &rest)
+        } else {
+            basic
+        }
+    }
+
+    /// Returns the play for the hand in `view` against the dealer's up card, by `play_by_count()`
+    /// at the view's true count if `index_plays` is set and by `play()` otherwise, or `None`
+    /// while there is no hand to play. The index plays are worked out for the Hi-Lo count, so they
+    /// are only made while the view's count is kept by Hi-Lo; by any other system, the play is
+    /// basic strategy.
+    pub fn advise(view: &GameView, index_plays: bool) -> Option<Action> {
+        let (Some(hand), Some(up_card)) = (&view.hand, view.dealer_up_card) else {
+            return None;
+        };
+        Some(
+            match index_plays && view.count.system == CountingSystem::HI_LO {
+                true => BasicStrategy::play_by_count(
+                    hand,
+                    up_card,
+                    &view.rules,
+                    &view.legal_actions,
+                    view.true_count(),
+                ),
+                false => BasicStrategy::play(hand, up_card, &view.rules, &view.legal_actions),
+            },
+        )
+    }
+}
+
+impl PlayerStrategy for BasicStrategy {
+    fn name(&self) -> String {
+        "Basic strategy".to_string()
+    }
+
+    fn bet(&mut self, view: &GameView) -> Result<u32, BlackjackError> {
+        Ok(if view.bankroll >= view.limits.minimum {
+            view.limits.minimum
+        } else {
+            0
+        })
+    }
+
+    fn decide(&mut self, view: &GameView) -> Result<Action, BlackjackError> {
+        Ok(BasicStrategy::advise(view, false).unwrap_or(Action::Stand))
+    }
+}
+
+/// A bot that counts cards: it sizes its bets with a `BetAdvisor` from the betting count, the true
+/// count with any Aces side counted taken into account, and
+/// plays its hands by basic strategy, departing from it by the index plays at the true count if
+/// `index_plays` is set and it counts by Hi-Lo.
+pub struct CountingBot {
+    pub advisor: Box<dyn BetAdvisor>,
+    pub index_plays: bool,
+}
+
+impl PlayerStrategy for CountingBot {
+    fn name(&self) -> String {
+        format!("Counting with {}", self.advisor.name())
+    }
+
+    fn bet(&mut self, view: &GameView) -> Result<u32, BlackjackError> {
+        if view.bankroll < view.limits.minimum {
+            return Ok(0);
+        }
+        Ok(self
+            .advisor
+            .suggest(
+                view.count.betting_count(),
+                view.limits.minimum,
+                view.bankroll,
+            )
+            .clamp(view.limits.minimum, view.max_bet()))
+    }
+
+    fn decide(&mut self, view: &GameView) -> Result<Action, BlackjackError> {
+        Ok(BasicStrategy::advise(view, self.index_plays).unwrap_or(Action::Stand))
+    }
+}
@@ -0,0 +1,388 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use super::tournament::OPPONENT_NAMES;
+use super::{
+    Bankroll, Chatter, ChipView, Classic, CurrencyFormat, DealerMimic, DealerPolicy,
+    DeckEstimation, Engine, GameController, KeyBindings, KeyError, Locale, PlayerStrategy,
+    RuleError, RuleSet, Seat, SideBet, StdinInput, TableLimits, TableView, TextTableView, Theme,
+    Variant, MAX_SEATS, STARTING_BANKROLL,
+};
+use crate::entropy::{self, EntropySource};
+use crate::Renderer;
+
+/// The reasons a game cannot be built from the chosen settings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BuildError {
+    /// The rules cannot be played.
+    #[error("The rules cannot be played: {0}.")]
+    Rules(#[from] RuleError),
+    /// The keys chosen for the player's decisions cannot be told apart.
+    #[error("The keys cannot be used: {0}.")]
+    Keys(#[from] KeyError),
+    /// The table minimum is zero chips.
+    #[error("The table minimum must be at least one chip.")]
+    ZeroMinimum,
+    /// The table minimum is above the table maximum.
+    #[error("The table minimum of {0} chips is above the maximum of {1} chips.")]
+    InvertedLimits(u32, u32),
+    /// The table has no seats, or more than `MAX_SEATS`; holds the seats asked for.
+    #[error("A table has between 1 and {max} seats, not {0}.", max = MAX_SEATS)]
+    Seats(usize),
+}
+
+/// Puts together a `GameController` from its settings, checking that they make a playable game.
+///
+/// Every setting except the viewer has a default: a classic blackjack table with the default
+/// `RuleSet`, no side bets, the lowest table limits, the starting bankroll, dollars, the default
+/// keys, no time limit on decisions, no pauses between cards, no colours, the default theme, English, ungraded decisions without hints, the decks left counted to the card, a silent dealer and a single seat for the player. Each setting is changed by calling the method of the same name, and
+/// `build()` checks the settings together and returns the controller, or the first problem found.
+///
+/// The player sits at first base. When more than one seat is asked for, the other seats are taken by
+/// opponents that play like the dealer, each starting with the same chips as the player. When a seed is given, the shoe is
+/// shuffled and the opponents seated exactly as in any other game built with the same seed. An
+/// entropy source can be given instead, and otherwise the game is shuffled with entropy from the
+/// operating system.
+pub struct GameBuilder {
+    pub viewer: Box<dyn Renderer>,
+    pub table_view: Option<Box<dyn TableView>>,
+    pub variant: Box<dyn Variant>,
+    pub rules: RuleSet,
+    pub dealer_policy: Option<Box<dyn DealerPolicy>>,
+    pub side_bets: Vec<Box<dyn SideBet>>,
+    pub limits: TableLimits,
+    pub bankroll: u32,
+    pub buy_in: Option<u32>,
+    pub currency: CurrencyFormat,
+    pub seed: Option<u64>,
+    pub entropy: Option<Box<dyn EntropySource>>,
+    pub seats: usize,
+    pub strategy: Option<Box<dyn PlayerStrategy>>,
+    pub keys: KeyBindings,
+    pub time_limit: Option<Duration>,
+    pub deal_delay: Duration,
+    pub dealer_delay: Duration,
+    pub plain: bool,
+    pub color: bool,
+    pub theme: Theme,
+    pub locale: Locale,
+    pub grade_strategy: bool,
+    pub hints: bool,
+    pub index_plays: bool,
+    pub deck_estimation: DeckEstimation,
+    pub ace_side_count: bool,
+    pub chatter: Option<Chatter>,
+}
+
+impl GameBuilder {
+    /// Starts building a game whose cards are displayed with `viewer`.
+    pub fn new(viewer: Box<dyn Renderer>) -> Self {
+        GameBuilder {
+            viewer,
+            table_view: None,
+            variant: Box::new(Classic),
+            rules: RuleSet::default(),
+            dealer_policy: None,
+            side_bets: Vec::new(),
+            limits: TableLimits::TABLES[0],
+            bankroll: STARTING_BANKROLL,
+            buy_in: None,
+            currency: CurrencyFormat::DOLLARS,
+            seed: None,
+            entropy: None,
+            seats: 1,
+            strategy: None,
+            keys: KeyBindings::default(),
+            time_limit: None,
+            deal_delay: Duration::ZERO,
+            dealer_delay: Duration::ZERO,
+            plain: false,
+            color: false,
+            theme: Theme::default(),
+            locale: Locale::default(),
+            grade_strategy: false,
+            hints: false,
+            index_plays: false,
+            deck_estimation: DeckEstimation::Exact,
+            ace_side_count: false,
+            chatter: None,
+        }
+    }
+
+    /// Sets how the whole table is drawn, in place of a `TextTableView` in the chosen currency.
+    pub fn table_view(mut self, table_view: Box<dyn TableView>) -> Self {
+        self.table_view = Some(table_view);
+        self
+    }
+
+    /// Sets the variant to play.
+    pub fn variant(mut self, variant: Box<dyn Variant>) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the table rules.
+    pub fn rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Sets the policy the dealer draws by, in place of the one the variant picks from the rules.
+    pub fn dealer_policy(mut self, policy: Box<dyn DealerPolicy>) -> Self {
+        self.dealer_policy = Some(policy);
+        self
+    }
+
+    /// Sets the side bets offered at the table.
+    pub fn side_bets(mut self, side_bets: Vec<Box<dyn SideBet>>) -> Self {
+        self.side_bets = side_bets;
+        self
+    }
+
+    /// Sets the smallest and largest main bet accepted.
+    pub fn limits(mut self, limits: TableLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets the chips the player starts with.
+    pub fn bankroll(mut self, chips: u32) -> Self {
+        self.bankroll = chips;
+        self
+    }
+
+    /// Sets the chips the player buys back in for once they cannot cover the table minimum, which
+    /// are the chips they start with unless set.
+    pub fn buy_in(mut self, chips: u32) -> Self {
+        self.buy_in = Some(chips);
+        self
+    }
+
+    /// Sets how amounts of chips are shown.
+    pub fn currency(mut self, currency: CurrencyFormat) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Seeds the entropy source, so that the game deals the same cards given the same choices.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the entropy source the game is shuffled with, in place of a seeded one.
+    pub fn entropy(mut self, entropy: Box<dyn EntropySource>) -> Self {
+        self.entropy = Some(entropy);
+        self
+    }
+
+    /// Sets the number of seats at the table, counting the player's.
+    pub fn seats(mut self, seats: usize) -> Self {
+        self.seats = seats;
+        self
+    }
+
+    /// Hands the player's seat to `strategy`, such as a bot, instead of prompting the player.
+    pub fn strategy(mut self, strategy: Box<dyn PlayerStrategy>) -> Self {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    /// Sets the keys the player types at each decision.
+    pub fn keys(mut self, keys: KeyBindings) -> Self {
+        self.keys = keys;
+        self
+    }
+
+    /// Gives the player `limit` for each decision, after which they stand.
+    pub fn time_limit(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// Pauses for `delay` before each card dealt to the player is shown, so the cards appear one at
+    /// a time.
+    pub fn deal_delay(mut self, delay: Duration) -> Self {
+        self.deal_delay = delay;
+        self
+    }
+
+    /// Pauses for `delay` before the dealer turns over the hole card and before each card the
+    /// dealer draws.
+    pub fn dealer_delay(mut self, delay: Duration) -> Self {
+        self.dealer_delay = delay;
+        self
+    }
+
+    /// Sets whether chips are drawn with plain ASCII text, for output read by other programs.
+    pub fn plain(mut self, plain: bool) -> Self {
+        self.plain = plain;
+        self
+    }
+
+    /// Sets whether results are written in ANSI colours.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the theme whose colours results are written in.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the locale everything the game writes is taken from. The table view is given its own
+    /// locale when it is made.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets whether the player's decisions are graded against basic strategy, with how many matched
+    /// shown in the session summary.
+    pub fn grade_strategy(mut self, grade: bool) -> Self {
+        self.grade_strategy = grade;
+        self
+    }
+
+    /// Sets whether each of the player's decisions is preceded by hints: the chance of busting, the
+    /// dealer's likeliest totals and the basic strategy play.
+    pub fn hints(mut self, hints: bool) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Sets whether the basic strategy play given in hints and advice, and that decisions are graded
+    /// against, departs from basic strategy by the index plays at the true count, for counting
+    /// practice. The index plays are made only while the count is kept by Hi-Lo.
+    pub fn index_plays(mut self, index_plays: bool) -> Self {
+        self.index_plays = index_plays;
+        self
+    }
+
+    /// Sets how closely the decks left are reckoned when the count is turned into a true count, for
+    /// the count shown, the bets advised and the index plays.
+    pub fn deck_estimation(mut self, estimation: DeckEstimation) -> Self {
+        self.deck_estimation = estimation;
+        self
+    }
+
+    /// Sets whether the Aces are side counted, to show the Aces left with the count and adjust the
+    /// bets advised by them when the counting system leaves Aces out.
+    pub fn ace_side_count(mut self, ace_side_count: bool) -> Self {
+        self.ace_side_count = ace_side_count;
+        self
+    }
+
+    /// Has the dealer talk through the game with the lines of `chatter`.
+    pub fn chatter(mut self, chatter: Chatter) -> Self {
+        self.chatter = Some(chatter);
+        self
+    }
+
+    /// Checks that the settings make a playable game.
+    pub fn validate(&self) -> Result<(), BuildError> {
+        self.rules.validate()?;
+        self.keys.validate()?;
+        if self.limits.minimum == 0 {
+            return Err(BuildError::ZeroMinimum);
+        }
+        if self.limits.minimum > self.limits.maximum {
+            return Err(BuildError::InvertedLimits(
+                self.limits.minimum,
+                self.limits.maximum,
+            ));
+        }
+        if !(1..=MAX_SEATS).contains(&self.seats) {
+            return Err(BuildError::Seats(self.seats));
+        }
+        Ok(())
+    }
+
+    /// Builds the game controller, with a freshly shuffled shoe and any opponents seated.
+    pub fn build(self) -> Result<GameController, BuildError> {
+        self.validate()?;
+
+        // A game dealt from a seed, rather than from an entropy source given to it, is recorded
+        let recorded_seed = self.seed.filter(|_| self.entropy.is_none());
+        let entropy = match (self.entropy, self.seed) {
+            (Some(entropy), _) => entropy,
+            (None, Some(seed)) => entropy::seeded(seed),
+            (None, None) => entropy::from_system(),
+        };
+        let limits = self.limits;
+        let mut engine = Engine::with_entropy(
+            self.variant,
+            self.rules,
+            limits,
+            Bankroll::new(self.bankroll),
+            entropy,
+        );
+
+        if self.seats > 1 {
+            let mut names = OPPONENT_NAMES.to_vec();
+            engine.entropy_mut().shuffle(&mut names);
+            let opponents = names
+                .into_iter()
+                .take(self.seats - 1)
+                .map(|name| {
+                    let strategy = Box::new(DealerMimic::new(engine.entropy_mut().next_u64()));
+                    Seat::new(name, self.bankroll, Some(strategy))
+                })
+                .collect();
+            engine.seat_opponents(opponents);
+        }
+        // Seeding again deals the same shoe, and starts the replay
+        if let Some(seed) = recorded_seed {
+            engine.seed(seed);
+        }
+        if let Some(policy) = self.dealer_policy {
+            engine.set_dealer_policy(policy);
+        }
+        engine.set_side_bets(self.side_bets);
+        engine.set_deck_estimation(self.deck_estimation);
+        engine.set_ace_side_count(self.ace_side_count);
+
+        let table_view = self
+            .table_view
+            .unwrap_or_else(|| Box::new(TextTableView::new(self.currency.clone())));
+        Ok(GameController {
+            engine,
+            strategy: self.strategy,
+            viewer: self.viewer,
+            table_view,
+            input: Box::new(StdinInput),
+            output: Box::new(io::stdout()),
+            last_bet: None,
+            bet_presets: [1, 2, 5, 10]
+                .iter()
+                .map(|multiple| (limits.minimum * multiple).min(limits.maximum))
+                .collect(),
+            chip_view: ChipView {
+                currency: self.currency.clone(),
+                plain: self.plain,
+            },
+            currency: self.currency,
+            buy_in: self.buy_in.unwrap_or(self.bankroll),
+            progression: None,
+            advisor: None,
+            chatter: self.chatter,
+            keys: self.keys,
+            time_limit: self.time_limit,
+            deal_delay: self.deal_delay,
+            dealer_delay: self.dealer_delay,
+            color: self.color,
+            theme: self.theme,
+            locale: self.locale,
+            grade_strategy: self.grade_strategy,
+            show_count: false,
+            show_hints: self.hints,
+            index_plays: self.index_plays,
+            started: Instant::now(),
+            table_shown: false,
+            save_requested: false,
+            quit_requested: false,
+        })
+    }
+}
@@ -0,0 +1,196 @@
+use alloc::collections::BTreeMap;
+
+use super::{Action, GameEvent, Locale, Outcome, Recipient};
+use crate::card::Rank;
+use crate::hand::Hand;
+use crate::prelude::*;
+
+/// The chatter packs built into the game, by name.
+const PACKS: [(&str, &str); 3] = [
+    ("friendly", include_str!("../../chatter/friendly.txt")),
+    ("gruff", include_str!("../../chatter/gruff.txt")),
+    ("amable", include_str!("../../chatter/amable.txt")),
+];
+
+/// The situations the dealer can remark on, as they are named in a chatter pack.
+pub const CHATTER_SITUATIONS: [&str; 12] = [
+    "shuffle",
+    "bet",
+    "ace-up",
+    "double",
+    "split",
+    "blackjack",
+    "win",
+    "dealer-bust",
+    "lose",
+    "bust",
+    "push",
+    "surrender",
+];
+
+/// The reasons a chatter pack cannot be read.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ChatterError {
+    /// A line is not a comment and does not give a situation and its text.
+    #[error("line {0} should be `situation = text`")]
+    Malformed(usize),
+    /// A line names a situation the dealer does not remark on.
+    #[error("line {0}: '{1}' is not a situation, choose one of: shuffle, bet, ace-up, double, split, blackjack, win, dealer-bust, lose, bust, push, surrender")]
+    UnknownSituation(usize, String),
+}
+
+/// What the dealer says as the game goes on: a line for a shuffle, a bet, an ace showing, a double
+/// or a split, and for every hand settled, such as "Sorry, nineteen beats eighteen."
+///
+/// The lines are kept in chatter packs, which can be swapped for one another. A pack is written like
+/// a locale file, each line giving something the dealer can say as `situation = text`, where the
+/// situation is one of `CHATTER_SITUATIONS`, except that a situation can be given many lines, which
+/// are said in turn. In the text of a settled hand, `{ $player }` and `{ $dealer }` are replaced
+/// with the totals of the player's hand and the dealer's in words. Lines starting with `#` are
+/// comments. A situation a pack gives no line for passes without a word.
+///
+/// The chatter follows the game through its `GameEvent`s, keeping its own copy of the hands so it
+/// knows the totals once a hand is settled; `react()` is given each event as it happens and returns
+/// what the dealer says about it, if anything. `named()` gives the built-in packs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Chatter {
+    pub name: String,
+    lines: BTreeMap<String, Vec<String>>,
+    /// How many times each situation has been remarked on, so its lines are said in turn.
+    said: BTreeMap<String, usize>,
+    dealer: Hand,
+    /// The player's hands in the round being played, by ID.
+    hands: Vec<(usize, Hand)>,
+    /// The hand split off another, which the next new hand dealt to carries on.
+    split_off: Option<Hand>,
+}
+
+impl Chatter {
+    /// The names of the built-in packs: a friendly and a gruff dealer in English, and a friendly
+    /// dealer in Spanish.
+    pub const NAMES: [&'static str; 3] = ["friendly", "gruff", "amable"];
+
+    /// Returns the built-in pack called `name`, one of `NAMES`.
+    pub fn named(name: &str) -> Option<Chatter> {
+        let (name, text) = PACKS.iter().find(|(other, _)| *other == name)?;
+        Chatter::parse(name, text).ok()
+    }
+
+    /// Reads the chatter pack `text`, calling it `name`.
+    pub fn parse(name: &str, text: &str) -> Result<Chatter, ChatterError> {
+        let mut lines: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((situation, said)) = line.split_once('=') else {
+                return Err(ChatterError::Malformed(number + 1));
+            };
+            let situation = situation.trim();
+            if !CHATTER_SITUATIONS.contains(&situation) {
+                return Err(ChatterError::UnknownSituation(
+                    number + 1,
+                    situation.to_string(),
+                ));
+            }
+            lines
+                .entry(situation.to_string())
+                .or_default()
+                .push(said.trim().to_string());
+        }
+        Ok(Chatter {
+            name: name.to_string(),
+            lines,
+            said: BTreeMap::new(),
+            dealer: Hand::new(),
+            hands: Vec::new(),
+            split_off: None,
+        })
+    }
+
+    /// Follows `event`, and returns what the dealer says about it, if anything, with totals named
+    /// in words in `locale`.
+    pub fn react(&mut self, event: &GameEvent, locale: &Locale) -> Option<String> {
+        match *event {
+            GameEvent::ShoeShuffled { .. } => self.say("shuffle", &[]),
+            GameEvent::PlayerAction {
+                action: Action::Bet(_),
+                ..
+            } => {
+                self.dealer = Hand::new();
+                self.hands.clear();
+                self.split_off = None;
+                self.say("bet", &[])
+            }
+            GameEvent::PlayerAction {
+                hand,
+                action: Action::Split,
+            } => {
+                let (_, split) = self.hands.iter_mut().find(|(id, _)| *id == hand)?;
+                self.split_off = Some(split.split());
+                self.say("split", &[])
+            }
+            GameEvent::PlayerAction {
+                action: Action::Double,
+                ..
+            } => self.say("double", &[]),
+            GameEvent::CardDealt {
+                card,
+                recipient: Recipient::Dealer,
+                face_up,
+            } => {
+                self.dealer.add(card);
+                if face_up && self.dealer.len() == 1 && card.rank == Rank::Ace {
+                    self.say("ace-up", &[])
+                } else {
+                    None
+                }
+            }
+            GameEvent::CardDealt {
+                card,
+                recipient: Recipient::Player(id),
+                ..
+            } => {
+                match self.hands.iter_mut().find(|(other, _)| *other == id) {
+                    Some((_, hand)) => hand.add(card),
+                    None => {
+                        let mut hand = self.split_off.take().unwrap_or_default();
+                        hand.add(card);
+                        self.hands.push((id, hand));
+                    }
+                }
+                None
+            }
+            GameEvent::HandResolved { hand, outcome, .. } => {
+                let (_, hand) = self.hands.iter().find(|(id, _)| *id == hand)?;
+                let situation = match outcome {
+                    Outcome::Win(_) if hand.is_blackjack() => "blackjack",
+                    Outcome::Win(_) if self.dealer.is_bust() => "dealer-bust",
+                    Outcome::Win(_) => "win",
+                    Outcome::Lose if hand.is_bust() => "bust",
+                    Outcome::Lose => "lose",
+                    Outcome::Push => "push",
+                    Outcome::Surrender => "surrender",
+                };
+                let player = locale.value_in_words(hand.value());
+                let dealer = locale.value_in_words(self.dealer.value());
+                self.say(situation, &[("player", &player), ("dealer", &dealer)])
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the next line for `situation`, with each `{ $name }` in it replaced by the value of
+    /// that name in `args`, or `None` if the pack has nothing to say about it.
+    fn say(&mut self, situation: &str, args: &[(&str, &String)]) -> Option<String> {
+        let lines = self.lines.get(situation)?;
+        let said = self.said.entry(situation.to_string()).or_insert(0);
+        let mut line = lines[*said % lines.len()].clone();
+        *said += 1;
+        for (name, value) in args {
+            line = line.replace(&format!("{{ ${} }}", name), value);
+        }
+        Some(line)
+    }
+}
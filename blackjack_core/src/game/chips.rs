@@ -0,0 +1,132 @@
+use super::Wager;
+use crate::prelude::*;
+
+/// A viewer implementation that formats an amount of chips as money in a given locale.
+///
+/// The currency symbol can be placed before or after the amount, digits are grouped in thousands
+/// with the locale's separator, and amounts can be shown with or without two decimal places,
+/// so 1250 chips can be shown as "$1,250" or "1.250,00 €".
+#[derive(Clone)]
+pub struct CurrencyFormat {
+    pub symbol: &'static str,
+    pub symbol_after: bool,
+    pub thousands_separator: char,
+    pub decimal_separator: char,
+    pub show_decimals: bool,
+}
+
+impl CurrencyFormat {
+    pub const DOLLARS: CurrencyFormat = CurrencyFormat {
+        symbol: "$",
+        symbol_after: false,
+        thousands_separator: ',',
+        decimal_separator: '.',
+        show_decimals: false,
+    };
+    pub const DOLLARS_AND_CENTS: CurrencyFormat = CurrencyFormat {
+        show_decimals: true,
+        ..CurrencyFormat::DOLLARS
+    };
+    pub const EUROS: CurrencyFormat = CurrencyFormat {
+        symbol: "€",
+        symbol_after: true,
+        thousands_separator: '.',
+        decimal_separator: ',',
+        show_decimals: true,
+    };
+    pub const POUNDS: CurrencyFormat = CurrencyFormat {
+        symbol: "£",
+        ..CurrencyFormat::DOLLARS
+    };
+
+    /// Formats `amount`, optionally with decimal places, leaving the sign to the caller.
+    pub fn format(&self, amount: u64, show_decimals: bool) -> String {
+        let digits = amount.to_string();
+        let mut grouped = String::new();
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                grouped.push(self.thousands_separator);
+            }
+            grouped.push(digit);
+        }
+        if show_decimals {
+            grouped.push(self.decimal_separator);
+            grouped.push_str("00");
+        }
+
+        if self.symbol_after {
+            format!("{} {}", grouped, self.symbol)
+        } else {
+            format!("{}{}", self.symbol, grouped)
+        }
+    }
+
+    /// Formats a net result with an explicit sign, such as "+$10" or "-10,00 €".
+    pub fn draw_signed(&self, amount: i64) -> String {
+        let sign = match amount.signum() {
+            1 => "+",
+            -1 => "-",
+            _ => "",
+        };
+        format!(
+            "{}{}",
+            sign,
+            self.format(amount.unsigned_abs(), self.show_decimals)
+        )
+    }
+
+    /// Formats an amount of chips for display, such as "$1,250".
+    pub fn draw(&self, chips: u32) -> String {
+        self.format(chips as u64, self.show_decimals)
+    }
+}
+
+/// A viewer implementation that displays an amount of chips as a stack of casino chip denominations.
+///
+/// This viewer makes change the way a dealer would at a real table, always using the largest
+/// denominations first, so 115 chips are shown as "1×$100 + 3×$5" rather than a bare number.
+/// Denominations are labelled using the table's `CurrencyFormat`. A `plain` view writes the
+/// count and denomination with an ASCII "x", as in "1x$100", for output read by other programs.
+pub struct ChipView {
+    pub currency: CurrencyFormat,
+    pub plain: bool,
+}
+
+impl ChipView {
+    /// The chip denominations available at the table, from largest to smallest.
+    pub const DENOMINATIONS: [u32; 6] = [1000, 500, 100, 25, 5, 1];
+
+    /// Shows an amount of chips as a stack of denominations, such as "1×$100 + 3×$5".
+    pub fn draw(&self, chips: u32) -> String {
+        let mut remaining = chips;
+        let mut stacks = Vec::new();
+
+        for denomination in ChipView::DENOMINATIONS {
+            let count = remaining / denomination;
+            if count > 0 {
+                stacks.push(format!(
+                    "{}{}{}",
+                    count,
+                    if self.plain { "x" } else { "×" },
+                    self.currency.format(denomination as u64, false)
+                ));
+                remaining -= count * denomination;
+            }
+        }
+
+        if stacks.is_empty() {
+            "no chips".to_string()
+        } else {
+            stacks.join(" + ")
+        }
+    }
+
+    /// Shows the chips riding on a wager, noting any portion covered by the house.
+    pub fn draw_wager(&self, wager: &Wager) -> String {
+        match (wager.stake, wager.free) {
+            (stake, 0) => self.draw(stake),
+            (0, free) => format!("{} free", self.draw(free)),
+            (stake, free) => format!("{} (+ {} free)", self.draw(stake), self.draw(free)),
+        }
+    }
+}
@@ -0,0 +1,1329 @@
+use std::io::Write;
+use std::ops::Range;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{
+    settle_hands, Accuracy, Action, Bankroll, BasicStrategy, BetAdvisor, BetError,
+    BettingProgression, CardCounter, Chatter, ChipView, CurrencyFormat, Engine, GameEvent,
+    GameObserver, GameState, GameView, KeyBindings, Locale, Odds, Outcome, Payout, PlayerHand,
+    PlayerStrategy, PromptStrategy, Recipient, Seat, Settlement, Snapshot, TableFrame, TableView,
+    Theme, HAND_KINDS, INSURANCE_PAYS, UP_CARD_NAMES,
+};
+use crate::error::BlackjackError;
+use crate::hand::Hand;
+use crate::{GameEngine, PlayerInput, Renderer};
+
+/// Returns `text` written in the colour with ANSI code `colour`, or as it is if colours are off or
+/// there is no colour.
+fn paint(text: &str, colour: &str, on: bool) -> String {
+    if on {
+        Theme::paint(text, colour)
+    } else {
+        text.to_string()
+    }
+}
+
+/// A game controller that manages the game logic and flow for a card game.
+///
+/// The `GameController` plays its rounds through an `Engine`, which keeps the shoe, the hands, the
+/// bankroll and the statistics, settles the wagers and plays any opponents, so the game played
+/// here is the same one played by every other frontend. The controller asks for each bet and
+/// decision, steps the engine with it and shows what happened.
+///
+/// The `GameController` uses a `Renderer` to display the cards in the player's and dealer's hands. This allows the game to be displayed in different formats, such as using card glyphs or alphabetic rank and suit names.
+/// Once the cards are dealt, the whole table is drawn as one frame by a `TableView`, and drawn again
+/// whenever it changes if the view redraws it; `table_shown` is true while the last frame drawn
+/// still shows the table as it is.
+///
+/// The rules that differ between games, such as how many hands the player is dealt and how hands are
+/// settled, are delegated to the engine's `Variant`, and table rules such as the number of decks,
+/// when the dealer hits and whether the player may surrender to its `RuleSet`.
+/// Any side bets offered at the table are settled on the opening cards and reported alongside the main hands.
+/// Every wager is taken from, and settled back into, the player's `Bankroll`. A player who can no
+/// longer cover the table minimum may buy back in for `buy_in` chips, which are added to whatever
+/// is left. When an automatic
+/// `BettingProgression` is chosen, it picks the main bet each round instead of the player.
+///
+/// The engine's table holds a `Seat` for the player and one for each opponent, and every seat is
+/// dealt to and plays its hands in casino order, from first base round to third base.
+/// The player's seat can be handed to a `PlayerStrategy`, the `strategy`, such as a bot, which then
+/// bets and plays every hand; the player is otherwise prompted for each decision through a
+/// `PromptStrategy`.
+/// The player's choices are read through a `PlayerInput`, which defaults to the keyboard, and
+/// everything the game shows is written to `output`, which defaults to stdout but can be any
+/// `io::Write` sink, such as a buffer or a network stream. Each card dealt, move made and hand
+/// settled is also reported as a `GameEvent` to any subscribed `GameObserver`s, and to the dealer's
+/// `chatter`, if a chatter pack is chosen, whose remarks are written with the rest of the game.
+///
+/// Between rounds the player can choose to save and quit, which sets `save_requested`; the session
+/// is then taken with `snapshot()` and carried on later by giving it to `restore()`. The player can
+/// also quit at any decision, which sets `quit_requested`: the rest of their hands stand, the round
+/// is settled as usual, and `run()` then offers to save before the game ends. With a `time_limit`, a
+/// player who does not decide in time stands. With `color` on, wins, blackjacks and busts are
+/// written in the colours of the `theme`, which by default are green, gold and red. Everything the
+/// game writes is taken from the `locale`, which is English unless another is chosen.
+///
+/// Cards can be dealt at the pace of a real table: the game pauses for `deal_delay` before each
+/// card dealt to the player is shown, and for `dealer_delay` before the dealer turns over the hole
+/// card and before each card the dealer draws. Both are zero unless set, so the game does not wait.
+///
+/// The count is kept by the engine's `CardCounter`. With
+/// `show_count` on, the count of the cards seen so far is shown on the table, or after
+/// each hand is shown if the table view does not redraw it, and before each bet; the player turns it
+/// on and off with the count key at a decision, or by typing 'count' at a bet. With `show_hints` on,
+/// every decision the player makes is preceded by hints: the chance of busting with another card,
+/// the totals the dealer is likeliest to finish on and the basic strategy play. With `grade_strategy` on, every decision the player makes is checked against basic strategy and
+/// counted in the statistics, and the session summary shows how many matched. The summary also gives
+/// the time played, counted from `started` on top of the time played before the session was saved.
+///
+/// A `GameController` is put together, and its settings checked, with a `GameBuilder`.
+///
+/// The `GameController` implements `GameEngine`, whose `run()` method encapsulates the entire game loop, allowing the game to be easily played and restarted.
+pub struct GameController {
+    pub engine: Engine,
+    pub strategy: Option<Box<dyn PlayerStrategy>>,
+    pub viewer: Box<dyn Renderer>,
+    pub table_view: Box<dyn TableView>,
+    pub input: Box<dyn PlayerInput>,
+    pub output: Box<dyn Write>,
+    pub last_bet: Option<u32>,
+    pub bet_presets: Vec<u32>,
+    pub chip_view: ChipView,
+    pub currency: CurrencyFormat,
+    pub buy_in: u32,
+    pub progression: Option<Box<dyn BettingProgression>>,
+    pub advisor: Option<Box<dyn BetAdvisor>>,
+    pub chatter: Option<Chatter>,
+    pub keys: KeyBindings,
+    pub time_limit: Option<Duration>,
+    pub deal_delay: Duration,
+    pub dealer_delay: Duration,
+    pub color: bool,
+    pub theme: Theme,
+    pub locale: Locale,
+    pub grade_strategy: bool,
+    pub show_count: bool,
+    pub show_hints: bool,
+    pub index_plays: bool,
+    pub started: Instant,
+    pub table_shown: bool,
+    pub save_requested: bool,
+    pub quit_requested: bool,
+}
+
+impl GameController {
+    /// The number of the dealer's likeliest finishing totals given in the hints.
+    const HINT_TOTALS: usize = 3;
+
+    /// Seeds the entropy source and starts a freshly shuffled shoe, so that the same seed deals the
+    /// same cards and opponents' bets given the same choices.
+    pub fn seed(&mut self, seed: u64) {
+        self.engine.seed(seed);
+    }
+
+    /// Takes a snapshot of the session between rounds: the table rules and limits, the shoe in
+    /// dealing order, the bankroll and the session statistics. The cards of the last round are
+    /// gathered into the discards, as they would be before the next one is dealt.
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = self.engine.snapshot();
+        let table = self.engine.table();
+        for player_hand in table.seats.iter().flat_map(|seat| &seat.hands) {
+            snapshot
+                .shoe
+                .return_cards(player_hand.hand.cards().iter().copied());
+        }
+        for hand in self.engine.others() {
+            snapshot.shoe.return_cards(hand.cards().iter().copied());
+        }
+        snapshot
+            .shoe
+            .return_cards(self.engine.dealer_hand().cards().iter().copied());
+        snapshot.stats.seconds_played = self.seconds_played();
+        Snapshot {
+            player_hands: Vec::new(),
+            dealer_hand: Hand::new(),
+            others: Vec::new(),
+            state: GameState::AwaitingBet,
+            ..snapshot
+        }
+    }
+
+    /// Carries on a session saved with `snapshot()`, replacing the rules, limits, shoe, bankroll and
+    /// statistics. The variant and the rest of the table are kept as they are, and the dealer policy
+    /// is picked again for the restored rules.
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        self.engine.resume(snapshot);
+        let policy = self.engine.variant().dealer_policy(self.engine.rules());
+        self.engine.set_dealer_policy(policy);
+        self.started = Instant::now();
+    }
+
+    /// Returns the seconds played in the session, including those played before it was saved.
+    pub fn seconds_played(&self) -> u64 {
+        self.engine.stats().seconds_played + self.started.elapsed().as_secs()
+    }
+
+    /// Returns the chips the player has available to bet.
+    pub fn bankroll(&self) -> &Bankroll {
+        self.engine.bankroll()
+    }
+
+    /// Subscribes an observer to the events of every round from now on.
+    pub fn subscribe(&mut self, observer: Box<dyn GameObserver>) {
+        self.engine.subscribe(observer);
+    }
+
+    /// Writes what the dealer says about an event, if anything.
+    fn chatter(&mut self, event: &GameEvent) {
+        let Some(line) = self
+            .chatter
+            .as_mut()
+            .and_then(|chatter| chatter.react(event, &self.locale))
+        else {
+            return;
+        };
+        // The chatter is only flavour, so a line that cannot be written is not worth stopping for
+        let _ = writeln!(
+            self.output,
+            "{}",
+            self.locale.message("chatter", &[("line", &line)])
+        );
+    }
+
+    /// Steps the engine with `action` and follows the events it reports: the shuffles, which are
+    /// announced, the cards, which change the table, and what the dealer says about each. What the
+    /// dealer says about the hands settled is left for `determine_winner()`, to follow the results.
+    /// The opening deal is shown a card at a time on a table view that redraws, pausing for
+    /// `deal_delay` before each card, as the cards would otherwise only be seen once all are dealt.
+    fn step(&mut self, action: Action) -> Result<GameState, BlackjackError> {
+        let mut dealing = matches!(action, Action::Bet(_))
+            && self.table_view.redraws()
+            && !self.deal_delay.is_zero();
+        let mut counter = *self.engine.counter();
+        let mut cards_remaining = self.engine.shoe().cards_remaining();
+        let mut dealer_cards = 0;
+        let state = self.engine.step(action)?;
+        let events = self.engine.events().to_vec();
+        for (index, event) in events.iter().enumerate() {
+            match *event {
+                GameEvent::ShoeShuffled { decks } => {
+                    writeln!(self.output, "{}", self.locale.text("shuffling"))?;
+                    cards_remaining = 52 * decks;
+                }
+                GameEvent::CardDealt { recipient, .. } => {
+                    if dealing {
+                        self.show_dealing(&events[..index], counter, cards_remaining)?;
+                        // The opening deal ends with the dealer's second card
+                        if recipient == Recipient::Dealer {
+                            dealer_cards += 1;
+                            dealing = dealer_cards < 2;
+                        }
+                    }
+                    cards_remaining = cards_remaining.saturating_sub(1);
+                    self.table_shown = false;
+                }
+                GameEvent::DealerReveal { .. } => self.table_shown = false,
+                GameEvent::HandResolved { .. } => continue,
+                GameEvent::PlayerAction { .. } => {}
+            }
+            counter.on_event(event);
+            self.chatter(event);
+        }
+        Ok(state)
+    }
+
+    /// Draws the table as it stood once the cards of `dealt` were dealt, with `count` and
+    /// `cards_remaining` as they were then, and waits for `deal_delay` before the next card.
+    fn show_dealing(
+        &mut self,
+        dealt: &[GameEvent],
+        count: CardCounter,
+        cards_remaining: usize,
+    ) -> Result<(), BlackjackError> {
+        let table = self.engine.table();
+        let mut seats: Vec<Seat> = table
+            .seats
+            .iter()
+            .map(|seat| Seat {
+                name: seat.name,
+                bankroll: seat.bankroll.clone(),
+                hands: seat
+                    .hands
+                    .iter()
+                    .map(|player_hand| PlayerHand {
+                        hand: Hand::new(),
+                        ..player_hand.clone()
+                    })
+                    .collect(),
+                strategy: None,
+            })
+            .collect();
+        let mut dealer_hand = Hand::new();
+        for event in dealt {
+            let GameEvent::CardDealt {
+                card,
+                recipient,
+                face_up,
+            } = *event
+            else {
+                continue;
+            };
+            let (seat, id) = match recipient {
+                Recipient::Dealer if face_up => {
+                    dealer_hand.add(card);
+                    continue;
+                }
+                Recipient::Dealer => {
+                    dealer_hand.add_face_down(card);
+                    continue;
+                }
+                Recipient::Player(id) => (table.player, id),
+                Recipient::Opponent { seat, hand } => (seat, hand),
+            };
+            let player_hand = seats
+                .get_mut(seat)
+                .and_then(|seat| seat.hands.iter_mut().find(|hand| hand.id == id));
+            if let Some(player_hand) = player_hand {
+                player_hand.hand.add(card);
+            }
+        }
+        let frame = TableFrame {
+            dealer_hand: &dealer_hand,
+            player_hands: &seats[table.player].hands,
+            opponents: seats
+                .iter()
+                .enumerate()
+                .filter(|(seat, _)| *seat != table.player)
+                .map(|(_, seat)| seat)
+                .collect(),
+            bankroll: self.engine.bankroll().balance(),
+            bankroll_history: &self.engine.stats().bankroll_history,
+            count: self.show_count.then_some(count),
+            cards_remaining,
+            decks: self.engine.shoe().decks(),
+        };
+        self.table_view
+            .render(&frame, &*self.viewer, &mut *self.output)?;
+        self.output.flush()?;
+        thread::sleep(self.deal_delay);
+        Ok(())
+    }
+
+    /// Waits for `delay` before the next card is shown, so the cards appear one at a time. A table
+    /// view that redraws is drawn first, so the cards dealt so far are seen while waiting.
+    fn pause(&mut self, delay: Duration) -> Result<(), BlackjackError> {
+        if delay.is_zero() {
+            return Ok(());
+        }
+        if self.table_view.redraws() {
+            self.redraw_table()?;
+        }
+        self.output.flush()?;
+        thread::sleep(delay);
+        Ok(())
+    }
+
+    /// Prompts the player for a whole number of chips, re-prompting until the input is valid.
+    fn prompt_chips(&mut self, message: &str) -> Result<u32, BlackjackError> {
+        loop {
+            writeln!(self.output, "{}", message)?;
+            match self.input.read_choice()?.parse::<u32>() {
+                Ok(amount) => return Ok(amount),
+                Err(_) => writeln!(self.output, "{}", self.locale.text("invalid-amount"))?,
+            }
+        }
+    }
+
+    /// Reads a main bet from the player, accepting a number of chips or a quick-bet shortcut.
+    ///
+    /// Pressing Enter or 'r' repeats the previous bet, 'm' bets the most the table and bankroll allow,
+    /// and the letters shown next to each preset bet that amount. Typing 'presets' followed by a list
+    /// of amounts replaces the presets, and typing 'count' shows or hides the count. Returns `None`
+    /// when the input did not produce a bet.
+    fn prompt_bet(&mut self, hands: u32) -> Result<Option<u32>, BlackjackError> {
+        if self.show_count {
+            writeln!(self.output, "{}", self.locale.count(self.engine.counter()))?;
+        }
+        let limits = self.engine.limits();
+        let keys = ('a'..='z').take(self.bet_presets.len());
+        let presets = keys
+            .zip(&self.bet_presets)
+            .map(|(key, amount)| format!("({}) {}", key, amount))
+            .collect::<Vec<String>>()
+            .join(" ");
+        writeln!(
+            self.output,
+            "{}",
+            self.locale.message(
+                "bet-prompt",
+                &[
+                    ("minimum", &self.currency.draw(limits.minimum)),
+                    ("maximum", &self.currency.draw(limits.maximum)),
+                ]
+            )
+        )?;
+        let quick_bets = match self.last_bet {
+            Some(bet) => self
+                .locale
+                .message("quick-bets-repeat", &[("bet", &bet), ("presets", &presets)]),
+            None => self.locale.message("quick-bets", &[("presets", &presets)]),
+        };
+        writeln!(self.output, "{}", quick_bets)?;
+
+        let choice = self.input.read_choice()?;
+        if choice == "count" {
+            self.show_count = !self.show_count;
+            if !self.show_count {
+                writeln!(self.output, "{}", self.locale.text("count-hidden"))?;
+            }
+            return Ok(None);
+        }
+        if let Some(amounts) = choice.strip_prefix("presets") {
+            let parsed = amounts
+                .split_whitespace()
+                .map(|amount| amount.parse::<u32>())
+                .collect::<Result<Vec<u32>, _>>();
+            match parsed {
+                Ok(amounts) if !amounts.is_empty() && amounts.len() <= 26 => {
+                    self.bet_presets = amounts;
+                    writeln!(self.output, "{}", self.locale.text("presets-updated"))?;
+                }
+                _ => writeln!(self.output, "{}", self.locale.text("presets-invalid"))?,
+            }
+            return Ok(None);
+        }
+
+        let bet = match choice.as_str() {
+            "" | "r" => {
+                if self.last_bet.is_none() {
+                    writeln!(self.output, "{}", self.locale.text("no-previous-bet"))?;
+                }
+                self.last_bet
+            }
+            "m" => Some(limits.maximum.min(self.engine.bankroll().balance() / hands)),
+            _ => {
+                let preset = match choice.as_bytes() {
+                    [key @ b'a'..=b'z'] => self.bet_presets.get((key - b'a') as usize),
+                    _ => None,
+                };
+                if let Some(&amount) = preset {
+                    return Ok(Some(amount));
+                }
+                match choice.parse::<u32>() {
+                    Ok(amount) => Some(amount),
+                    Err(_) => {
+                        writeln!(self.output, "{}", self.locale.text("invalid-bet"))?;
+                        None
+                    }
+                }
+            }
+        };
+        Ok(bet)
+    }
+
+    /// Writes the hints for the decision in `view`: the chance of busting if the player hits, the
+    /// totals the dealer is likeliest to finish on and the basic strategy play.
+    fn show_hints(&mut self, view: &GameView) -> Result<(), BlackjackError> {
+        let (Some(hand), Some(up_card)) = (&view.hand, view.dealer_up_card) else {
+            return Ok(());
+        };
+        if !view.legal_actions.contains(&Action::Hit) {
+            return Ok(());
+        }
+        let dealer_hand = self.engine.dealer_hand();
+        let mut unseen = self.engine.shoe().cards().to_vec();
+        unseen.extend(
+            (0..dealer_hand.len())
+                .filter(|&index| dealer_hand.is_face_down(index))
+                .map(|index| dealer_hand.cards()[index]),
+        );
+        let odds = Odds::new(
+            hand,
+            up_card,
+            &unseen,
+            self.engine.dealer_policy(),
+            self.engine.rules().dealer_peeks,
+        );
+        let percent = |chance: f64| format!("{:.0}%", chance * 100.0);
+        let totals = odds
+            .dealer_totals
+            .iter()
+            .take(Self::HINT_TOTALS)
+            .map(|(total, chance)| {
+                let total = match total {
+                    Some(total) => total.to_string(),
+                    None => self.locale.text("hint-bust"),
+                };
+                self.locale.message(
+                    "hint-total",
+                    &[("total", &total), ("chance", &percent(*chance))],
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let play = BasicStrategy::advise(view, self.index_plays).unwrap_or(Action::Stand);
+        let lines = [
+            self.locale.text("hints"),
+            self.locale.message(
+                "hint-bust-if-hit",
+                &[("chance", &percent(odds.bust_if_hit))],
+            ),
+            self.locale.message("hint-dealer", &[("totals", &totals)]),
+            self.locale
+                .message("hint-play", &[("action", &self.locale.action(play))]),
+        ];
+        for line in lines {
+            writeln!(self.output, "{}", line)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the count on a line of its own, while the player has it shown and the table view
+    /// does not already show it on the table.
+    fn show_count(&mut self) -> Result<(), BlackjackError> {
+        if self.show_count && !self.table_view.redraws() {
+            writeln!(self.output, "{}", self.locale.count(self.engine.counter()))?;
+        }
+        Ok(())
+    }
+
+    /// Asks the strategy playing the player's seat for one of the view's legal actions, or prompts
+    /// the player for one if the seat has no strategy. Choices made by a strategy are shown.
+    fn decide(&mut self, view: &GameView) -> Result<Action, BlackjackError> {
+        let Some(strategy) = &mut self.strategy else {
+            // A player who is leaving stands on every hand left to play
+            if self.quit_requested {
+                writeln!(self.output, "{}", self.locale.text("leaving-stand"))?;
+                return Ok(match view.legal_actions.contains(&Action::KeepHands) {
+                    true => Action::KeepHands,
+                    false => Action::Stand,
+                });
+            }
+            // The hints weigh up playing a hand, which insurance is not
+            if self.show_hints && !view.legal_actions.contains(&Action::Insure) {
+                self.show_hints(view)?;
+            }
+            let mut prompt = PromptStrategy::new(&mut *self.input, &mut *self.output);
+            prompt.keys = self.keys.clone();
+            prompt.time_limit = self.time_limit;
+            prompt.locale = self.locale.clone();
+            prompt.show_count = self.show_count;
+            prompt.index_plays = self.index_plays;
+            let action = prompt.decide(view)?;
+            self.quit_requested = prompt.quit_requested;
+            if prompt.show_count != self.show_count {
+                self.show_count = prompt.show_count;
+                self.table_shown = false;
+            }
+            // Hands stood on by a player who quit are not their own decisions
+            if self.grade_strategy && !self.quit_requested {
+                if let (Some(hand), Some(up_card), Some(play)) = (
+                    &view.hand,
+                    view.dealer_up_card,
+                    BasicStrategy::advise(view, self.index_plays),
+                ) {
+                    self.engine
+                        .stats_mut()
+                        .record_decision(hand, up_card, action == play);
+                }
+            }
+            return Ok(action);
+        };
+        let action = strategy.decide(view)?;
+        if !view.legal_actions.contains(&action) {
+            return Err(BlackjackError::IllegalAction {
+                action,
+                legal: view.legal_actions.clone(),
+            });
+        }
+        let message = self.locale.message(
+            "strategy-chooses",
+            &[
+                ("name", &strategy.name()),
+                ("action", &self.locale.action(action)),
+            ],
+        );
+        writeln!(self.output, "{}", message)?;
+        Ok(action)
+    }
+
+    /// Shows the running and true counts of the cards dealt so far, and the bet the advisor
+    /// suggests, when a bet advisor has been chosen. With the ace side count on, the Aces left are
+    /// shown too, and the bet is suggested from the count they adjust.
+    fn show_advice(&mut self) -> Result<(), BlackjackError> {
+        let Some(advisor) = &self.advisor else {
+            return Ok(());
+        };
+        let counter = self.engine.counter();
+        let limits = self.engine.limits();
+        let true_count = counter.true_count();
+        let suggestion = advisor
+            .suggest(
+                counter.betting_count(),
+                limits.minimum,
+                self.engine.bankroll().balance(),
+            )
+            .clamp(limits.minimum, limits.maximum);
+
+        let message = self.locale.message(
+            "advice",
+            &[
+                ("running", &format!("{:+}", counter.running_count())),
+                ("true", &format!("{:+.1}", true_count)),
+                ("decks", &format!("{:.1}", counter.decks_remaining())),
+                ("advisor", &advisor.name()),
+                ("bet", &self.currency.draw(suggestion)),
+            ],
+        );
+        writeln!(self.output, "{}", message)?;
+        if counter.ace_side_count {
+            let message = self.locale.message(
+                "advice-aces",
+                &[
+                    ("aces", &counter.aces_remaining().to_string()),
+                    ("surplus", &format!("{:+.1}", counter.ace_surplus())),
+                    ("betting", &format!("{:+.1}", counter.betting_count())),
+                ],
+            );
+            writeln!(self.output, "{}", message)?;
+        }
+        Ok(())
+    }
+
+    /// Checks that a main bet of `bet` chips on each of `hands` hands is within the table limits and
+    /// can be covered by the bankroll.
+    fn check_bet(&self, bet: u32, hands: u32) -> Result<(), BetError> {
+        self.engine.limits().validate(bet)?;
+        let balance = self.engine.bankroll().balance();
+        if balance < bet.saturating_mul(hands) {
+            return Err(BetError::InsufficientChips(balance));
+        }
+        Ok(())
+    }
+
+    /// Asks the strategy playing the player's seat for the main bet, keeping it within the table
+    /// limits and what the bankroll can cover on every hand.
+    fn strategy_bet(&mut self) -> Result<u32, BlackjackError> {
+        let view = self.engine.view();
+        let minimum = self.engine.limits().minimum;
+        let Some(strategy) = &mut self.strategy else {
+            return Ok(minimum);
+        };
+        let bet = strategy.bet(&view)?.min(view.max_bet()).max(minimum);
+        let message = self.locale.message(
+            "strategy-bets",
+            &[
+                ("name", &strategy.name()),
+                ("bet", &self.currency.draw(bet)),
+            ],
+        );
+        writeln!(self.output, "{}", message)?;
+        Ok(bet)
+    }
+
+    /// Asks the player for their main bet and any side bets, and deals the round with them.
+    ///
+    /// The main bet is placed on every hand the variant deals, so it must be covered that many times,
+    /// and the side bets out of what is left.
+    fn take_bets(&mut self) -> Result<(), BlackjackError> {
+        let hands = self.engine.variant().hands_per_player() as u32;
+        let limits = self.engine.limits();
+        self.show_bankroll()?;
+
+        self.show_advice()?;
+
+        let bet = match &self.progression {
+            // A strategy playing the player's seat places its own bets
+            _ if self.strategy.is_some() => self.strategy_bet()?,
+            Some(progression) => {
+                let wanted = progression.next_bet();
+                let bet = wanted
+                    .min(limits.maximum)
+                    .min(self.engine.bankroll().balance() / hands)
+                    .max(limits.minimum);
+                let message = if bet == wanted {
+                    self.locale.message(
+                        "strategy-bets",
+                        &[
+                            ("name", &progression.name()),
+                            ("bet", &self.currency.draw(bet)),
+                        ],
+                    )
+                } else {
+                    self.locale.message(
+                        "progression-capped",
+                        &[
+                            ("name", &progression.name()),
+                            ("wanted", &self.currency.draw(wanted)),
+                            ("bet", &self.currency.draw(bet)),
+                        ],
+                    )
+                };
+                writeln!(self.output, "{}", message)?;
+                bet
+            }
+            None => loop {
+                let bet = match self.prompt_bet(hands)? {
+                    Some(bet) => bet,
+                    None => continue,
+                };
+                match self.check_bet(bet, hands) {
+                    Ok(()) => break bet,
+                    Err(error) => writeln!(self.output, "{}", self.locale.bet_error(&error))?,
+                }
+            },
+        };
+        self.last_bet = Some(bet);
+
+        let mut left = self
+            .engine
+            .bankroll()
+            .balance()
+            .saturating_sub(bet.saturating_mul(hands));
+        let mut wagers = Vec::new();
+        for index in 0..self.engine.side_bets().len() {
+            // Strategies only place the main bet
+            if self.strategy.is_some() {
+                wagers.push(0);
+                continue;
+            }
+            let name = self.engine.side_bets()[index].name();
+            let wager = loop {
+                let prompt = self.locale.message("side-bet-prompt", &[("name", &name)]);
+                let wager = self.prompt_chips(&prompt)?;
+                if let Err(error) = limits.validate_side_bet(wager) {
+                    writeln!(self.output, "{}", self.locale.bet_error(&error))?;
+                    continue;
+                }
+                if wager <= left {
+                    break wager;
+                }
+                let message = self
+                    .locale
+                    .message("only-left", &[("amount", &self.currency.draw(left))]);
+                writeln!(self.output, "{}", message)?;
+            };
+            left -= wager;
+            wagers.push(wager);
+        }
+        self.engine.set_side_bet_wagers(wagers);
+
+        self.step(Action::Bet(bet))?;
+        Ok(())
+    }
+
+    /// Returns everything on the table, for the table view to draw.
+    pub fn frame(&self) -> TableFrame<'_> {
+        // A table view that is not drawn again has the count written after each hand instead
+        let count = self.show_count && self.table_view.redraws();
+        TableFrame {
+            count: count.then_some(*self.engine.counter()),
+            ..self.engine.frame()
+        }
+    }
+
+    /// Draws the whole table as one frame with the table view.
+    fn show_table(&mut self) -> Result<(), BlackjackError> {
+        // The frame is built from the engine directly, as the output is borrowed mutably alongside it
+        let count = self.show_count && self.table_view.redraws();
+        let table = TableFrame {
+            count: count.then_some(*self.engine.counter()),
+            ..self.engine.frame()
+        };
+        self.table_view
+            .render(&table, &*self.viewer, &mut *self.output)?;
+        self.table_shown = true;
+        Ok(())
+    }
+
+    /// Draws the table again if it has changed since the last frame was drawn.
+    fn redraw_table(&mut self) -> Result<(), BlackjackError> {
+        if self.table_shown {
+            return Ok(());
+        }
+        self.show_table()
+    }
+
+    /// Shows the player's hand at `index` after it changes, followed by its total if `total` is
+    /// true, or draws the whole table again if the table view redraws it.
+    fn show_hand(&mut self, index: usize, total: bool) -> Result<(), BlackjackError> {
+        if self.table_view.redraws() {
+            return self.redraw_table();
+        }
+        if let Some(label) = self.hand_label(index) {
+            write!(self.output, "{}: ", label)?;
+        }
+        let hand = &self.engine.player_hands()[index].hand;
+        write!(self.output, "{} ", self.locale.text("hand-shown"))?;
+        self.viewer.render(hand, &mut *self.output)?;
+        writeln!(self.output)?;
+        if total {
+            let total = hand.calculate_hand_total();
+            writeln!(
+                self.output,
+                "{}",
+                self.locale.message("your-total", &[("total", &total)])
+            )?;
+        }
+        self.show_count()
+    }
+
+    /// Returns the label used to refer to the hand at `index`, such as "Hand 2 of 3", or `None` for
+    /// a single hand.
+    fn hand_label(&self, index: usize) -> Option<String> {
+        let hands = self.engine.player_hands();
+        (hands.len() > 1).then(|| hands[index].label(hands.len(), &self.locale))
+    }
+
+    /// Offers the player insurance against the dealer's Ace.
+    fn insurance_phase(&mut self) -> Result<(), BlackjackError> {
+        let view = self.engine.view();
+        let action = self.decide(&view)?;
+        self.step(action)?;
+        Ok(())
+    }
+
+    /// Offers the player the chance to swap the second cards of their two hands.
+    fn switch_phase(&mut self) -> Result<(), BlackjackError> {
+        if self.table_view.redraws() {
+            self.redraw_table()?;
+        } else {
+            for index in 0..self.engine.player_hands().len() {
+                self.show_hand(index, false)?;
+            }
+        }
+        let view = self.engine.view();
+        let action = self.decide(&view)?;
+        self.step(action)?;
+        if action == Action::Switch {
+            self.table_shown = false;
+            writeln!(self.output, "{}", self.locale.text("cards-switched"))?;
+        }
+        Ok(())
+    }
+
+    /// Plays the player's hands in turn, offering the chance to switch first in variants that allow
+    /// it, and asking the strategy playing the seat, or prompting the player, for every decision.
+    /// Hands created by a split are played in turn once the hand before them is finished. Every
+    /// hand is shown, including those the engine stands as they are dealt.
+    fn play_hands(&mut self) -> Result<(), BlackjackError> {
+        if *self.engine.state() == GameState::AwaitingSwitch {
+            self.switch_phase()?;
+        }
+        let mut index = 0;
+        while index < self.engine.player_hands().len() {
+            let hand = &self.engine.player_hands()[index].hand;
+            // Split aces receive a single card each
+            let split_aces = hand.is_split_aces();
+            let charlie = self.engine.rules().is_charlie(hand);
+            let cards = hand.len();
+            self.show_hand(index, !split_aces && !charlie)?;
+            if charlie {
+                let message = self.locale.message("charlie-cards", &[("cards", &cards)]);
+                writeln!(self.output, "{}", message)?;
+            }
+            if *self.engine.state() != (GameState::AwaitingAction { hand: index }) {
+                index += 1;
+                continue;
+            }
+
+            let view = self.engine.view();
+            let action = self.decide(&view)?;
+            match action {
+                Action::Double if view.free_double => {
+                    writeln!(self.output, "{}", self.locale.text("free-double"))?
+                }
+                Action::Split if view.free_split => {
+                    writeln!(self.output, "{}", self.locale.text("free-split"))?
+                }
+                _ => {}
+            }
+            if matches!(action, Action::Hit | Action::Double | Action::Split) {
+                self.pause(self.deal_delay)?;
+            }
+            self.step(action)?;
+            match action {
+                Action::Hit if self.engine.player_hands()[index].hand.is_bust() => {
+                    self.show_hand(index, false)?;
+                    let message = self.locale.text("player-busts");
+                    writeln!(
+                        self.output,
+                        "{}",
+                        paint(&message, &self.theme.bust, self.color)
+                    )?;
+                    index += 1;
+                }
+                Action::Stand => index += 1,
+                Action::Surrender => {
+                    writeln!(self.output, "{}", self.locale.text("player-surrenders"))?;
+                    index += 1;
+                }
+                Action::Double => {
+                    self.show_hand(index, true)?;
+                    index += 1;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Shows the hands the opponents in `seats` finished with, once they have played them.
+    fn show_opponents(&mut self, seats: Range<usize>) -> Result<(), BlackjackError> {
+        let table = self.engine.table();
+        for seat in seats.filter(|&seat| seat != table.player) {
+            let opponent = &table.seats[seat];
+            for player_hand in &opponent.hands {
+                let mut cards = Vec::new();
+                self.viewer.render(&player_hand.hand, &mut cards)?;
+                let message = self.locale.message(
+                    "opponent-plays",
+                    &[
+                        ("name", &opponent.name),
+                        ("cards", &String::from_utf8_lossy(&cards)),
+                        ("total", &player_hand.hand.calculate_hand_total()),
+                    ],
+                );
+                writeln!(self.output, "{}", message)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Plays the dealer's turn, where the dealer will automatically hit until the rules say to stand.
+    fn dealer_turn(&mut self) -> Result<(), BlackjackError> {
+        if self.engine.dealer_hand().is_hole_card_hidden() {
+            self.pause(self.dealer_delay)?;
+            self.step(Action::Continue)?;
+        }
+        self.show_dealer()?;
+        while *self.engine.state() == GameState::DealerPlaying {
+            // A dealer dealt both cards face up may stand without drawing
+            if !self.engine.dealer_policy().hits(self.engine.dealer_hand()) {
+                self.step(Action::Continue)?;
+                continue;
+            }
+            writeln!(self.output, "{}", self.locale.text("dealer-hits"))?;
+            self.pause(self.dealer_delay)?;
+            self.step(Action::Continue)?;
+            self.show_dealer()?;
+        }
+        Ok(())
+    }
+
+    /// Shows the dealer's hand and total after it changes, or draws the whole table again if the
+    /// table view redraws it.
+    fn show_dealer(&mut self) -> Result<(), BlackjackError> {
+        if self.table_view.redraws() {
+            return self.redraw_table();
+        }
+        let dealer_hand = self.engine.dealer_hand();
+        write!(self.output, "{} ", self.locale.text("hand-shown"))?;
+        self.viewer.render(dealer_hand, &mut *self.output)?;
+        writeln!(self.output)?;
+        let total = dealer_hand.calculate_hand_total();
+        writeln!(
+            self.output,
+            "{}",
+            self.locale.message("dealer-total", &[("total", &total)])
+        )?;
+        self.show_count()
+    }
+
+    /// Shows how each of the player's hands, side bets and insurance was settled by the engine, and
+    /// how the opponents did, and records the main bets' result with any betting progression.
+    fn determine_winner(&mut self) -> Result<(), BlackjackError> {
+        let engine = &self.engine;
+        let rules = engine.rules();
+        let dealer_hand = engine.dealer_hand();
+        let dealer_total = dealer_hand.calculate_hand_total();
+        let settled = settle_hands(rules, engine.variant(), engine.player_hands(), dealer_hand);
+        let mut round = Settlement::default();
+
+        for (index, (player_hand, (outcome, settlement))) in
+            engine.player_hands().iter().zip(settled).enumerate()
+        {
+            let hand = &player_hand.hand;
+            let player_total = hand.calculate_hand_total();
+            let locale = &self.locale;
+            let message = match outcome {
+                Outcome::Win(_) if rules.is_charlie(hand) => locale.text("result-charlie"),
+                Outcome::Win(payout) if hand.is_blackjack() => {
+                    locale.message("result-blackjack", &[("payout", &payout)])
+                }
+                Outcome::Win(payout) if payout != Payout::EVEN_MONEY => {
+                    locale.message("result-bonus", &[("payout", &payout)])
+                }
+                Outcome::Win(_) if dealer_hand.is_bust() => locale.text("result-dealer-busts"),
+                Outcome::Win(_) => locale.text("result-win"),
+                Outcome::Lose if hand.is_bust() => locale.text("result-bust"),
+                Outcome::Lose if player_total == dealer_total => locale.text("result-lose-tie"),
+                Outcome::Lose => locale.text("result-lose"),
+                Outcome::Push if player_total != dealer_total => {
+                    locale.message("result-push-totals", &[("total", &dealer_total)])
+                }
+                Outcome::Push => locale.text("result-push"),
+                Outcome::Surrender => locale.text("result-surrendered"),
+            };
+            let colour = match outcome {
+                Outcome::Win(_) if hand.is_blackjack() => self.theme.blackjack.as_str(),
+                Outcome::Win(_) => &self.theme.win,
+                Outcome::Lose if hand.is_bust() => &self.theme.bust,
+                _ => "",
+            };
+            round += settlement;
+            let result = paint(&message, colour, self.color);
+            let bet = self.chip_view.draw_wager(&player_hand.wager);
+            let net = self.currency.draw_signed(settlement.net());
+            let line = match self.hand_label(index) {
+                Some(label) => self.locale.message(
+                    "hand-result-labelled",
+                    &[
+                        ("label", &label),
+                        ("result", &result),
+                        ("bet", &bet),
+                        ("net", &net),
+                    ],
+                ),
+                None => self.locale.message(
+                    "hand-result",
+                    &[("result", &result), ("bet", &bet), ("net", &net)],
+                ),
+            };
+            writeln!(self.output, "{}", line)?;
+        }
+        let resolved = engine
+            .events()
+            .iter()
+            .filter(|event| matches!(event, GameEvent::HandResolved { .. }))
+            .copied()
+            .collect::<Vec<GameEvent>>();
+        for event in resolved {
+            self.chatter(&event);
+        }
+        if let Some(progression) = &mut self.progression {
+            progression.record(round.net());
+        }
+
+        let engine = &self.engine;
+        for ((side_bet, result), &wager) in engine
+            .side_bets()
+            .iter()
+            .zip(engine.side_bet_results())
+            .zip(engine.side_bet_wagers())
+        {
+            if wager == 0 {
+                continue;
+            }
+            let settlement = Settlement::side_bet(wager, result.map(|(_, payout)| payout));
+            let net = self.currency.draw_signed(settlement.net());
+            let message = match result {
+                Some((combination, payout)) => self.locale.message(
+                    "side-bet-wins",
+                    &[
+                        ("name", &side_bet.name()),
+                        ("combination", &self.locale.combination(combination)),
+                        ("payout", payout),
+                        ("net", &net),
+                    ],
+                ),
+                None => self.locale.message(
+                    "side-bet-loses",
+                    &[("name", &side_bet.name()), ("net", &net)],
+                ),
+            };
+            writeln!(self.output, "{}", message)?;
+        }
+        if engine.insurance() > 0 {
+            let dealer_blackjack = engine.dealer_hand().is_blackjack();
+            let settlement = Settlement::insurance(engine.insurance(), dealer_blackjack);
+            let net = self.currency.draw_signed(settlement.net());
+            let message = match dealer_blackjack {
+                true => self.locale.message(
+                    "insurance-wins",
+                    &[("payout", &INSURANCE_PAYS), ("net", &net)],
+                ),
+                false => self.locale.message("insurance-loses", &[("net", &net)]),
+            };
+            writeln!(self.output, "{}", message)?;
+        }
+
+        let table = engine.table();
+        for (seat, opponent) in table.seats.iter().enumerate() {
+            if seat == table.player || !opponent.is_playing() {
+                continue;
+            }
+            let mut total = Settlement::default();
+            for (_, settlement) in settle_hands(
+                engine.rules(),
+                engine.variant(),
+                &opponent.hands,
+                engine.dealer_hand(),
+            ) {
+                total += settlement;
+            }
+            let message = self.locale.message(
+                "opponent-result",
+                &[
+                    ("name", &opponent.name),
+                    ("net", &self.currency.draw_signed(total.net())),
+                    ("amount", &self.currency.draw(opponent.bankroll.balance())),
+                ],
+            );
+            writeln!(self.output, "{}", message)?;
+        }
+
+        self.show_bankroll()
+    }
+
+    /// Shows the player's bankroll, as an amount and as chips.
+    fn show_bankroll(&mut self) -> Result<(), BlackjackError> {
+        let balance = self.engine.bankroll().balance();
+        let message = self.locale.message(
+            "bankroll",
+            &[
+                ("amount", &self.currency.draw(balance)),
+                ("chips", &self.chip_view.draw(balance)),
+            ],
+        );
+        writeln!(self.output, "{}", message)?;
+        Ok(())
+    }
+
+    /// Shows the game over screen with the session statistics when the player cannot cover the
+    /// table minimum, and offers a rebuy. Returns true if the player bought back in.
+    pub fn game_over(&mut self) -> Result<bool, BlackjackError> {
+        let stats = self.engine.stats();
+        let locale = &self.locale;
+        let lines = [
+            locale.text("game-over"),
+            locale.message(
+                "cannot-cover",
+                &[
+                    (
+                        "amount",
+                        &self.currency.draw(self.engine.bankroll().balance()),
+                    ),
+                    ("minimum", &self.currency.draw(self.engine.limits().minimum)),
+                ],
+            ),
+            locale.message("rounds-played", &[("rounds", &stats.rounds_played)]),
+            locale.message(
+                "hands-record",
+                &[
+                    ("won", &stats.hands_won),
+                    ("lost", &stats.hands_lost),
+                    ("pushed", &stats.hands_pushed),
+                ],
+            ),
+            locale.message("blackjacks", &[("count", &stats.blackjacks)]),
+            locale.message(
+                "peak-bankroll",
+                &[
+                    ("amount", &self.currency.draw(stats.peak_bankroll)),
+                    ("chips", &self.chip_view.draw(stats.peak_bankroll)),
+                ],
+            ),
+            locale.message("rebuys", &[("count", &stats.rebuys)]),
+            locale.text("summary-end"),
+        ];
+        for line in lines {
+            writeln!(self.output, "{}", line)?;
+        }
+
+        loop {
+            let prompt = self.locale.message(
+                "rebuy-prompt",
+                &[("amount", &self.currency.draw(self.buy_in))],
+            );
+            writeln!(self.output, "{}", prompt)?;
+            match self.input.read_key(&["y", "n"], None)?.as_deref() {
+                Some("y") => {
+                    self.engine.bankroll_mut().deposit(self.buy_in);
+                    let balance = self.engine.bankroll().balance();
+                    let stats = self.engine.stats_mut();
+                    stats.rebuys += 1;
+                    stats.record_buy_in(self.buy_in, balance);
+                    return Ok(true);
+                }
+                Some("n") => return Ok(false),
+                _ => writeln!(self.output, "{}", self.locale.text("rebuy-invalid"))?,
+            }
+        }
+    }
+
+    /// Cashes out the bankroll and shows a summary of the session: the hands played and how they
+    /// went, the biggest win and loss, the accounts of chips bought in and cashed out, the net
+    /// result, the total wagered and the return to player, how often the player's decisions matched
+    /// basic strategy if they were graded, and the time played.
+    pub fn cash_out(&mut self) -> Result<(), BlackjackError> {
+        let stats = self.engine.stats();
+        let locale = &self.locale;
+        let cash_out = self.engine.bankroll().balance();
+        let net = cash_out as i64 - stats.bought_in as i64;
+        let draw = |amount: u64| self.currency.format(amount, self.currency.show_decimals);
+
+        let mut lines = vec![
+            locale.text("session-summary"),
+            locale.message(
+                "hands-played",
+                &[(
+                    "hands",
+                    &(stats.hands_won + stats.hands_lost + stats.hands_pushed),
+                )],
+            ),
+            locale.message(
+                "hands-record",
+                &[
+                    ("won", &stats.hands_won),
+                    ("lost", &stats.hands_lost),
+                    ("pushed", &stats.hands_pushed),
+                ],
+            ),
+            locale.message("blackjacks", &[("count", &stats.blackjacks)]),
+            locale.message("biggest-win", &[("amount", &draw(stats.biggest_win))]),
+            locale.message("biggest-loss", &[("amount", &draw(stats.biggest_loss))]),
+            locale.message(
+                "bought-in",
+                &[
+                    (
+                        "amount",
+                        &self
+                            .currency
+                            .format(stats.bought_in, self.currency.show_decimals),
+                    ),
+                    ("rebuys", &stats.rebuys),
+                ],
+            ),
+            locale.message("cashed-out", &[("amount", &self.currency.draw(cash_out))]),
+            locale.message("net-result", &[("amount", &self.currency.draw_signed(net))]),
+            locale.message(
+                "total-wagered",
+                &[(
+                    "amount",
+                    &self
+                        .currency
+                        .format(stats.wagered, self.currency.show_decimals),
+                )],
+            ),
+            match stats.return_to_player() {
+                Some(percentage) => locale.message(
+                    "return-to-player",
+                    &[("percentage", &format!("{:.1}", percentage))],
+                ),
+                None => locale.text("return-to-player-none"),
+            },
+        ];
+        if let Some(percentage) = stats.accuracy() {
+            lines.push(locale.message(
+                "strategy-accuracy",
+                &[
+                    ("correct", &stats.basic_strategy_plays),
+                    ("decisions", &stats.decisions),
+                    ("percentage", &format!("{:.1}", percentage)),
+                ],
+            ));
+            let groups = |names: &[String], groups: &[Accuracy]| {
+                names
+                    .iter()
+                    .zip(groups)
+                    .filter_map(|(name, group)| {
+                        let percentage = group.percentage()?;
+                        Some(locale.message(
+                            "strategy-group",
+                            &[
+                                ("name", name),
+                                ("correct", &group.correct),
+                                ("decisions", &group.decisions),
+                                ("percentage", &format!("{:.0}", percentage)),
+                            ],
+                        ))
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            };
+            let kinds: Vec<String> = HAND_KINDS
+                .iter()
+                .map(|kind| locale.text(&format!("hand-kind-{}", kind)))
+                .collect();
+            let up_cards: Vec<String> = UP_CARD_NAMES.iter().map(|name| name.to_string()).collect();
+            lines.push(locale.message(
+                "strategy-by-hand",
+                &[("groups", &groups(&kinds, &stats.by_hand))],
+            ));
+            lines.push(locale.message(
+                "strategy-by-up-card",
+                &[("groups", &groups(&up_cards, &stats.by_up_card))],
+            ));
+        }
+        lines.push(locale.message("time-played", &[("minutes", &(self.seconds_played() / 60))]));
+        lines.push(locale.text("summary-end"));
+        for line in lines {
+            writeln!(self.output, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the entire game, including the player's turn, dealer's turn, and winner determination.
+///
+/// The `run()` method encapsulates the game flow by calling methods to handle each phase of the game.
+impl GameController {
+    /// Plays a single round: takes the bets, deals, offers insurance, plays every hand and settles
+    /// the wagers. The cards stay on the table until the next round is dealt.
+    pub fn play_round(&mut self) -> Result<(), BlackjackError> {
+        self.take_bets()?;
+        self.redraw_table()?;
+        if *self.engine.state() == GameState::AwaitingInsurance {
+            self.insurance_phase()?;
+        }
+        if self
+            .engine
+            .rules()
+            .dealer_peeks_blackjack(self.engine.dealer_hand())
+        {
+            writeln!(self.output, "{}", self.locale.text("dealer-peeks"))?;
+        } else {
+            // The seats before the player's play as soon as the cards are dealt, and those after
+            // once the player's hands are finished
+            let table = self.engine.table();
+            let (player, seats) = (table.player, table.seats.len());
+            self.show_opponents(0..player)?;
+            self.play_hands()?;
+            self.show_opponents(player + 1..seats)?;
+        }
+        self.dealer_turn()?;
+        self.determine_winner()
+    }
+
+    /// Returns true if the bankroll can cover the table minimum on every hand the variant deals.
+    pub fn can_cover_minimum(&self) -> bool {
+        let hands = self.engine.variant().hands_per_player() as u32;
+        self.engine.bankroll().balance() >= self.engine.limits().minimum * hands
+    }
+}
+
+impl GameEngine for GameController {
+    fn run(&mut self) -> Result<bool, BlackjackError> {
+        if !self.can_cover_minimum() && !self.game_over()? {
+            return Ok(false);
+        }
+
+        self.play_round()?;
+
+        // A player who quit during the round may still keep the session to carry on later
+        if self.quit_requested {
+            self.quit_requested = false;
+            writeln!(self.output, "{}", self.locale.text("save-prompt"))?;
+            self.save_requested = self.input.read_key(&["y", "n"], None)?.as_deref() == Some("y");
+            return Ok(false);
+        }
+
+        // Ask the user if they want to play again, or to save the session and carry it on later
+        writeln!(self.output, "{}", self.locale.text("play-again"))?;
+        let choice = self.input.read_key(&["y", "n", "s"], None)?;
+        self.save_requested = choice.as_deref() == Some("s");
+        Ok(choice.as_deref() == Some("y"))
+    }
+}
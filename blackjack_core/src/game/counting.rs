@@ -0,0 +1,425 @@
+use super::{GameEvent, GameObserver};
+use crate::card::{Card, Rank};
+use crate::prelude::*;
+use crate::shoe::Shoe;
+
+/// A card counting system: the tag each card adds to the running count, by the card's value.
+///
+/// A balanced system's tags add up to zero over a whole deck, so its running count is turned into a
+/// true count by dividing it by the decks left. An unbalanced one, such as KO, starts each shoe from
+/// a negative count instead, so that its running count is played as it is; see `initial_count()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CountingSystem {
+    pub name: &'static str,
+    /// The tag of an Ace, then of each value from 2 to 10, tens and faces alike.
+    pub tags: [i32; 10],
+}
+
+impl CountingSystem {
+    /// Hi-Lo, the most widely played count: 2 to 6 count +1, tens and Aces -1.
+    pub const HI_LO: CountingSystem = CountingSystem {
+        name: "Hi-Lo",
+        tags: [-1, 1, 1, 1, 1, 1, 0, 0, 0, -1],
+    };
+    /// Knock-Out, Hi-Lo with the 7 counted +1 as well, which leaves it unbalanced.
+    pub const KO: CountingSystem = CountingSystem {
+        name: "KO",
+        tags: [-1, 1, 1, 1, 1, 1, 1, 0, 0, -1],
+    };
+    /// Hi-Opt I, which leaves Aces and 2s out of the count.
+    pub const HI_OPT_I: CountingSystem = CountingSystem {
+        name: "Hi-Opt I",
+        tags: [0, 0, 1, 1, 1, 1, 0, 0, 0, -1],
+    };
+    /// Hi-Opt II, a level two count that leaves Aces out.
+    pub const HI_OPT_II: CountingSystem = CountingSystem {
+        name: "Hi-Opt II",
+        tags: [0, 1, 1, 2, 2, 1, 1, 0, 0, -2],
+    };
+    /// Omega II, a level two count that leaves Aces out and counts the 9 -1.
+    pub const OMEGA_II: CountingSystem = CountingSystem {
+        name: "Omega II",
+        tags: [0, 1, 1, 2, 2, 2, 1, 0, -1, -2],
+    };
+    /// The Zen count, a level two count that keeps Aces in at -1.
+    pub const ZEN: CountingSystem = CountingSystem {
+        name: "Zen",
+        tags: [-1, 1, 1, 2, 2, 2, 1, 0, 0, -2],
+    };
+
+    /// The names the systems are chosen by, in the order of `SYSTEMS`.
+    pub const NAMES: [&'static str; 6] = ["hi-lo", "ko", "hi-opt-1", "hi-opt-2", "omega-2", "zen"];
+
+    /// Every system built into the game, simplest first.
+    pub const SYSTEMS: [CountingSystem; 6] = [
+        CountingSystem::HI_LO,
+        CountingSystem::KO,
+        CountingSystem::HI_OPT_I,
+        CountingSystem::HI_OPT_II,
+        CountingSystem::OMEGA_II,
+        CountingSystem::ZEN,
+    ];
+
+    /// Returns the system called `name`, one of `NAMES`.
+    pub fn from_name(name: &str) -> Option<CountingSystem> {
+        let index = CountingSystem::NAMES
+            .iter()
+            .position(|other| *other == name)?;
+        Some(CountingSystem::SYSTEMS[index])
+    }
+
+    /// Returns the level of the system: the largest tag, up or down, that any card is given.
+    pub fn level(&self) -> i32 {
+        self.tags.iter().map(|tag| tag.abs()).max().unwrap_or(0)
+    }
+
+    /// Returns true if Aces are left out of the count, as they are in Hi-Opt I, Hi-Opt II and
+    /// Omega II, to be side counted instead.
+    pub fn is_ace_neutral(&self) -> bool {
+        self.tags[0] == 0
+    }
+
+    /// Returns the tag of `card`.
+    pub fn tag(&self, card: &Card) -> i32 {
+        self.tags[card.rank.value() as usize - 1]
+    }
+
+    /// Returns true if the tags of a whole deck add up to zero.
+    pub fn is_balanced(&self) -> bool {
+        self.deck_total() == 0
+    }
+
+    /// Returns the count a freshly shuffled shoe of `decks` decks starts from: zero for a balanced
+    /// system, and for an unbalanced one as far below zero as every deck but one adds up to, so
+    /// that its count comes back to zero with a deck left of a shoe that is neither rich nor poor.
+    pub fn initial_count(&self, decks: usize) -> i32 {
+        -self.deck_total() * (decks as i32 - 1)
+    }
+
+    /// Returns what the tags of a whole deck add up to, where there are four of each value but
+    /// sixteen tens.
+    fn deck_total(&self) -> i32 {
+        4 * self.tags.iter().sum::<i32>() + 12 * self.tags[9]
+    }
+}
+
+impl Default for CountingSystem {
+    fn default() -> Self {
+        CountingSystem::HI_LO
+    }
+}
+
+/// How closely the decks left in the shoe are reckoned when a running count is turned into a true
+/// count. A computer knows them to the card, but a counter at the table eyes the discard tray and
+/// rounds to the nearest quarter, half or whole deck, which moves the true count they bet and play
+/// by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeckEstimation {
+    /// To the card.
+    #[default]
+    Exact,
+    /// To the nearest quarter of a deck.
+    Quarter,
+    /// To the nearest half deck.
+    Half,
+    /// To the nearest whole deck.
+    Full,
+}
+
+impl DeckEstimation {
+    /// The names of the estimations, as written in the config file and on the command line.
+    pub const NAMES: [&'static str; 4] = ["exact", "quarter", "half", "full"];
+
+    /// Returns the estimation called `name`, one of `NAMES`.
+    pub fn from_name(name: &str) -> Option<DeckEstimation> {
+        match name {
+            "exact" => Some(DeckEstimation::Exact),
+            "quarter" => Some(DeckEstimation::Quarter),
+            "half" => Some(DeckEstimation::Half),
+            "full" => Some(DeckEstimation::Full),
+            _ => None,
+        }
+    }
+
+    /// Returns `decks` as they are estimated: rounded to the nearest step, but never to less than
+    /// one step, as no counter reckons an unfinished shoe to be empty.
+    pub fn estimate(self, decks: f64) -> f64 {
+        let step = match self {
+            DeckEstimation::Exact => return decks,
+            DeckEstimation::Quarter => 0.25,
+            DeckEstimation::Half => 0.5,
+            DeckEstimation::Full => 1.0,
+        };
+        // Rounded by hand, as `f64::round()` needs the standard library; `decks` is never negative
+        let steps = (decks / step + 0.5) as u32;
+        (steps as f64 * step).max(step)
+    }
+}
+
+/// Keeps the count of the cards seen at the table, as a counter sitting at it would, by following
+/// the game's `GameEvent`s: each card dealt face up is counted as it is dealt, the dealer's hole card
+/// once it is turned over, and the count starts again whenever the shoe is shuffled. The cards are
+/// counted by a `CountingSystem`, Hi-Lo unless another is chosen.
+///
+/// The decks left to deal are reckoned from every card dealt, face down or not, as closely as the
+/// `estimation` says, and turn the running count into a true count. The Aces seen are counted on
+/// the side as well; with `ace_side_count` on, the Aces left are shown with the count, and the Aces
+/// left over an even share are added back into the count bets are sized from, by
+/// `betting_count()`, for a system that leaves them out of the main count.
+///
+/// The `GameController` and the `Engine` each keep a counter fed with every event they report,
+/// whose count is shown on the table, given to bet advisors and passed to strategies and simulated
+/// bots in the `GameView`; another can be subscribed to either as a `GameObserver`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CardCounter {
+    pub system: CountingSystem,
+    pub estimation: DeckEstimation,
+    pub ace_side_count: bool,
+    decks: usize,
+    running_count: i32,
+    cards_dealt: usize,
+    aces_seen: usize,
+}
+
+impl CardCounter {
+    /// Creates a Hi-Lo counter for a freshly shuffled shoe of `decks` decks.
+    pub fn new(decks: usize) -> CardCounter {
+        CardCounter::with_system(CountingSystem::HI_LO, decks)
+    }
+
+    /// Creates a counter that counts by `system`, for a freshly shuffled shoe of `decks` decks.
+    pub fn with_system(system: CountingSystem, decks: usize) -> CardCounter {
+        CardCounter {
+            system,
+            estimation: DeckEstimation::Exact,
+            ace_side_count: false,
+            decks,
+            running_count: system.initial_count(decks),
+            cards_dealt: 0,
+            aces_seen: 0,
+        }
+    }
+
+    /// Picks the count up part way through `shoe`, as if every card dealt from it since it was
+    /// shuffled had been seen except the `hidden` ones still face down. This is how a game carried
+    /// on from a snapshot, whose events were never reported, gets its count back.
+    pub fn resume<'a>(&mut self, shoe: &Shoe, hidden: impl IntoIterator<Item = &'a Card>) {
+        let hidden: Vec<&Card> = hidden.into_iter().collect();
+        let unseen = || shoe.cards().iter().chain(hidden.iter().copied());
+        let system = self.system;
+        let full = system.initial_count(shoe.decks()) + system.deck_total() * shoe.decks() as i32;
+        let unseen_aces = unseen().filter(|card| card.rank == Rank::Ace).count();
+        self.decks = shoe.decks();
+        self.running_count = full - unseen().map(|card| system.tag(card)).sum::<i32>();
+        self.cards_dealt = shoe.decks() * 52 - shoe.cards_remaining();
+        self.aces_seen = (shoe.decks() * 4).saturating_sub(unseen_aces);
+    }
+
+    /// Returns the running count of the cards seen since the shoe was shuffled.
+    pub fn running_count(&self) -> i32 {
+        self.running_count
+    }
+
+    /// Returns the number of decks left to deal, as the counter estimates them.
+    pub fn decks_remaining(&self) -> f64 {
+        let exact = (self.decks * 52).saturating_sub(self.cards_dealt) as f64 / 52.0;
+        self.estimation.estimate(exact)
+    }
+
+    /// Returns the running count divided by the decks left to deal, taken as no less than one card
+    /// so an empty shoe does not divide by zero. An unbalanced system's running count is played as
+    /// it is, so that is returned instead.
+    pub fn true_count(&self) -> f64 {
+        self.true_count_of(self.running_count as f64)
+    }
+
+    /// Returns the Aces left to deal.
+    pub fn aces_remaining(&self) -> usize {
+        (self.decks * 4).saturating_sub(self.aces_seen)
+    }
+
+    /// Returns how many more Aces are left to deal than the four a deck for the decks left, or
+    /// fewer if the number is negative.
+    pub fn ace_surplus(&self) -> f64 {
+        self.aces_remaining() as f64 - 4.0 * self.decks_remaining()
+    }
+
+    /// Returns the true count bets are sized from. With the side count on, a system that leaves
+    /// Aces out of its count has each spare Ace, which favours the player, added back at the
+    /// system's level, as an Ace would be tagged if the system counted it; otherwise this is the
+    /// true count.
+    pub fn betting_count(&self) -> f64 {
+        if !self.ace_side_count || !self.system.is_ace_neutral() {
+            return self.true_count();
+        }
+        let adjusted = self.running_count as f64 + self.system.level() as f64 * self.ace_surplus();
+        self.true_count_of(adjusted)
+    }
+
+    /// Turns a `running` count into a true count, as `true_count()` does.
+    fn true_count_of(&self, running: f64) -> f64 {
+        match self.system.is_balanced() {
+            true => running / self.decks_remaining().max(1.0 / 52.0),
+            false => running,
+        }
+    }
+
+    /// Counts `card`, seen face up.
+    fn see(&mut self, card: &Card) {
+        self.running_count += self.system.tag(card);
+        if card.rank == Rank::Ace {
+            self.aces_seen += 1;
+        }
+    }
+}
+
+impl GameObserver for CardCounter {
+    fn on_event(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::ShoeShuffled { decks } => {
+                self.decks = decks;
+                self.running_count = self.system.initial_count(decks);
+                self.cards_dealt = 0;
+                self.aces_seen = 0;
+            }
+            GameEvent::CardDealt { card, face_up, .. } => {
+                self.cards_dealt += 1;
+                if face_up {
+                    self.see(&card);
+                }
+            }
+            GameEvent::DealerReveal { card } => self.see(&card),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Rank, Suit};
+    use crate::game::Recipient;
+
+    fn dealt(rank: Rank, face_up: bool) -> GameEvent {
+        GameEvent::CardDealt {
+            card: Card::new(rank, Suit::Clubs),
+            recipient: Recipient::Player(1),
+            face_up,
+        }
+    }
+
+    #[test]
+    fn only_ko_is_unbalanced() {
+        for system in CountingSystem::SYSTEMS {
+            assert_eq!(
+                system.is_balanced(),
+                system != CountingSystem::KO,
+                "{}",
+                system.name
+            );
+        }
+    }
+
+    #[test]
+    fn ko_counts_back_to_zero_by_the_last_deck() {
+        let mut counter = CardCounter::with_system(CountingSystem::KO, 2);
+        assert_eq!(counter.running_count(), -4);
+        for card in Shoe::new(1).cards() {
+            counter.on_event(&dealt(card.rank, true));
+        }
+        assert_eq!(counter.running_count(), 0);
+    }
+
+    #[test]
+    fn systems_are_found_by_name() {
+        assert_eq!(
+            CountingSystem::from_name("omega-2"),
+            Some(CountingSystem::OMEGA_II)
+        );
+        assert_eq!(CountingSystem::from_name("red-7"), None);
+    }
+
+    #[test]
+    fn face_up_cards_are_counted_as_they_are_dealt() {
+        let mut counter = CardCounter::new(1);
+        for rank in [Rank::Five, Rank::Two, Rank::King, Rank::Six] {
+            counter.on_event(&dealt(rank, true));
+        }
+        assert_eq!(counter.running_count(), 2);
+    }
+
+    #[test]
+    fn a_hole_card_is_counted_once_it_is_turned_over() {
+        let mut counter = CardCounter::new(1);
+        let hole_card = Card::new(Rank::Ace, Suit::Spades);
+        counter.on_event(&dealt(Rank::Three, true));
+        counter.on_event(&GameEvent::CardDealt {
+            card: hole_card,
+            recipient: Recipient::Dealer,
+            face_up: false,
+        });
+        assert_eq!(counter.running_count(), 1);
+        counter.on_event(&GameEvent::DealerReveal { card: hole_card });
+        assert_eq!(counter.running_count(), 0);
+    }
+
+    #[test]
+    fn a_shuffle_starts_the_count_again() {
+        let mut counter = CardCounter::new(1);
+        counter.on_event(&dealt(Rank::Four, true));
+        counter.on_event(&GameEvent::ShoeShuffled { decks: 6 });
+        assert_eq!(counter, CardCounter::new(6));
+    }
+
+    #[test]
+    fn the_true_count_is_the_running_count_per_deck_left() {
+        let mut counter = CardCounter::new(2);
+        for _ in 0..26 {
+            counter.on_event(&dealt(Rank::Two, true));
+            counter.on_event(&dealt(Rank::Nine, false));
+        }
+        assert_eq!(counter.running_count(), 26);
+        assert_eq!(counter.decks_remaining(), 1.0);
+        assert_eq!(counter.true_count(), 26.0);
+    }
+
+    #[test]
+    fn an_empty_shoe_still_has_a_true_count() {
+        let mut counter = CardCounter::new(1);
+        for _ in 0..52 {
+            counter.on_event(&dealt(Rank::Two, true));
+        }
+        assert_eq!(counter.decks_remaining(), 0.0);
+        assert_eq!(counter.true_count(), 52.0 * 52.0);
+    }
+
+    #[test]
+    fn spare_aces_are_bet_on_when_side_counted() {
+        let mut counter = CardCounter::with_system(CountingSystem::HI_OPT_I, 1);
+        for _ in 0..26 {
+            counter.on_event(&dealt(Rank::Seven, true));
+        }
+        assert_eq!(counter.aces_remaining(), 4);
+        assert_eq!(counter.ace_surplus(), 2.0);
+        assert_eq!(counter.betting_count(), 0.0);
+        counter.ace_side_count = true;
+        assert_eq!(counter.betting_count(), 4.0);
+    }
+
+    #[test]
+    fn a_resumed_count_leaves_out_the_cards_still_face_down() {
+        // An unshuffled shoe deals from the King of its last suit down, so the hidden card is a Six
+        let mut shoe = Shoe::new(1);
+        let seen: Vec<Card> = (0..7).map(|_| shoe.draw().unwrap()).collect();
+        let hidden = shoe.draw().unwrap();
+        let mut counter = CardCounter::new(1);
+        counter.resume(&shoe, [&hidden]);
+        assert_eq!(
+            counter.running_count(),
+            seen.iter()
+                .map(|card| counter.system.tag(card))
+                .sum::<i32>()
+        );
+        assert_eq!(counter.decks_remaining(), 44.0 / 52.0);
+    }
+}
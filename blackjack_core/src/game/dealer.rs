@@ -0,0 +1,61 @@
+use crate::hand::Hand;
+use crate::prelude::*;
+
+/// The `DealerPolicy` trait decides when the dealer draws another card.
+///
+/// The `DealerPolicy` trait provides the following methods:
+/// - `name(&self) -> String`: Returns a description of the policy, such as "Dealer stands on soft 17".
+/// - `hits(&self, hand: &Hand) -> bool`: Returns whether the dealer must draw to the given hand.
+///
+/// The table's policy comes from its `RuleSet`, or from the `Variant` being played, and can be
+/// replaced to try out house rules or to practice against an unusual dealer.
+pub trait DealerPolicy {
+    fn name(&self) -> String;
+    fn hits(&self, hand: &Hand) -> bool;
+}
+
+/// The usual rule: the dealer draws to 16 and stands on every 17, including soft 17.
+pub struct StandsSoft17;
+
+impl DealerPolicy for StandsSoft17 {
+    fn name(&self) -> String {
+        "Dealer stands on soft 17".to_string()
+    }
+
+    fn hits(&self, hand: &Hand) -> bool {
+        hand.value().total < 17
+    }
+}
+
+/// The dealer draws to 16 and to a soft 17, standing on hard 17 and above.
+pub struct HitsSoft17;
+
+impl DealerPolicy for HitsSoft17 {
+    fn name(&self) -> String {
+        "Dealer hits soft 17".to_string()
+    }
+
+    fn hits(&self, hand: &Hand) -> bool {
+        let value = hand.value();
+        value.total < 17 || (value.total == 17 && value.soft)
+    }
+}
+
+/// A house rule where the dealer draws below `total` and stands from `total` up, also drawing to a
+/// soft `total` if `hits_soft` is set.
+pub struct StandsOn {
+    pub total: u32,
+    pub hits_soft: bool,
+}
+
+impl DealerPolicy for StandsOn {
+    fn name(&self) -> String {
+        let hard = if self.hits_soft { "hard " } else { "" };
+        format!("Dealer stands on {}{}", hard, self.total)
+    }
+
+    fn hits(&self, hand: &Hand) -> bool {
+        let value = hand.value();
+        value.total < self.total || (value.total == self.total && value.soft && self.hits_soft)
+    }
+}
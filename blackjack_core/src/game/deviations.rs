@@ -0,0 +1,97 @@
+use super::Action;
+use crate::card::Card;
+use crate::hand::Hand;
+
+/// The hand an index play is made on: a hard total, a pair of cards of the value given, 1 for
+/// Aces and 10 for tens, or any hand at all, as for insurance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexHand {
+    Hard(u32),
+    Pair(u32),
+    Any,
+}
+
+/// A play that departs from basic strategy once the count says the cards left favour it: `play` is
+/// made on `hand` against an up card of `up_card` (1 for an Ace, 10 for a ten) at a Hi-Lo true
+/// count of `index` or more, and not made below it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexPlay {
+    pub hand: IndexHand,
+    pub up_card: u32,
+    pub index: i32,
+    pub play: Action,
+}
+
+impl IndexPlay {
+    const fn new(hand: IndexHand, up_card: u32, index: i32, play: Action) -> IndexPlay {
+        IndexPlay {
+            hand,
+            up_card,
+            index,
+            play,
+        }
+    }
+
+    /// Returns true if the index play is made on `hand` against `up_card`.
+    pub fn applies(&self, hand: &Hand, up_card: Card) -> bool {
+        let value = hand.value();
+        let fits = match self.hand {
+            IndexHand::Hard(total) => !value.soft && value.total == total,
+            IndexHand::Pair(rank) => hand.is_pair() && hand.cards()[0].rank.value() == rank,
+            IndexHand::Any => true,
+        };
+        fits && up_card.rank.value() == self.up_card
+    }
+}
+
+/// The Illustrious 18, the index plays worth the most to a Hi-Lo counter in a shoe game where the
+/// dealer stands on soft 17, most valuable first, starting with taking insurance at +3.
+pub const ILLUSTRIOUS_18: [IndexPlay; 18] = [
+    IndexPlay::new(IndexHand::Any, 1, 3, Action::Insure),
+    IndexPlay::new(IndexHand::Hard(16), 10, 0, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(15), 10, 4, Action::Stand),
+    IndexPlay::new(IndexHand::Pair(10), 5, 5, Action::Split),
+    IndexPlay::new(IndexHand::Pair(10), 6, 4, Action::Split),
+    IndexPlay::new(IndexHand::Hard(10), 10, 4, Action::Double),
+    IndexPlay::new(IndexHand::Hard(12), 3, 2, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(12), 2, 3, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(11), 1, 1, Action::Double),
+    IndexPlay::new(IndexHand::Hard(9), 2, 1, Action::Double),
+    IndexPlay::new(IndexHand::Hard(10), 1, 4, Action::Double),
+    IndexPlay::new(IndexHand::Hard(9), 7, 3, Action::Double),
+    IndexPlay::new(IndexHand::Hard(16), 9, 5, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(13), 2, -1, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(12), 4, 0, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(12), 5, -2, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(12), 6, -1, Action::Stand),
+    IndexPlay::new(IndexHand::Hard(13), 3, -2, Action::Stand),
+];
+
+/// The Fab 4, the surrenders worth the most to a Hi-Lo counter where late surrender is offered.
+pub const FAB_4: [IndexPlay; 4] = [
+    IndexPlay::new(IndexHand::Hard(14), 10, 3, Action::Surrender),
+    IndexPlay::new(IndexHand::Hard(15), 10, 0, Action::Surrender),
+    IndexPlay::new(IndexHand::Hard(15), 9, 2, Action::Surrender),
+    IndexPlay::new(IndexHand::Hard(15), 1, 1, Action::Surrender),
+];
+
+/// Returns the index play for `hand` against `up_card` that can be made with the `legal` actions,
+/// the Fab 4 looked through before the Illustrious 18, or `None` if there is none.
+///
+/// A split or surrender that the `basic` strategy play makes is never given up for an index play
+/// of another kind, as a pair of sixes is a hard 12 and a surrendered 16 is not stood on.
+pub fn index_play_for(
+    hand: &Hand,
+    up_card: Card,
+    basic: Action,
+    legal: &[Action],
+) -> Option<&'static IndexPlay> {
+    FAB_4
+        .iter()
+        .chain(&ILLUSTRIOUS_18)
+        .filter(|index_play| legal.contains(&index_play.play))
+        .filter(|index_play| {
+            !matches!(basic, Action::Split | Action::Surrender) || basic == index_play.play
+        })
+        .find(|index_play| index_play.applies(hand, up_card))
+}
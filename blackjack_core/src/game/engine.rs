@@ -0,0 +1,1392 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "std")]
+use super::TableFrame;
+use super::{
+    settle_hands, Bankroll, BasicStrategy, BetError, CardCounter, CountingSystem, DealerPolicy,
+    DeckEstimation, GameEvent, GameObserver, GameView, Outcome, Payout, PlayerHand, Recipient,
+    Replay, RuleSet, Seat, SessionStats, Settlement, SideBet, Snapshot, Table, TableLimits,
+    Variant, Wager, MAX_SEATS,
+};
+use crate::card::{Card, Rank};
+use crate::entropy::{self, EntropySource};
+use crate::error::BlackjackError;
+use crate::hand::Hand;
+use crate::prelude::*;
+use crate::shoe::Shoe;
+use core::ops::Range;
+
+/// A move made by the player, or by a frontend on their behalf, to advance the round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Places a main bet of the given number of chips on every hand and deals the round.
+    Bet(u32),
+    /// Swaps the second cards of the player's two hands, in variants that allow it.
+    Switch,
+    /// Keeps the player's hands as dealt, in variants that allow switching.
+    KeepHands,
+    /// Takes insurance against a dealer natural when the dealer shows an Ace, for half the main bet.
+    Insure,
+    /// Turns insurance down when it is offered.
+    DeclineInsurance,
+    /// Takes another card on the current hand.
+    Hit,
+    /// Finishes the current hand.
+    Stand,
+    /// Doubles the wager on the current hand and takes exactly one more card.
+    Double,
+    /// Splits the current pair into two hands.
+    Split,
+    /// Gives up the current hand for half the wager back, where the rules allow it.
+    Surrender,
+    /// Moves the round on when no decision is needed, such as while the dealer plays.
+    Continue,
+    /// Sits the round out, watching it dealt to the given number of other players instead, as a
+    /// back counter does until the count is worth betting on. At most `MAX_SEATS - 1` other players
+    /// can be dealt to.
+    SitOut(usize),
+}
+
+impl Action {
+    /// Returns the short code the action is written as in a replay, such as "h" for a hit or "b25"
+    /// for a bet of 25 chips.
+    pub fn code(&self) -> String {
+        match self {
+            Action::Bet(bet) => format!("b{}", bet),
+            Action::Switch => "w".to_string(),
+            Action::KeepHands => "k".to_string(),
+            Action::Insure => "i".to_string(),
+            Action::DeclineInsurance => "n".to_string(),
+            Action::Hit => "h".to_string(),
+            Action::Stand => "s".to_string(),
+            Action::Double => "d".to_string(),
+            Action::Split => "p".to_string(),
+            Action::Surrender => "r".to_string(),
+            Action::Continue => "c".to_string(),
+            Action::SitOut(others) => format!("o{}", others),
+        }
+    }
+
+    /// Reads an action from the short code returned by `code()`.
+    pub fn from_code(code: &str) -> Result<Self, BlackjackError> {
+        let action = match code {
+            "w" => Action::Switch,
+            "k" => Action::KeepHands,
+            "i" => Action::Insure,
+            "n" => Action::DeclineInsurance,
+            "h" => Action::Hit,
+            "s" => Action::Stand,
+            "d" => Action::Double,
+            "p" => Action::Split,
+            "r" => Action::Surrender,
+            "c" => Action::Continue,
+            _ => code
+                .strip_prefix('b')
+                .and_then(|bet| bet.parse::<u32>().ok())
+                .map(Action::Bet)
+                .or_else(|| {
+                    code.strip_prefix('o')
+                        .and_then(|others| others.parse::<usize>().ok())
+                        .map(Action::SitOut)
+                })
+                .ok_or_else(|| BlackjackError::InvalidAction(code.to_string()))?,
+        };
+        Ok(action)
+    }
+}
+
+/// The point the round has reached, telling the caller which actions the engine expects next.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameState {
+    /// Waiting for an `Action::Bet` to deal a new round.
+    AwaitingBet,
+    /// Waiting for the player to `Insure` or `DeclineInsurance` against the dealer's Ace.
+    AwaitingInsurance,
+    /// Waiting for the player to `Switch` or `KeepHands`.
+    AwaitingSwitch,
+    /// Waiting for the player to act on the hand at the given index.
+    AwaitingAction { hand: usize },
+    /// The dealer is playing; each `Continue` reveals the hole card or draws one card.
+    DealerPlaying,
+    /// The round is over, with the outcome of each player hand and the net chips won or lost.
+    Resolved { outcomes: Vec<Outcome>, net: i64 },
+}
+
+/// A headless blackjack engine that plays rounds one action at a time.
+///
+/// The `Engine` never reads input or prints anything. Callers feed it `Action`s through `step()`
+/// and inspect the returned `GameState`, along with the hands and bankroll, to drive any frontend,
+/// whether a terminal, a GUI or a simulation; the `GameController` plays its rounds through one
+/// too. Rules that differ between games are delegated to a `Variant` implementation, and
+/// subscribed `GameObserver`s are told of each `GameEvent` as the round is played, as is the
+/// engine's own `CardCounter`, whose count is given to strategies in the `GameView`; the events of
+/// the last step can also be read back with `events()`.
+///
+/// The hands and chips are kept in a `Table` of `Seat`s. The player's seat is played by the
+/// actions given to `step()`, and opponents seated with `seat_opponents()` bet and play by their own
+/// `PlayerStrategy` in casino order: the seats before the player's as soon as the cards are dealt,
+/// and those after once the player's hands are finished. Side bets offered with `set_side_bets()`
+/// are taken alongside the next main bet and settled on the opening cards. When the dealer shows
+/// an Ace over a face-down hole card, the player is offered insurance before anyone plays or the
+/// dealer peeks; opponents never take it, as basic strategy never does.
+///
+/// The whole game can be saved at any point as a `Snapshot` and carried on later with `restore()`.
+/// In practice mode, decisions can be taken back with `undo()`; outside it, every action stands.
+/// Once seeded, the engine records a `Replay` that plays the game again exactly. With the `async`
+/// feature, an `AsyncEngine` runs an engine on a thread of its own behind an awaitable handle.
+pub struct Engine {
+    shoe: Shoe,
+    entropy: Box<dyn EntropySource>,
+    variant: Box<dyn Variant>,
+    rules: RuleSet,
+    dealer_policy: Box<dyn DealerPolicy>,
+    limits: TableLimits,
+    table: Table,
+    stats: SessionStats,
+    dealer_hand: Hand,
+    others: Vec<Hand>,
+    side_bets: Vec<Box<dyn SideBet>>,
+    next_side_bet_wagers: Vec<u32>,
+    side_bet_wagers: Vec<u32>,
+    side_bet_results: Vec<Option<(&'static str, Payout)>>,
+    insurance: u32,
+    state: GameState,
+    observers: Vec<Box<dyn GameObserver>>,
+    events: Vec<GameEvent>,
+    counter: CardCounter,
+    practice: bool,
+    history: Vec<Checkpoint>,
+    replay: Option<Replay>,
+}
+
+/// The game as it was before an action taken in practice mode, for `undo()` to put back: a snapshot
+/// of the player's game, the chips and hands of every seat, which opponents playing can change, and
+/// a fork of the entropy source, if it can be forked, so a shoe shuffled again deals the same cards.
+struct Checkpoint {
+    snapshot: Snapshot,
+    seats: Vec<(Bankroll, Vec<PlayerHand>)>,
+    entropy: Option<Box<dyn EntropySource>>,
+}
+
+impl Engine {
+    /// Creates an engine for the given variant, table rules and table limits, waiting for the first bet.
+    /// The cards are shuffled with entropy from the operating system.
+    #[cfg(feature = "std")]
+    pub fn new(
+        variant: Box<dyn Variant>,
+        rules: RuleSet,
+        limits: TableLimits,
+        bankroll: Bankroll,
+    ) -> Self {
+        Engine::with_entropy(variant, rules, limits, bankroll, entropy::from_system())
+    }
+
+    /// Creates an engine that shuffles with the given entropy source, such as a hardware random
+    /// number generator or a provably fair server seed.
+    pub fn with_entropy(
+        variant: Box<dyn Variant>,
+        rules: RuleSet,
+        limits: TableLimits,
+        bankroll: Bankroll,
+        mut entropy: Box<dyn EntropySource>,
+    ) -> Self {
+        let mut shoe = Shoe::with_penetration(rules.decks, rules.penetration);
+        shoe.shuffle(&mut *entropy);
+        let mut player = Seat::new("You", 0, None);
+        player.bankroll = bankroll;
+        Engine {
+            shoe,
+            entropy,
+            dealer_policy: variant.dealer_policy(&rules),
+            variant,
+            rules,
+            limits,
+            stats: SessionStats::new(player.bankroll.balance()),
+            table: Table::new(player, Vec::new()),
+            dealer_hand: Hand::new(),
+            others: Vec::new(),
+            side_bets: Vec::new(),
+            next_side_bet_wagers: Vec::new(),
+            side_bet_wagers: Vec::new(),
+            side_bet_results: Vec::new(),
+            insurance: 0,
+            state: GameState::AwaitingBet,
+            observers: Vec::new(),
+            events: Vec::new(),
+            counter: CardCounter::new(rules.decks),
+            practice: false,
+            history: Vec::new(),
+            replay: None,
+        }
+    }
+
+    /// Seeds the entropy source and starts a freshly shuffled shoe, so that the same seed
+    /// deals the same cards given the same actions. A new replay is recorded from this point.
+    /// Seeding is meant for between rounds: any cards on the table belong to the old shoe and are
+    /// cleared away, and the engine waits for a bet.
+    pub fn seed(&mut self, seed: u64) {
+        self.replay = Some(Replay::new(
+            self.variant.name(),
+            self.rules,
+            self.limits,
+            self.table.player().bankroll.balance(),
+            seed,
+        ));
+        for seat in &mut self.table.seats {
+            seat.hands.clear();
+        }
+        self.dealer_hand = Hand::new();
+        self.others.clear();
+        self.state = GameState::AwaitingBet;
+        self.history.clear();
+        self.entropy = entropy::seeded(seed);
+        self.shoe = Shoe::with_penetration(self.rules.decks, self.rules.penetration);
+        self.shoe.shuffle(&mut *self.entropy);
+        self.emit(GameEvent::ShoeShuffled {
+            decks: self.shoe.decks(),
+        });
+    }
+
+    /// Subscribes an observer to the events of every round from now on.
+    pub fn subscribe(&mut self, observer: Box<dyn GameObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Returns the events reported during the last call to `step()`, in the order they happened.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Returns the point the round has reached.
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// Returns the counter keeping the Hi-Lo count of the cards seen so far.
+    pub fn counter(&self) -> &CardCounter {
+        &self.counter
+    }
+
+    /// Counts the cards by `system` from now on, counting those already dealt from the shoe again.
+    pub fn set_counting_system(&mut self, system: CountingSystem) {
+        self.counter.system = system;
+        self.recount();
+    }
+
+    /// Reckons the decks left as closely as `estimation` says from now on.
+    pub fn set_deck_estimation(&mut self, estimation: DeckEstimation) {
+        self.counter.estimation = estimation;
+    }
+
+    /// Sets whether the counter side counts the Aces from now on.
+    pub fn set_ace_side_count(&mut self, ace_side_count: bool) {
+        self.counter.ace_side_count = ace_side_count;
+    }
+
+    /// Returns the shoe the cards are dealt from.
+    pub fn shoe(&self) -> &Shoe {
+        &self.shoe
+    }
+
+    /// Returns the entropy source the shoe is shuffled with, for anything else at the table that is
+    /// drawn at random, such as the opponents seated, so that a seeded game seats the same ones.
+    pub fn entropy_mut(&mut self) -> &mut dyn EntropySource {
+        &mut *self.entropy
+    }
+
+    /// Returns the seats at the table, the player's and any opponents'.
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Seats `opponents` at the table in place of any already there, to bet and play every round
+    /// from the next one on. The cards of the opponents leaving go back to the shoe.
+    pub fn seat_opponents(&mut self, opponents: Vec<Seat>) {
+        let player = self.table.player;
+        for (_, seat) in self
+            .table
+            .seats
+            .iter_mut()
+            .enumerate()
+            .filter(|(seat, _)| *seat != player)
+        {
+            for mut player_hand in seat.hands.drain(..) {
+                self.shoe.return_cards(player_hand.hand.clear());
+            }
+        }
+        self.table.seat_opponents(opponents);
+    }
+
+    /// Returns the player's hands and the wagers riding on them.
+    pub fn player_hands(&self) -> &Vec<PlayerHand> {
+        &self.table.player().hands
+    }
+
+    /// Returns the dealer's hand, with the hole card face-down until the dealer plays.
+    pub fn dealer_hand(&self) -> &Hand {
+        &self.dealer_hand
+    }
+
+    /// Returns the hands of the other players dealt the last round, if the player sat it out.
+    pub fn others(&self) -> &[Hand] {
+        &self.others
+    }
+
+    /// Returns everything on the table, for a `TableView` to draw.
+    #[cfg(feature = "std")]
+    pub fn frame(&self) -> TableFrame<'_> {
+        TableFrame {
+            dealer_hand: &self.dealer_hand,
+            player_hands: &self.table.player().hands,
+            opponents: self.table.opponents().collect(),
+            bankroll: self.table.player().bankroll.balance(),
+            bankroll_history: &self.stats.bankroll_history,
+            count: None,
+            cards_remaining: self.shoe.cards_remaining(),
+            decks: self.shoe.decks(),
+        }
+    }
+
+    /// Returns the chips the player has available to bet.
+    pub fn bankroll(&self) -> &Bankroll {
+        &self.table.player().bankroll
+    }
+
+    /// Returns the player's chips for changing between rounds, such as to buy back in.
+    pub fn bankroll_mut(&mut self) -> &mut Bankroll {
+        &mut self.table.player_mut().bankroll
+    }
+
+    /// Returns the statistics of every round played so far.
+    pub fn stats(&self) -> &SessionStats {
+        &self.stats
+    }
+
+    /// Returns the statistics for recording what the engine does not see itself, such as rebuys and
+    /// how the player's decisions compare with basic strategy.
+    pub fn stats_mut(&mut self) -> &mut SessionStats {
+        &mut self.stats
+    }
+
+    /// Returns the variant being played.
+    pub fn variant(&self) -> &dyn Variant {
+        &*self.variant
+    }
+
+    /// Returns the table rules.
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    /// Returns the policy the dealer draws by.
+    pub fn dealer_policy(&self) -> &dyn DealerPolicy {
+        &*self.dealer_policy
+    }
+
+    /// Replaces the policy the dealer draws by, which otherwise comes from the variant and rules.
+    pub fn set_dealer_policy(&mut self, policy: Box<dyn DealerPolicy>) {
+        self.dealer_policy = policy;
+    }
+
+    /// Returns the table limits on the main bet.
+    pub fn limits(&self) -> TableLimits {
+        self.limits
+    }
+
+    /// Returns the side bets offered at the table.
+    pub fn side_bets(&self) -> &[Box<dyn SideBet>] {
+        &self.side_bets
+    }
+
+    /// Offers `side_bets` at the table from the next round on, none of them wagered on until
+    /// `set_side_bet_wagers()` is given an amount for each.
+    pub fn set_side_bets(&mut self, side_bets: Vec<Box<dyn SideBet>>) {
+        self.side_bets = side_bets;
+        self.next_side_bet_wagers.clear();
+    }
+
+    /// Sets the chips wagered on each side bet, in the order they are offered, for the next bet to
+    /// take with the main bet. The wagers stay on for every round after until they are set again.
+    pub fn set_side_bet_wagers(&mut self, wagers: Vec<u32>) {
+        self.next_side_bet_wagers = wagers;
+    }
+
+    /// Returns the chips wagered on each side bet this round.
+    pub fn side_bet_wagers(&self) -> &[u32] {
+        &self.side_bet_wagers
+    }
+
+    /// Returns how each side bet settled on this round's opening cards: the winning combination and
+    /// its payout, or `None` if it lost.
+    pub fn side_bet_results(&self) -> &[Option<(&'static str, Payout)>] {
+        &self.side_bet_results
+    }
+
+    /// Returns the chips the player has on insurance this round, 0 if they did not take it.
+    pub fn insurance(&self) -> u32 {
+        self.insurance
+    }
+
+    /// Takes a snapshot of the whole game, which can be saved and later given to `restore()`.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            variant: self.variant.name().to_string(),
+            rules: self.rules,
+            limits: self.limits,
+            shoe: self.shoe.clone(),
+            bankroll: self.table.player().bankroll.clone(),
+            stats: self.stats.clone(),
+            player_hands: self.table.player().hands.clone(),
+            dealer_hand: self.dealer_hand.clone(),
+            others: self.others.clone(),
+            insurance: self.insurance,
+            state: self.state.clone(),
+        }
+    }
+
+    /// Recreates an engine playing `variant` from a snapshot, ready to carry on from the point the
+    /// snapshot was taken. The entropy source is seeded afresh.
+    #[cfg(feature = "std")]
+    pub fn restore(variant: Box<dyn Variant>, snapshot: Snapshot) -> Self {
+        Engine::restore_with_entropy(variant, snapshot, entropy::from_system())
+    }
+
+    /// Recreates an engine from a snapshot as `restore()` does, shuffling from then on with the
+    /// given entropy source.
+    pub fn restore_with_entropy(
+        variant: Box<dyn Variant>,
+        snapshot: Snapshot,
+        entropy: Box<dyn EntropySource>,
+    ) -> Self {
+        let rules = snapshot.rules;
+        let limits = snapshot.limits;
+        let mut engine = Engine::with_entropy(variant, rules, limits, Bankroll::new(0), entropy);
+        engine.load(snapshot);
+        engine
+    }
+
+    /// Carries this engine on from `snapshot`, keeping its variant, observers, side bets and
+    /// opponents. The opponents' hands belonged to the shoe being replaced, so they are cleared, and
+    /// so is the replay, as the game no longer follows from its seed.
+    pub fn resume(&mut self, snapshot: Snapshot) {
+        let player = self.table.player;
+        for (seat, opponent) in self.table.seats.iter_mut().enumerate() {
+            if seat != player {
+                opponent.hands.clear();
+            }
+        }
+        self.history.clear();
+        self.replay = None;
+        self.load(snapshot);
+    }
+
+    /// Puts the game back to the moment `snapshot` was taken.
+    fn load(&mut self, snapshot: Snapshot) {
+        self.rules = snapshot.rules;
+        self.limits = snapshot.limits;
+        self.shoe = snapshot.shoe;
+        self.table.player_mut().bankroll = snapshot.bankroll;
+        self.stats = snapshot.stats;
+        self.table.player_mut().hands = snapshot.player_hands;
+        self.dealer_hand = snapshot.dealer_hand;
+        self.others = snapshot.others;
+        self.insurance = snapshot.insurance;
+        self.state = snapshot.state;
+        self.recount();
+    }
+
+    /// Counts the cards dealt from the shoe again, as they were not seen being dealt, leaving out the
+    /// dealer's hole card while it is face down.
+    fn recount(&mut self) {
+        let hidden = (0..self.dealer_hand.len())
+            .filter(|&index| self.dealer_hand.is_face_down(index))
+            .map(|index| &self.dealer_hand.cards()[index]);
+        self.counter.resume(&self.shoe, hidden);
+    }
+
+    /// Turns practice mode on or off. In practice mode every decision of the round can be taken
+    /// back with `undo()`; leaving practice mode forgets them.
+    pub fn set_practice(&mut self, practice: bool) {
+        self.practice = practice;
+        if !practice {
+            self.history.clear();
+        }
+    }
+
+    /// Returns true if the engine is in practice mode.
+    pub fn is_practice(&self) -> bool {
+        self.practice
+    }
+
+    /// Returns the replay recorded since the engine was last seeded, or `None` if it has not been
+    /// seeded and so cannot be played again.
+    pub fn replay(&self) -> Option<&Replay> {
+        self.replay.as_ref()
+    }
+
+    /// Returns true if there is a decision that `undo()` can take back.
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    /// Takes back the last action of the round, putting the hands, shoe, bankroll, statistics and
+    /// entropy source back as they were, and returns the state the round is back in. The same cards
+    /// are dealt again if the same action is repeated, even across a shuffle, as long as the entropy
+    /// source can be forked. Undo is only allowed in practice mode, and never goes back past the
+    /// bet that dealt the round. Observers are not told of undone actions.
+    pub fn undo(&mut self) -> Result<GameState, BlackjackError> {
+        if !self.practice {
+            return Err(BlackjackError::UndoUnavailable);
+        }
+        let checkpoint = self.history.pop().ok_or(BlackjackError::NothingToUndo)?;
+        self.load(checkpoint.snapshot);
+        for (seat, (bankroll, hands)) in self.table.seats.iter_mut().zip(checkpoint.seats) {
+            seat.bankroll = bankroll;
+            seat.hands = hands;
+        }
+        if let Some(entropy) = checkpoint.entropy {
+            self.entropy = entropy;
+        }
+        if let Some(replay) = &mut self.replay {
+            replay.actions.pop();
+        }
+        Ok(self.state.clone())
+    }
+
+    /// Returns the game as it is now, for `undo()` to put back.
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            snapshot: self.snapshot(),
+            seats: self
+                .table
+                .seats
+                .iter()
+                .map(|seat| (seat.bankroll.clone(), seat.hands.clone()))
+                .collect(),
+            entropy: self.entropy.fork(),
+        }
+    }
+
+    /// Returns what the player sees at this point of the round, so that a `PlayerStrategy` can drive
+    /// the engine: while a bet is awaited, step with `Action::Bet` of the strategy's bet, and
+    /// otherwise with the action it decides on.
+    pub fn view(&self) -> GameView {
+        let index = match self.state {
+            GameState::AwaitingAction { hand } => Some(hand),
+            GameState::AwaitingInsurance | GameState::AwaitingSwitch => Some(0),
+            _ => None,
+        };
+        let mut view = self.seat_view(self.table.player, index, self.legal_actions());
+        // The bankroll must cover the bet on every hand the variant deals
+        if index.is_none() {
+            view.bankroll /= self.variant.hands_per_player() as u32;
+        }
+        view
+    }
+
+    /// Returns what `seat` sees when betting, or when acting on its hand at `index` with the given
+    /// legal actions.
+    fn seat_view(&self, seat: usize, index: Option<usize>, legal_actions: Vec<Action>) -> GameView {
+        let hand = index.map(|index| &self.table.seats[seat].hands[index].hand);
+        GameView {
+            hand: hand.cloned(),
+            dealer_up_card: hand.and(self.dealer_hand.cards().first().copied()),
+            legal_actions,
+            free_double: hand.is_some_and(|hand| self.variant.free_double(hand)),
+            free_split: hand.is_some_and(|hand| self.variant.free_split(hand)),
+            bankroll: self.table.seats[seat].bankroll.balance(),
+            limits: self.limits,
+            rules: self.rules,
+            count: self.counter,
+        }
+    }
+
+    /// Returns the actions the engine will accept in its current state. Where a bet is accepted, it
+    /// is listed as a bet of the table minimum, though any bet within the limits is allowed, and the
+    /// round can be sat out with `Action::SitOut` instead, which is not listed.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        match self.state {
+            GameState::AwaitingBet => vec![Action::Bet(self.limits.minimum)],
+            GameState::AwaitingInsurance => vec![Action::Insure, Action::DeclineInsurance],
+            GameState::Resolved { .. } => vec![Action::Bet(self.limits.minimum), Action::Continue],
+            GameState::AwaitingSwitch => vec![Action::Switch, Action::KeepHands],
+            GameState::AwaitingAction { hand } => self.hand_actions(self.table.player, hand),
+            GameState::DealerPlaying => vec![Action::Continue],
+        }
+    }
+
+    /// Returns the actions `seat` may take on its hand at `index`. A hit is only offered while
+    /// there is a card left to deal it.
+    fn hand_actions(&self, seat: usize, index: usize) -> Vec<Action> {
+        let mut actions = Vec::new();
+        if self.shoe.cards_remaining() >= 1 {
+            actions.push(Action::Hit);
+        }
+        actions.push(Action::Stand);
+        if self.can_double(seat, index) {
+            actions.push(Action::Double);
+        }
+        if self.can_split(seat, index) {
+            actions.push(Action::Split);
+        }
+        if self
+            .rules
+            .allows_surrender(&self.table.seats[seat].hands[index].hand)
+        {
+            actions.push(Action::Surrender);
+        }
+        actions
+    }
+
+    /// Applies `action` and returns the state the round has moved on to.
+    ///
+    /// A bet is accepted while awaiting a bet or once a round is resolved, which clears the table for
+    /// the next round, and so is sitting the round out. An action that does not fit the current
+    /// state is refused without changing anything, with an `IllegalAction` error listing the actions
+    /// that would have been accepted.
+    pub fn step(&mut self, action: Action) -> Result<GameState, BlackjackError> {
+        self.events.clear();
+        let before = self.practice.then(|| self.checkpoint());
+        let player = self.table.player;
+        self.state = match (&self.state, action) {
+            (GameState::AwaitingBet | GameState::Resolved { .. }, Action::Bet(bet)) => {
+                self.deal(bet)?
+            }
+            (GameState::AwaitingBet | GameState::Resolved { .. }, Action::SitOut(others)) => {
+                self.sit_out(others)?
+            }
+            (GameState::Resolved { .. }, Action::Continue) => GameState::AwaitingBet,
+            (GameState::AwaitingInsurance, Action::Insure) => {
+                let cost = self.insurance_cost();
+                self.stake(player, cost);
+                self.insurance = cost;
+                self.emit(GameEvent::PlayerAction { hand: 0, action });
+                self.open_play()?
+            }
+            (GameState::AwaitingInsurance, Action::DeclineInsurance) => {
+                self.emit(GameEvent::PlayerAction { hand: 0, action });
+                self.open_play()?
+            }
+            (GameState::AwaitingSwitch, Action::Switch) => {
+                self.emit(GameEvent::PlayerAction { hand: 0, action });
+                let (first, second) = self.table.seats[player].hands.split_at_mut(1);
+                first[0].hand.swap_second_card(&mut second[0].hand);
+                self.next_hand(0)?
+            }
+            (GameState::AwaitingSwitch, Action::KeepHands) => {
+                self.emit(GameEvent::PlayerAction { hand: 0, action });
+                self.next_hand(0)?
+            }
+            (&GameState::AwaitingAction { hand }, action)
+                if self.hand_actions(player, hand).contains(&action) =>
+            {
+                self.emit(GameEvent::PlayerAction {
+                    hand: self.table.seats[player].hands[hand].id,
+                    action,
+                });
+                if self.act(player, hand, action)? {
+                    self.next_hand(hand + 1)?
+                } else {
+                    self.next_hand(hand)?
+                }
+            }
+            (GameState::DealerPlaying, Action::Continue) => self.dealer_step()?,
+            _ => {
+                return Err(BlackjackError::IllegalAction {
+                    action,
+                    legal: self.legal_actions(),
+                })
+            }
+        };
+        if let Some(replay) = &mut self.replay {
+            replay.actions.push(action);
+        }
+        if let Some(before) = before {
+            if matches!(action, Action::Bet(_) | Action::SitOut(_)) {
+                self.history.clear();
+            } else {
+                self.history.push(before);
+            }
+        }
+        Ok(self.state.clone())
+    }
+
+    /// Takes a wager of `amount` chips from the bankroll of `seat`, recording it in the session
+    /// accounts if it is the player's. Returns false, without taking anything, if the bankroll cannot
+    /// cover it.
+    fn stake(&mut self, seat: usize, amount: u32) -> bool {
+        if !self.table.seats[seat].bankroll.withdraw(amount) {
+            return false;
+        }
+        if seat == self.table.player {
+            self.stats.wagered += amount as u64;
+        }
+        true
+    }
+
+    /// Takes a main bet of `bet` chips on every hand, along with the side bets, and deals the opening
+    /// cards to every seat with a bet down, offering insurance first if the dealer shows an Ace. A
+    /// bet that cannot be covered, or a round that cannot be dealt, is refused before anything is
+    /// staked or cleared from the table.
+    fn deal(&mut self, bet: u32) -> Result<GameState, BlackjackError> {
+        let player = self.table.player;
+        let hands = self.variant.hands_per_player();
+        self.limits.validate(bet)?;
+        for &wager in &self.next_side_bet_wagers {
+            self.limits.validate_side_bet(wager)?;
+        }
+        let stake = self
+            .next_side_bet_wagers
+            .iter()
+            .fold(bet.saturating_mul(hands as u32), |stake, &wager| {
+                stake.saturating_add(wager)
+            });
+        let balance = self.table.player().bankroll.balance();
+        if balance < stake {
+            return Err(BetError::InsufficientChips(balance).into());
+        }
+        // Every opponent may bet on a hand of their own
+        let seated = hands + self.table.seats.len() - 1;
+        if self.cards_for_round(seated) < 2 * (seated + 1) {
+            return Err(BlackjackError::EmptyDeck);
+        }
+        self.stake(player, stake);
+        self.emit(GameEvent::PlayerAction {
+            hand: 0,
+            action: Action::Bet(bet),
+        });
+
+        self.clear_table();
+        self.side_bet_wagers = self.next_side_bet_wagers.clone();
+        self.table.seats[player].hands = PlayerHand::opening(hands, bet);
+        self.place_opponent_bets()?;
+        self.deal_opening(0)?;
+
+        // Side bets are settled on the opening cards, before any switch or split changes them
+        self.side_bet_results = self
+            .side_bets
+            .iter()
+            .map(|side_bet| side_bet.resolve(&self.table.player().hands[0].hand, &self.dealer_hand))
+            .collect();
+
+        if self.offers_insurance() {
+            return Ok(GameState::AwaitingInsurance);
+        }
+        self.open_play()
+    }
+
+    /// Returns the chips insurance costs the player: half the main bet on every hand, rounded down.
+    fn insurance_cost(&self) -> u32 {
+        let stake = self
+            .table
+            .player()
+            .hands
+            .iter()
+            .fold(0u32, |stake, player_hand| {
+                stake.saturating_add(player_hand.wager.stake)
+            });
+        stake / 2
+    }
+
+    /// Returns true if the player is offered insurance on the cards just dealt: the dealer shows an
+    /// Ace over a face-down hole card, and the player can cover half their bet.
+    fn offers_insurance(&self) -> bool {
+        let cost = self.insurance_cost();
+        self.dealer_hand.cards()[0].rank == Rank::Ace
+            && self.dealer_hand.is_hole_card_hidden()
+            && cost > 0
+            && self.table.player().bankroll.balance() >= cost
+    }
+
+    /// Starts play on the opening cards once any insurance is settled on: the dealer peeks for a
+    /// natural, then the seats before the player's play and the player is offered a switch or their
+    /// first hand.
+    fn open_play(&mut self) -> Result<GameState, BlackjackError> {
+        let player = self.table.player;
+        if self.rules.dealer_peeks_blackjack(&self.dealer_hand) {
+            return Ok(GameState::DealerPlaying);
+        }
+        self.play_seats(0..player)?;
+        // Hands that are both naturals have nothing to gain from a switch
+        let naturals = self
+            .table
+            .player()
+            .hands
+            .iter()
+            .all(|player_hand| player_hand.hand.is_blackjack());
+        if self.variant.allows_switch() && !naturals {
+            Ok(GameState::AwaitingSwitch)
+        } else {
+            self.next_hand(0)
+        }
+    }
+
+    /// Has every opponent's strategy place a bet on a hand for the round, or sit it out.
+    fn place_opponent_bets(&mut self) -> Result<(), BlackjackError> {
+        for seat in 0..self.table.seats.len() {
+            if seat != self.table.player {
+                let view = self.seat_view(seat, None, Vec::new());
+                self.table.seats[seat].place_bet(&view)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Shuffles if the round is due a shuffle, then deals two cards to each hand at the table in
+    /// casino order, and to `others` other players after them, and the dealer's up card and hole
+    /// card last in each pass.
+    fn deal_opening(&mut self, others: usize) -> Result<(), BlackjackError> {
+        let hands = self
+            .table
+            .seats
+            .iter()
+            .map(|seat| seat.hands.len())
+            .sum::<usize>();
+        self.shuffle_if_due(hands + others);
+        self.others = vec![Hand::new(); others];
+        for round in 0..2 {
+            for seat in 0..self.table.seats.len() {
+                for index in 0..self.table.seats[seat].hands.len() {
+                    let card = self.draw(self.recipient(seat, index), true)?;
+                    self.table.seats[seat].hands[index].hand.add(card);
+                }
+            }
+            for other in 0..others {
+                let card = self.draw(self.other_recipient(other), true)?;
+                self.others[other].add(card);
+            }
+            self.deal_dealer(round)?;
+        }
+        Ok(())
+    }
+
+    /// Sits the round out while the opponents seated play it as usual, and deals it to `others`
+    /// other players as well, so the cards are dealt from the shoe as they would be at a busy
+    /// table. Each of the other players plays by basic strategy, doubling but never splitting, and
+    /// the dealer plays out the round whatever becomes of them; nothing of the player's is staked or
+    /// recorded in the statistics. Their hands are kept on the table as they are dealt, for
+    /// `others()` until the next round, which returns them to the shoe. More players than the
+    /// table seats are refused with a `TooManySeats` error before anything is dealt.
+    fn sit_out(&mut self, others: usize) -> Result<GameState, BlackjackError> {
+        let seated = others + self.table.seats.len() - 1;
+        if seated >= MAX_SEATS {
+            return Err(BlackjackError::TooManySeats(seated));
+        }
+        if self.cards_for_round(seated) < 2 * (seated + 1) {
+            return Err(BlackjackError::EmptyDeck);
+        }
+        self.clear_table();
+        self.place_opponent_bets()?;
+        self.deal_opening(others)?;
+
+        if !self.rules.dealer_peeks_blackjack(&self.dealer_hand) {
+            self.play_seats(0..self.table.seats.len())?;
+            let up_card = self.dealer_hand.cards()[0];
+            for other in 0..others {
+                loop {
+                    let hand = &self.others[other];
+                    if hand.calculate_hand_total() >= 21 {
+                        break;
+                    }
+                    let legal: &[Action] = match hand.len() {
+                        2 => &[Action::Hit, Action::Stand, Action::Double],
+                        _ => &[Action::Hit, Action::Stand],
+                    };
+                    let play = BasicStrategy::play(hand, up_card, &self.rules, legal);
+                    if play == Action::Stand {
+                        break;
+                    }
+                    let card = self.draw(self.other_recipient(other), true)?;
+                    self.others[other].add(card);
+                    if play == Action::Double {
+                        break;
+                    }
+                }
+            }
+        }
+        if self.dealer_hand.is_hole_card_hidden() {
+            self.dealer_hand.reveal();
+            self.emit(GameEvent::DealerReveal {
+                card: self.dealer_hand.cards()[1],
+            });
+        }
+        while self.dealer_policy.hits(&self.dealer_hand) {
+            let card = self.draw(Recipient::Dealer, true)?;
+            self.dealer_hand.add(card);
+        }
+        self.settle_opponents();
+
+        Ok(GameState::Resolved {
+            outcomes: Vec::new(),
+            net: 0,
+        })
+    }
+
+    /// Clears the last round's cards from the table, returning them to the shoe, along with the
+    /// side bets settled on them.
+    fn clear_table(&mut self) {
+        for seat in &mut self.table.seats {
+            for mut player_hand in seat.hands.drain(..) {
+                self.shoe.return_cards(player_hand.hand.clear());
+            }
+        }
+        for mut hand in self.others.drain(..) {
+            self.shoe.return_cards(hand.clear());
+        }
+        self.shoe.return_cards(self.dealer_hand.clear());
+        self.side_bet_wagers.clear();
+        self.side_bet_results.clear();
+        self.insurance = 0;
+    }
+
+    /// Shuffles once the cut card is out, or when there may not be enough cards left for a round of
+    /// `hands` hands and the dealer's.
+    fn shuffle_if_due(&mut self, hands: usize) {
+        if self.shuffle_due(hands) {
+            self.shoe.shuffle(&mut *self.entropy);
+            self.emit(GameEvent::ShoeShuffled {
+                decks: self.shoe.decks(),
+            });
+        }
+    }
+
+    /// Returns true if the shoe is to be shuffled before a round of `hands` hands is dealt.
+    fn shuffle_due(&self, hands: usize) -> bool {
+        self.shoe.needs_shuffle() || self.shoe.cards_remaining() < 10 * (hands + 1)
+    }
+
+    /// Returns the cards there will be to deal a round of `hands` hands from once the table is
+    /// cleared: every card of the shoe if it is to be shuffled first, or else the cards left.
+    fn cards_for_round(&self, hands: usize) -> usize {
+        if self.shuffle_due(hands) {
+            52 * self.shoe.decks()
+        } else {
+            self.shoe.cards_remaining()
+        }
+    }
+
+    /// Deals the dealer's card for the given `round` of the opening deal: the up card in the first,
+    /// and the hole card, face down unless the variant deals it face up, in the second.
+    fn deal_dealer(&mut self, round: usize) -> Result<(), BlackjackError> {
+        let face_up = round == 0 || self.variant.dealer_cards_face_up();
+        let card = self.draw(Recipient::Dealer, face_up)?;
+        if face_up {
+            self.dealer_hand.add(card);
+        } else {
+            self.dealer_hand.add_face_down(card);
+        }
+        Ok(())
+    }
+
+    /// Reports an event to the counter and every observer, and keeps it for `events()`.
+    fn emit(&mut self, event: GameEvent) {
+        self.counter.on_event(&event);
+        for observer in &mut self.observers {
+            observer.on_event(&event);
+        }
+        self.events.push(event);
+    }
+
+    /// Draws a card from the shoe for `recipient` and reports it being dealt.
+    fn draw(&mut self, recipient: Recipient, face_up: bool) -> Result<Card, BlackjackError> {
+        let card = self.shoe.draw()?;
+        self.emit(GameEvent::CardDealt {
+            card,
+            recipient,
+            face_up,
+        });
+        Ok(card)
+    }
+
+    /// Returns who is dealt the card for the hand at `index` of `seat`.
+    fn recipient(&self, seat: usize, index: usize) -> Recipient {
+        let hand = self.table.seats[seat].hands[index].id;
+        if seat == self.table.player {
+            Recipient::Player(hand)
+        } else {
+            Recipient::Opponent { seat, hand }
+        }
+    }
+
+    /// Returns who is dealt the cards of the other player `other` dealt a round the player sits
+    /// out, who sit in the empty seats after those taken at the table.
+    fn other_recipient(&self, other: usize) -> Recipient {
+        Recipient::Opponent {
+            seat: self.table.seats.len() + other,
+            hand: 1,
+        }
+    }
+
+    /// Returns true if the hand at `index` of `seat` may be doubled, the double can be paid for and
+    /// there is a card left to deal it.
+    fn can_double(&self, seat: usize, index: usize) -> bool {
+        let seat = &self.table.seats[seat];
+        let player_hand = &seat.hands[index];
+        self.shoe.cards_remaining() >= 1
+            && self.rules.allows_double(&player_hand.hand)
+            && (self.variant.free_double(&player_hand.hand)
+                || seat.bankroll.balance() >= player_hand.wager.total())
+    }
+
+    /// Returns true if the hand at `index` of `seat` is a pair that may be split, the split can be
+    /// paid for and there are cards left to deal both hands.
+    fn can_split(&self, seat: usize, index: usize) -> bool {
+        let seat = &self.table.seats[seat];
+        let player_hand = &seat.hands[index];
+        self.shoe.cards_remaining() >= 2
+            && self.rules.allows_split(&player_hand.hand, seat.hands.len())
+            && (self.variant.free_split(&player_hand.hand)
+                || seat.bankroll.balance() >= player_hand.wager.total())
+    }
+
+    /// Applies `action` to the hand at `index` of `seat`, staking whatever it costs, and returns
+    /// true if the hand is finished. The action must be one of the hand's legal actions.
+    fn act(&mut self, seat: usize, index: usize, action: Action) -> Result<bool, BlackjackError> {
+        match action {
+            Action::Hit => {
+                let card = self.draw(self.recipient(seat, index), true)?;
+                let hand = &mut self.table.seats[seat].hands[index].hand;
+                hand.add(card);
+                Ok(hand.is_bust() || self.rules.is_charlie(hand))
+            }
+            Action::Stand => Ok(true),
+            Action::Surrender => {
+                self.table.seats[seat].hands[index].surrendered = true;
+                Ok(true)
+            }
+            Action::Double => {
+                let player_hand = &self.table.seats[seat].hands[index];
+                let free = self.variant.free_double(&player_hand.hand);
+                if !free {
+                    self.stake(seat, player_hand.wager.total());
+                }
+                let card = self.draw(self.recipient(seat, index), true)?;
+                let player_hand = &mut self.table.seats[seat].hands[index];
+                player_hand.wager.double(free);
+                player_hand.hand.add(card);
+                Ok(true)
+            }
+            Action::Split => {
+                let player_hand = &self.table.seats[seat].hands[index];
+                let cost = player_hand.wager.total();
+                let wager = if self.variant.free_split(&player_hand.hand) {
+                    Wager {
+                        stake: 0,
+                        free: cost,
+                    }
+                } else {
+                    self.stake(seat, cost);
+                    Wager::new(cost)
+                };
+                let hands = &mut self.table.seats[seat].hands;
+                let id = hands.len() + 1;
+                let new_hand = hands[index].split(id, wager);
+                hands.insert(index + 1, new_hand);
+                for index in [index, index + 1] {
+                    let card = self.draw(self.recipient(seat, index), true)?;
+                    self.table.seats[seat].hands[index].hand.add(card);
+                }
+                Ok(false)
+            }
+            action => Err(BlackjackError::IllegalAction {
+                action,
+                legal: self.hand_actions(seat, index),
+            }),
+        }
+    }
+
+    /// Returns true if the hand at `index` of `seat` is played no further as it was dealt: split
+    /// Aces, which receive a single card each, and two cards totalling 21, which have nothing to
+    /// gain.
+    fn stands_as_dealt(&self, seat: usize, index: usize) -> bool {
+        let hand = &self.table.seats[seat].hands[index].hand;
+        hand.is_split_aces() || (hand.len() == 2 && hand.calculate_hand_total() == 21)
+    }
+
+    /// Plays each opponent's hands in `seats` by its strategy. Hands created by a split are played
+    /// in turn once the hand before them is finished.
+    fn play_seats(&mut self, seats: Range<usize>) -> Result<(), BlackjackError> {
+        let player = self.table.player;
+        for seat in seats.filter(|&seat| seat != player) {
+            let mut index = 0;
+            while index < self.table.seats[seat].hands.len() {
+                if self.stands_as_dealt(seat, index) {
+                    index += 1;
+                    continue;
+                }
+                let legal = self.hand_actions(seat, index);
+                let view = self.seat_view(seat, Some(index), legal.clone());
+                let Some(strategy) = &mut self.table.seats[seat].strategy else {
+                    break;
+                };
+                let action = strategy.decide(&view)?;
+                if !legal.contains(&action) {
+                    return Err(BlackjackError::IllegalAction { action, legal });
+                }
+                if self.act(seat, index, action)? {
+                    index += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the state for the first of the player's hands from `index` onwards still to be
+    /// played, or, once every hand is finished, plays the seats after the player's and hands over to
+    /// the dealer.
+    fn next_hand(&mut self, index: usize) -> Result<GameState, BlackjackError> {
+        let player = self.table.player;
+        let hands = self.table.seats[player].hands.len();
+        if let Some(hand) = (index..hands).find(|&hand| !self.stands_as_dealt(player, hand)) {
+            return Ok(GameState::AwaitingAction { hand });
+        }
+        self.play_seats(player + 1..self.table.seats.len())?;
+        Ok(GameState::DealerPlaying)
+    }
+
+    /// Reveals the dealer's hole card or draws one card, settling the round once the dealer stands.
+    fn dealer_step(&mut self) -> Result<GameState, BlackjackError> {
+        if self.dealer_hand.is_hole_card_hidden() {
+            self.dealer_hand.reveal();
+            self.emit(GameEvent::DealerReveal {
+                card: self.dealer_hand.cards()[1],
+            });
+        } else if self.dealer_policy.hits(&self.dealer_hand) {
+            let card = self.draw(Recipient::Dealer, true)?;
+            self.dealer_hand.add(card);
+        }
+
+        Ok(if self.dealer_policy.hits(&self.dealer_hand) {
+            GameState::DealerPlaying
+        } else {
+            self.settle()
+        })
+    }
+
+    /// Settles every player hand, side bet and insurance and pays the returns into the bankroll,
+    /// then settles the opponents.
+    fn settle(&mut self) -> GameState {
+        let player = self.table.player;
+        let settled = settle_hands(
+            &self.rules,
+            &*self.variant,
+            &self.table.seats[player].hands,
+            &self.dealer_hand,
+        );
+        let mut outcomes = Vec::new();
+        let mut total = Settlement::default();
+        for (index, (outcome, settlement)) in settled.into_iter().enumerate() {
+            let player_hand = &self.table.seats[player].hands[index];
+            self.stats.record_hand(&player_hand.hand, outcome);
+            let event = GameEvent::HandResolved {
+                hand: player_hand.id,
+                parent: player_hand.parent,
+                outcome,
+                net: settlement.net(),
+            };
+            total += settlement;
+            outcomes.push(outcome);
+            self.emit(event);
+        }
+        for (result, &wager) in self.side_bet_results.iter().zip(&self.side_bet_wagers) {
+            if wager > 0 {
+                total += Settlement::side_bet(wager, result.map(|(_, payout)| payout));
+            }
+        }
+        if self.insurance > 0 {
+            total += Settlement::insurance(self.insurance, self.dealer_hand.is_blackjack());
+        }
+        self.table.seats[player].bankroll.deposit(total.returned);
+        self.stats.returned += total.returned as u64;
+        self.stats
+            .record_round(self.table.seats[player].bankroll.balance(), total.net());
+        self.settle_opponents();
+
+        GameState::Resolved {
+            outcomes,
+            net: total.net(),
+        }
+    }
+
+    /// Settles every opponent's hands against the dealer and pays the returns into their bankrolls.
+    fn settle_opponents(&mut self) {
+        for seat in 0..self.table.seats.len() {
+            if seat == self.table.player {
+                continue;
+            }
+            let mut total = Settlement::default();
+            for (_, settlement) in settle_hands(
+                &self.rules,
+                &*self.variant,
+                &self.table.seats[seat].hands,
+                &self.dealer_hand,
+            ) {
+                total += settlement;
+            }
+            self.table.seats[seat].bankroll.deposit(total.returned);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{BlackjackSwitch, Classic};
+
+    /// Returns an engine playing `variant` with `chips` chips at the lowest table limits, whose
+    /// cards are shuffled from `seed`.
+    fn engine(variant: Box<dyn Variant>, chips: u32, seed: u64) -> Engine {
+        let mut engine = Engine::new(
+            variant,
+            RuleSet::default(),
+            TableLimits::TABLES[0],
+            Bankroll::new(chips),
+        );
+        engine.seed(seed);
+        engine
+    }
+
+    /// Returns a seeded engine in practice mode, dealt a round that waits for the player to act.
+    fn dealt_engine() -> Engine {
+        for seed in 0.. {
+            let mut engine = engine(Box::new(Classic), 1000, seed);
+            engine.set_practice(true);
+            if let Ok(GameState::AwaitingAction { .. }) = engine.step(Action::Bet(10)) {
+                return engine;
+            }
+        }
+        unreachable!("every seed dealt a round over at once")
+    }
+
+    /// Bets `chips` and turns insurance down if it is offered, returning the state the round is in.
+    fn bet(engine: &mut Engine, chips: u32) -> GameState {
+        match engine.step(Action::Bet(chips)).unwrap() {
+            GameState::AwaitingInsurance => engine.step(Action::DeclineInsurance).unwrap(),
+            state => state,
+        }
+    }
+
+    #[test]
+    fn a_bet_that_cannot_be_covered_takes_no_chips() {
+        let mut engine = engine(Box::new(BlackjackSwitch), 30, 0);
+        assert!(matches!(
+            engine.step(Action::Bet(20)),
+            Err(BlackjackError::Bet(BetError::InsufficientChips(30)))
+        ));
+        assert_eq!(engine.bankroll().balance(), 30);
+        assert_eq!(engine.stats().wagered, 0);
+        assert_eq!(engine.state(), &GameState::AwaitingBet);
+    }
+
+    #[test]
+    fn a_two_card_21_stands_without_waiting_for_the_player() {
+        for seed in 0..1000 {
+            let mut engine = engine(Box::new(Classic), 1000, seed);
+            let state = bet(&mut engine, 10);
+            if engine.player_hands()[0].hand.calculate_hand_total() == 21 {
+                assert_eq!(state, GameState::DealerPlaying);
+                return;
+            }
+        }
+        panic!("no seed dealt a 21");
+    }
+
+    #[test]
+    fn two_naturals_are_not_offered_a_switch() {
+        for seed in 0..10_000 {
+            let mut engine = engine(Box::new(BlackjackSwitch), 1000, seed);
+            let state = bet(&mut engine, 10);
+            let hands = engine.player_hands();
+            if hands
+                .iter()
+                .all(|player_hand| player_hand.hand.is_blackjack())
+            {
+                assert_eq!(state, GameState::DealerPlaying);
+                return;
+            }
+        }
+        panic!("no seed dealt two naturals");
+    }
+
+    #[test]
+    fn every_action_is_read_back_from_its_code() {
+        let actions = [
+            Action::Bet(25),
+            Action::Insure,
+            Action::DeclineInsurance,
+            Action::Switch,
+            Action::KeepHands,
+            Action::Hit,
+            Action::Stand,
+            Action::Double,
+            Action::Split,
+            Action::Surrender,
+            Action::Continue,
+            Action::SitOut(3),
+        ];
+        for action in actions {
+            assert_eq!(Action::from_code(&action.code()).unwrap(), action);
+        }
+    }
+
+    #[test]
+    fn unknown_codes_are_refused() {
+        for code in ["", "x", "b", "b-5", "o", "h1", "B25"] {
+            assert!(
+                matches!(
+                    Action::from_code(code),
+                    Err(BlackjackError::InvalidAction(_))
+                ),
+                "{:?} was read as an action",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn an_undone_hit_draws_the_same_card_again() {
+        let mut engine = dealt_engine();
+        engine.step(Action::Hit).unwrap();
+        let hand = engine.player_hands()[0].hand.clone();
+        engine.undo().unwrap();
+        engine.step(Action::Hit).unwrap();
+        assert_eq!(engine.player_hands()[0].hand, hand);
+        assert_eq!(
+            engine.replay().unwrap().actions,
+            [Action::Bet(10), Action::Hit]
+        );
+    }
+
+    #[test]
+    fn undo_puts_the_entropy_source_back() {
+        let mut engine = dealt_engine();
+        let expected = engine.entropy.fork().unwrap().next_u64();
+        engine.step(Action::Hit).unwrap();
+        // As a shuffle part way through the action would
+        engine.entropy.next_u64();
+        engine.undo().unwrap();
+        assert_eq!(engine.entropy.next_u64(), expected);
+    }
+
+    #[test]
+    fn a_round_sat_out_is_dealt_to_the_others_without_a_stake() {
+        let mut engine = engine(Box::new(Classic), 1000, 0);
+        let state = engine.step(Action::SitOut(3)).unwrap();
+        assert!(matches!(state, GameState::Resolved { net: 0, .. }));
+        assert_eq!(engine.others().len(), 3);
+        assert!(engine.others().iter().all(|hand| hand.len() >= 2));
+        assert!(engine.player_hands().is_empty());
+        assert_eq!(engine.bankroll().balance(), 1000);
+    }
+
+    #[test]
+    fn sitting_out_for_more_players_than_the_table_seats_is_refused() {
+        let mut engine = engine(Box::new(Classic), 1000, 0);
+        assert!(matches!(
+            engine.step(Action::SitOut(MAX_SEATS)),
+            Err(BlackjackError::TooManySeats(seats)) if seats == MAX_SEATS
+        ));
+        assert!(engine.others().is_empty());
+        assert_eq!(engine.state, GameState::AwaitingBet);
+    }
+}
@@ -0,0 +1,54 @@
+use super::{Action, Outcome};
+use crate::card::Card;
+use serde::{Deserialize, Serialize};
+
+/// Who a card was dealt to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Recipient {
+    /// The player's hand with the given ID.
+    Player(usize),
+    /// The hand with ID `hand` of the opponent sitting in seat `seat`.
+    Opponent { seat: usize, hand: usize },
+    /// The dealer.
+    Dealer,
+}
+
+/// Something that happened during a round, reported to every `GameObserver`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// The shoe was shuffled, so every card is back in play.
+    ShoeShuffled { decks: usize },
+    /// A card was dealt. Face-down cards are reported too, and revealed later by `DealerReveal`.
+    CardDealt {
+        card: Card,
+        recipient: Recipient,
+        face_up: bool,
+    },
+    /// The player made a move. `hand` is the ID of the hand acted on, or 0 for a bet or a switch.
+    PlayerAction { hand: usize, action: Action },
+    /// The dealer turned over the hole card.
+    DealerReveal { card: Card },
+    /// The player's hand with ID `hand` was settled, winning or losing `net` chips. `parent` is the
+    /// hand it was split from, if any.
+    HandResolved {
+        hand: usize,
+        parent: Option<usize>,
+        outcome: Outcome,
+        net: i64,
+    },
+}
+
+/// The `GameObserver` trait is implemented by anything that wants to follow a game as it is played,
+/// such as a user interface, a logger, a statistics tracker or a card counter.
+///
+/// Observers are subscribed to a `GameController` or an `Engine`, which passes each `GameEvent` to
+/// `on_event()` as it happens. Any closure taking a `&GameEvent` is also an observer.
+pub trait GameObserver {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+impl<F: FnMut(&GameEvent)> GameObserver for F {
+    fn on_event(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::mem;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::BlackjackError;
+use crate::PlayerInput;
+
+/// Reads the player's choices from the keyboard. Once stdin is closed, for example when piped input
+/// runs out, reading another choice returns an `UnexpectedEof` error, so the game ends instead of
+/// asking again forever.
+///
+/// A read from stdin cannot be given up part way, so the first choice read with a time limit starts
+/// a thread that reads every line from then on, and choices are taken from it. Lines typed after
+/// the time ran out, before the next prompt, are dropped rather than answering it.
+pub struct StdinInput;
+
+/// The lines read from stdin by the reading thread, once it has been started.
+static STDIN_LINES: OnceLock<Mutex<StdinLines>> = OnceLock::new();
+
+/// The lines read from stdin by the reading thread, and whether the last read ran out of time.
+struct StdinLines {
+    receiver: Receiver<io::Result<String>>,
+    timed_out: bool,
+}
+
+impl StdinLines {
+    /// Returns the lines read from stdin, starting the reading thread on the first call.
+    fn get() -> &'static Mutex<StdinLines> {
+        STDIN_LINES.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel();
+            thread::spawn(move || loop {
+                let line = read_line();
+                let done = line.is_err();
+                if sender.send(line).is_err() || done {
+                    break;
+                }
+            });
+            Mutex::new(StdinLines {
+                receiver,
+                timed_out: false,
+            })
+        })
+    }
+
+    /// Waits for the next line, for at most `limit` if one is given, returning `None` if the time
+    /// runs out. Lines typed after the last read ran out of time were meant for that prompt, so
+    /// they are dropped.
+    fn next(&mut self, limit: Option<Duration>) -> io::Result<Option<String>> {
+        if mem::take(&mut self.timed_out) {
+            while let Ok(Ok(_)) = self.receiver.try_recv() {}
+        }
+        let line = match limit {
+            Some(limit) => self.receiver.recv_timeout(limit),
+            None => self
+                .receiver
+                .recv()
+                .map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match line {
+            Ok(line) => line.map(Some),
+            Err(RecvTimeoutError::Timeout) => {
+                self.timed_out = true;
+                Ok(None)
+            }
+            // The thread stops once stdin is closed
+            Err(RecvTimeoutError::Disconnected) => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+/// Reads a line from stdin, or returns an `UnexpectedEof` error once stdin is closed.
+fn read_line() -> io::Result<String> {
+    let mut line = String::new();
+    if io::stdin().read_line(&mut line)? == 0 {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+    Ok(line)
+}
+
+impl PlayerInput for StdinInput {
+    /// Reads a line from stdin and returns it trimmed and lowercased.
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        io::stdout().flush()?;
+        let line = match STDIN_LINES.get() {
+            Some(lines) => lines.lock().unwrap().next(None)?.unwrap_or_default(),
+            None => read_line()?,
+        };
+        Ok(line.trim().to_lowercase())
+    }
+
+    /// Waits at most `limit` for a line from stdin, returning it trimmed and lowercased.
+    fn read_choice_within(&mut self, limit: Duration) -> Result<Option<String>, BlackjackError> {
+        io::stdout().flush()?;
+        let line = StdinLines::get().lock().unwrap().next(Some(limit))?;
+        Ok(line.map(|line| line.trim().to_lowercase()))
+    }
+}
+
+/// Reads the player's choices a line at a time from any buffered reader, such as a network
+/// connection. Once the reader has no more lines, reading another choice returns an
+/// `UnexpectedEof` error, so a player who disconnects ends the game.
+pub struct LineInput<R: BufRead> {
+    pub reader: R,
+}
+
+impl<R: BufRead> LineInput<R> {
+    /// Creates an input that reads the player's choices from `reader`.
+    pub fn new(reader: R) -> Self {
+        LineInput { reader }
+    }
+}
+
+impl<R: BufRead> PlayerInput for LineInput<R> {
+    /// Reads the next line and returns it trimmed and lowercased.
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        let mut choice = String::new();
+        if self.reader.read_line(&mut choice)? == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(choice.trim().to_lowercase())
+    }
+}
+
+/// Plays back a fixed list of choices, as if the player had typed them one after another.
+///
+/// A `ScriptedInput` lets a game be driven without a keyboard, for example by a test, a bot or a
+/// server relaying choices from elsewhere. Once every choice has been used, reading another one
+/// returns `BlackjackError::InputExhausted`, so a script that runs out ends the game rather than
+/// leaving it waiting.
+pub struct ScriptedInput {
+    pub choices: VecDeque<String>,
+}
+
+impl ScriptedInput {
+    /// Creates an input that answers each prompt with the next of `choices`.
+    pub fn new<S: Into<String>>(choices: impl IntoIterator<Item = S>) -> Self {
+        ScriptedInput {
+            choices: choices.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the number of choices not yet read.
+    pub fn remaining(&self) -> usize {
+        self.choices.len()
+    }
+}
+
+impl PlayerInput for ScriptedInput {
+    /// Returns the next choice, trimmed and lowercased like typed input.
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        let choice = self
+            .choices
+            .pop_front()
+            .ok_or(BlackjackError::InputExhausted)?;
+        Ok(choice.trim().to_lowercase())
+    }
+}
+
+impl<T: PlayerInput + ?Sized> PlayerInput for &mut T {
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        (**self).read_choice()
+    }
+
+    fn read_choice_within(&mut self, limit: Duration) -> Result<Option<String>, BlackjackError> {
+        (**self).read_choice_within(limit)
+    }
+
+    fn read_key(
+        &mut self,
+        keys: &[&str],
+        limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        (**self).read_key(keys, limit)
+    }
+}
+
+impl<T: PlayerInput + ?Sized> PlayerInput for Box<T> {
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        (**self).read_choice()
+    }
+
+    fn read_choice_within(&mut self, limit: Duration) -> Result<Option<String>, BlackjackError> {
+        (**self).read_choice_within(limit)
+    }
+
+    fn read_key(
+        &mut self,
+        keys: &[&str],
+        limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        (**self).read_key(keys, limit)
+    }
+}
@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+/// The names of the choices at a decision that can be bound to other keys, in the order they are
+/// offered.
+pub const KEY_NAMES: [&str; 8] = [
+    "hit",
+    "stand",
+    "double",
+    "split",
+    "surrender",
+    "advice",
+    "count",
+    "quit",
+];
+
+/// The reasons a key cannot be bound to a choice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum KeyError {
+    /// No choice has the name given.
+    #[error(
+        "there is no such choice, choose one of: hit, stand, double, split, surrender, advice, count, quit"
+    )]
+    UnknownChoice,
+    /// The key for the named choice is empty or holds spaces.
+    #[error("the key for {0} must be one word without spaces")]
+    InvalidKey(&'static str),
+    /// Two choices were given the same key.
+    #[error("{0} and {1} cannot have the same key")]
+    Clash(&'static str, &'static str),
+}
+
+/// The keys the player types to choose what to do with a hand.
+///
+/// A key can be a single letter or a whole word, such as `hit` or `dd`, and is matched without
+/// regard to case. By default each choice is the letter shown in brackets in the prompt:
+/// `(h)it`, `(s)tand`, `(d)ouble`, `s(p)lit`, `su(r)render`, `(a)dvice`, which shows the basic
+/// strategy play, `(c)ount`, which shows or hides the card count, and `(q)uit`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyBindings {
+    pub hit: String,
+    pub stand: String,
+    pub double: String,
+    pub split: String,
+    pub surrender: String,
+    pub advice: String,
+    pub count: String,
+    pub quit: String,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            hit: "h".to_string(),
+            stand: "s".to_string(),
+            double: "d".to_string(),
+            split: "p".to_string(),
+            surrender: "r".to_string(),
+            advice: "a".to_string(),
+            count: "c".to_string(),
+            quit: "q".to_string(),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Binds the choice called `name`, one of `KEY_NAMES`, to `key`.
+    pub fn bind(&mut self, name: &str, key: &str) -> Result<(), KeyError> {
+        let (name, slot) = match name {
+            "hit" => ("hit", &mut self.hit),
+            "stand" => ("stand", &mut self.stand),
+            "double" => ("double", &mut self.double),
+            "split" => ("split", &mut self.split),
+            "surrender" => ("surrender", &mut self.surrender),
+            "advice" => ("advice", &mut self.advice),
+            "count" => ("count", &mut self.count),
+            "quit" => ("quit", &mut self.quit),
+            _ => return Err(KeyError::UnknownChoice),
+        };
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(KeyError::InvalidKey(name));
+        }
+        *slot = key.to_lowercase();
+        Ok(())
+    }
+
+    /// Checks that no two choices share a key, so every key the player types means one thing.
+    pub fn validate(&self) -> Result<(), KeyError> {
+        let keys = self.keys();
+        for (index, (name, key)) in keys.iter().enumerate() {
+            if let Some((other, _)) = keys[index + 1..].iter().find(|(_, other)| other == key) {
+                return Err(KeyError::Clash(name, other));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the prompt label for the choice called `name`: the key is put in brackets where it
+    /// appears in the name, as in `s(p)lit`, or after the name, as in `hit (j)`.
+    pub fn label(&self, name: &str) -> String {
+        self.label_as(name, name)
+    }
+
+    /// Returns the prompt label for the choice called `name`, shown as `word`, such as the name in
+    /// another language: the key is put in brackets where it appears in the word, as in
+    /// `se(p)arar`, or after the word, as in `pedir (h)`.
+    pub fn label_as(&self, name: &str, word: &str) -> String {
+        let Some((_, key)) = self.keys().into_iter().find(|(other, _)| *other == name) else {
+            return word.to_string();
+        };
+        match word.find(key) {
+            Some(start) => format!("{}({}){}", &word[..start], key, &word[start + key.len()..]),
+            None => format!("{} ({})", word, key),
+        }
+    }
+
+    /// Returns every choice's name with its key, in the order of `KEY_NAMES`.
+    pub fn keys(&self) -> [(&'static str, &str); 8] {
+        [
+            ("hit", &self.hit),
+            ("stand", &self.stand),
+            ("double", &self.double),
+            ("split", &self.split),
+            ("surrender", &self.surrender),
+            ("advice", &self.advice),
+            ("count", &self.count),
+            ("quit", &self.quit),
+        ]
+    }
+}
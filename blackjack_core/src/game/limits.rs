@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+/// The smallest and largest main bet accepted at a table, which hold for side bets too.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableLimits {
+    pub minimum: u32,
+    pub maximum: u32,
+}
+
+/// The reasons a main bet can be refused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BetError {
+    /// The bet is below the table minimum.
+    #[error("The table minimum is {0} chips.")]
+    BelowMinimum(u32),
+    /// The bet is above the table maximum.
+    #[error("The table maximum is {0} chips.")]
+    AboveMaximum(u32),
+    /// The bankroll cannot cover the bet on every hand; holds the chips available.
+    #[error("You only have {0} chips to cover that bet.")]
+    InsufficientChips(u32),
+}
+
+impl TableLimits {
+    /// The limits offered when the player picks a table.
+    pub const TABLES: [TableLimits; 3] = [
+        TableLimits {
+            minimum: 5,
+            maximum: 500,
+        },
+        TableLimits {
+            minimum: 25,
+            maximum: 1000,
+        },
+        TableLimits {
+            minimum: 100,
+            maximum: 5000,
+        },
+    ];
+
+    /// Checks that `bet` is within the table limits.
+    pub fn validate(&self, bet: u32) -> Result<(), BetError> {
+        if bet < self.minimum {
+            Err(BetError::BelowMinimum(self.minimum))
+        } else if bet > self.maximum {
+            Err(BetError::AboveMaximum(self.maximum))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that a side bet of `wager` chips is within the table limits, or is no bet at all.
+    pub fn validate_side_bet(&self, wager: u32) -> Result<(), BetError> {
+        match wager {
+            0 => Ok(()),
+            wager => self.validate(wager),
+        }
+    }
+}
+
+impl core::fmt::Display for TableLimits {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}-{} chips", self.minimum, self.maximum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn side_bets_are_held_to_the_table_limits_unless_skipped() {
+        let limits = TableLimits::TABLES[1];
+
+        assert_eq!(limits.validate_side_bet(0), Ok(()));
+        assert_eq!(limits.validate_side_bet(25), Ok(()));
+        assert_eq!(limits.validate_side_bet(5), Err(BetError::BelowMinimum(25)));
+        assert_eq!(
+            limits.validate_side_bet(1001),
+            Err(BetError::AboveMaximum(1000))
+        );
+    }
+}
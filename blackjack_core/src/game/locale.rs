@@ -0,0 +1,362 @@
+use alloc::collections::BTreeMap;
+use core::fmt::Display;
+
+use super::{Action, BetError, CardCounter, Outcome, MAX_SEATS};
+use crate::card::{Card, Rank, Suit};
+use crate::error::BlackjackError;
+use crate::hand::HandValue;
+use crate::prelude::*;
+
+/// The English text, which every other locale falls back to.
+const ENGLISH: &str = include_str!("../../locales/en.ftl");
+
+/// The locales built into the game, by name.
+const LOCALES: [(&str, &str); 2] = [
+    ("en", ENGLISH),
+    ("es", include_str!("../../locales/es.ftl")),
+];
+
+/// The text the game shows in one language: its prompts, messages, results and the names of cards.
+///
+/// The text is kept in locale files, one per language, in a small subset of the Fluent format:
+/// each line gives the text for a key as `key = text`, and a `{ $name }` in the text is replaced
+/// with the value of that name given to `message()`. Lines starting with `#` are comments. A key
+/// missing from a locale is shown in English, and a key missing from English is shown as it is.
+///
+/// The default locale is English, and `named()` gives it and the other built-in locales.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    pub name: String,
+    messages: BTreeMap<String, String>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        let mut locale = Locale {
+            name: "en".to_string(),
+            messages: BTreeMap::new(),
+        };
+        locale.read(ENGLISH);
+        locale
+    }
+}
+
+impl Locale {
+    /// The names of the built-in locales: English and Spanish.
+    pub const NAMES: [&'static str; 2] = ["en", "es"];
+
+    /// Returns the built-in locale called `name`, one of `NAMES`.
+    pub fn named(name: &str) -> Option<Locale> {
+        let (name, text) = LOCALES.iter().find(|(other, _)| *other == name)?;
+        let mut locale = Locale {
+            name: name.to_string(),
+            ..Locale::default()
+        };
+        locale.read(text);
+        Some(locale)
+    }
+
+    /// Reads the text of a locale file, replacing the text of every key it gives.
+    fn read(&mut self, text: &str) {
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                self.messages
+                    .insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    /// Returns the text for `key`.
+    pub fn text(&self, key: &str) -> String {
+        self.message(key, &[])
+    }
+
+    /// Returns the text for `key`, with each `{ $name }` in it replaced by the value of that name
+    /// in `args`.
+    pub fn message(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let Some(text) = self.messages.get(key) else {
+            return key.to_string();
+        };
+        let mut text = text.clone();
+        for (name, value) in args {
+            text = text.replace(&format!("{{ ${} }}", name), &value.to_string());
+        }
+        text
+    }
+
+    /// Returns the name of `rank` in words, such as "Seven".
+    pub fn rank(&self, rank: Rank) -> String {
+        self.text(&format!("rank-{}", rank.name().to_lowercase()))
+    }
+
+    /// Returns the name of `suit`, such as "Hearts".
+    pub fn suit(&self, suit: Suit) -> String {
+        self.text(&format!("suit-{}", suit.to_string().to_lowercase()))
+    }
+
+    /// Returns a card named from its rank and suit as they are written, such as "A of ♠" or
+    /// "Ace of Spades".
+    pub fn card(&self, rank: &dyn Display, suit: &dyn Display) -> String {
+        self.message("card-name", &[("rank", rank), ("suit", suit)])
+    }
+
+    /// Returns `card` named in words, such as "Seven of Clubs".
+    pub fn card_in_words(&self, card: &Card) -> String {
+        self.card(&self.rank(card.rank), &self.suit(card.suit))
+    }
+
+    /// Returns a hand's value as it is called at the table, such as "soft 17" or "20".
+    pub fn value(&self, value: HandValue) -> String {
+        if value.soft {
+            self.message("soft-total", &[("total", &value.total)])
+        } else {
+            value.total.to_string()
+        }
+    }
+
+    /// Returns a hand's value in words, as it is read aloud, such as "soft eighteen".
+    pub fn value_in_words(&self, value: HandValue) -> String {
+        let key = format!("number-{}", value.total);
+        let total = if self.messages.contains_key(&key) {
+            self.text(&key)
+        } else {
+            value.total.to_string()
+        };
+        if value.soft {
+            self.message("soft-total", &[("total", &total)])
+        } else {
+            total
+        }
+    }
+
+    /// Returns the count kept by `counter` as the counting overlay shows it, such as "Running count
+    /// +4, true count +1.6, 2.5 decks left", with the decks left as the counter estimates them and
+    /// the Aces left after it if the counter side counts them.
+    pub fn count(&self, counter: &CardCounter) -> String {
+        let mut count = self.message(
+            "count-overlay",
+            &[
+                ("running", &format!("{:+}", counter.running_count())),
+                ("true", &format!("{:+.1}", counter.true_count())),
+                ("decks", &format!("{:.1}", counter.decks_remaining())),
+            ],
+        );
+        if counter.ace_side_count {
+            count.push_str(", ");
+            count.push_str(&self.message(
+                "count-aces",
+                &[
+                    ("aces", &counter.aces_remaining().to_string()),
+                    ("surplus", &format!("{:+.1}", counter.ace_surplus())),
+                ],
+            ));
+        }
+        count
+    }
+
+    /// Returns how a strategy's `action` is shown, such as "Hit".
+    pub fn action(&self, action: Action) -> String {
+        match action {
+            Action::Bet(amount) => self.message("action-bet", &[("amount", &amount)]),
+            Action::Hit => self.text("action-hit"),
+            Action::Stand => self.text("action-stand"),
+            Action::Double => self.text("action-double"),
+            Action::Split => self.text("action-split"),
+            Action::Surrender => self.text("action-surrender"),
+            Action::Switch => self.text("action-switch"),
+            Action::KeepHands => self.text("action-keep-hands"),
+            Action::Insure => self.text("action-insure"),
+            Action::DeclineInsurance => self.text("action-decline-insurance"),
+            Action::Continue => self.text("action-continue"),
+            Action::SitOut(others) => self.message("action-sit-out", &[("others", &others)]),
+        }
+    }
+
+    /// Returns the name of a side bet's winning `combination`, as `SideBet::resolve()` gives it in
+    /// English, such as "Suited 20".
+    pub fn combination(&self, combination: &str) -> String {
+        let key = format!(
+            "combination-{}",
+            combination.to_lowercase().replace(' ', "-")
+        );
+        self.text(&key)
+    }
+
+    /// Returns why a bet was refused, such as "The table minimum is 5 chips."
+    pub fn bet_error(&self, error: &BetError) -> String {
+        let (key, chips) = match *error {
+            BetError::BelowMinimum(chips) => ("bet-below-minimum", chips),
+            BetError::AboveMaximum(chips) => ("bet-above-maximum", chips),
+            BetError::InsufficientChips(chips) => ("bet-insufficient-chips", chips),
+        };
+        self.message(key, &[("chips", &chips)])
+    }
+
+    /// Returns what went wrong in `error`, as the game reports it. The errors it wraps from outside
+    /// the game, such as a file that could not be read, are given as they describe themselves.
+    pub fn error(&self, error: &BlackjackError) -> String {
+        match error {
+            #[cfg(feature = "std")]
+            BlackjackError::Io(error) => self.message("error-io", &[("error", error)]),
+            BlackjackError::InvalidCard(text) => {
+                self.message("error-invalid-card", &[("text", text)])
+            }
+            BlackjackError::InvalidAction(text) => {
+                self.message("error-invalid-action", &[("text", text)])
+            }
+            BlackjackError::InputExhausted => self.text("error-input-exhausted"),
+            BlackjackError::TooManySeats(others) => self.message(
+                "error-too-many-seats",
+                &[("max", &(MAX_SEATS - 1)), ("others", others)],
+            ),
+            BlackjackError::EmptyDeck => self.text("error-empty-deck"),
+            BlackjackError::Bet(error) => self.bet_error(error),
+            #[cfg(feature = "std")]
+            BlackjackError::Build(error) => error.to_string(),
+            BlackjackError::Rules(error) => self.message("error-rules", &[("error", error)]),
+            BlackjackError::Snapshot(error) => self.message("error-snapshot", &[("error", error)]),
+            BlackjackError::UnsupportedVersion { found, supported } => self.message(
+                "error-unsupported-version",
+                &[("found", found), ("supported", supported)],
+            ),
+            BlackjackError::UnknownVariant(name) => {
+                self.message("error-unknown-variant", &[("name", name)])
+            }
+            BlackjackError::UndoUnavailable => self.text("error-undo-unavailable"),
+            BlackjackError::NothingToUndo => self.text("error-nothing-to-undo"),
+            BlackjackError::EngineStopped => self.text("error-engine-stopped"),
+            BlackjackError::IllegalAction { action, legal } => {
+                let legal = match legal.is_empty() {
+                    true => self.text("error-no-actions"),
+                    false => legal
+                        .iter()
+                        .map(|&action| self.action(action))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                };
+                self.message(
+                    "error-illegal-action",
+                    &[("action", &self.action(*action)), ("legal", &legal)],
+                )
+            }
+        }
+    }
+
+    /// Returns how a hand's `outcome` is put in a word, as the hand history and the window show it,
+    /// such as "won".
+    pub fn outcome(&self, outcome: &Outcome) -> String {
+        self.text(match outcome {
+            Outcome::Win(_) => "outcome-won",
+            Outcome::Push => "outcome-push",
+            Outcome::Lose => "outcome-lost",
+            Outcome::Surrender => "outcome-surrendered",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a locale holding only the text of the locale file `text`.
+    fn read(text: &str) -> Locale {
+        let mut locale = Locale {
+            name: "test".to_string(),
+            messages: BTreeMap::new(),
+        };
+        locale.read(text);
+        locale
+    }
+
+    #[test]
+    fn lines_are_read_as_keys_and_text() {
+        let locale =
+            read("## Section\n# a comment\n\n  greeting =  Hello, { $name }!  \nsum = 1 + 1 = 2");
+        assert_eq!(locale.text("greeting"), "Hello, { $name }!");
+        assert_eq!(locale.text("sum"), "1 + 1 = 2");
+        assert_eq!(locale.messages.len(), 2);
+    }
+
+    #[test]
+    fn placeholders_are_replaced_by_name() {
+        let locale = read("pair = { $first } and { $second }, { $first } again");
+        assert_eq!(
+            locale.message("pair", &[("second", &2), ("first", &"one")]),
+            "one and 2, one again"
+        );
+        assert_eq!(
+            locale.message("pair", &[("first", &1)]),
+            "1 and { $second }, 1 again"
+        );
+    }
+
+    #[test]
+    fn missing_keys_are_shown_as_they_are() {
+        assert_eq!(read("").text("no-such-key"), "no-such-key");
+        assert_eq!(Locale::default().text("no-such-key"), "no-such-key");
+    }
+
+    #[test]
+    fn a_locale_read_over_english_keeps_the_keys_it_does_not_give() {
+        // As `named()` reads every other locale
+        let mut locale = Locale::default();
+        locale.read("action-hit = Pedir");
+        assert_eq!(locale.text("action-hit"), "Pedir");
+        assert_eq!(locale.text("action-stand"), "Stand");
+    }
+
+    #[test]
+    fn every_built_in_locale_gives_every_english_key() {
+        let english = read(ENGLISH);
+        for (name, text) in LOCALES {
+            let locale = read(text);
+            for key in english.messages.keys() {
+                assert!(locale.messages.contains_key(key), "{} has no {}", name, key);
+            }
+        }
+    }
+
+    #[test]
+    fn english_errors_read_as_the_errors_describe_themselves() {
+        let locale = Locale::default();
+        for error in [
+            BlackjackError::EmptyDeck,
+            BlackjackError::UnknownVariant("pontoon".to_string()),
+            BlackjackError::TooManySeats(9),
+            BlackjackError::Bet(BetError::BelowMinimum(5)),
+        ] {
+            assert_eq!(locale.error(&error), error.to_string());
+        }
+        assert_eq!(
+            locale.bet_error(&BetError::InsufficientChips(40)),
+            "You only have 40 chips to cover that bet."
+        );
+    }
+
+    #[test]
+    fn errors_and_combinations_are_given_in_the_locale() {
+        let spanish = Locale::named("es").unwrap();
+        assert_eq!(
+            spanish.error(&BlackjackError::UnknownVariant("pontoon".to_string())),
+            "no hay ninguna variante llamada 'pontoon'"
+        );
+        assert_eq!(
+            spanish.bet_error(&BetError::AboveMaximum(500)),
+            "El máximo de la mesa es 500 fichas."
+        );
+        assert_eq!(spanish.combination("Suited 20"), "20 del mismo palo");
+        assert_eq!(Locale::default().combination("Royal Match"), "Royal Match");
+    }
+
+    #[test]
+    fn only_built_in_locales_are_named() {
+        for name in Locale::NAMES {
+            assert_eq!(Locale::named(name).unwrap().name, name);
+        }
+        assert_eq!(Locale::named("fr"), None);
+    }
+}
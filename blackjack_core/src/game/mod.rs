@@ -0,0 +1,94 @@
+//! The game engine: wagers, bankrolls, variants, side bets and the controllers that play rounds.
+//!
+//! The `Engine` plays rounds headlessly, one `Action` at a time, while the `GameController` and
+//! `TournamentController` drive a game interactively, reading the player's choices from the keyboard
+//! or from a `ScriptedInput`. The controllers and everything else that reads input or writes output
+//! need the `std` feature.
+
+mod advisor;
+#[cfg(feature = "async")]
+mod async_engine;
+mod bankroll;
+mod bots;
+#[cfg(feature = "std")]
+mod builder;
+mod chatter;
+mod chips;
+#[cfg(feature = "std")]
+mod controller;
+mod counting;
+mod dealer;
+mod deviations;
+mod engine;
+mod event;
+#[cfg(feature = "std")]
+mod input;
+mod keys;
+mod limits;
+mod locale;
+mod odds;
+mod payout;
+mod progression;
+mod replay;
+mod rules;
+mod side_bet;
+mod snapshot;
+mod solver;
+mod stats;
+mod strategy;
+mod table;
+#[cfg(feature = "std")]
+mod table_view;
+mod theme;
+#[cfg(feature = "std")]
+mod tournament;
+mod variant;
+mod wager;
+
+pub use advisor::{BetAdvisor, BetPlan, BetRamp, KellyAdvisor, RampError, Wonging, MAX_RAMP_COUNT};
+#[cfg(feature = "async")]
+pub use async_engine::AsyncEngine;
+pub use bankroll::{Bankroll, STARTING_BANKROLL};
+pub use bots::{BasicStrategy, CountingBot, DealerMimic};
+#[cfg(feature = "std")]
+pub use builder::{BuildError, GameBuilder};
+pub use chatter::{Chatter, ChatterError, CHATTER_SITUATIONS};
+pub use chips::{ChipView, CurrencyFormat};
+#[cfg(feature = "std")]
+pub use controller::GameController;
+pub use counting::{CardCounter, CountingSystem, DeckEstimation};
+pub use dealer::{DealerPolicy, HitsSoft17, StandsOn, StandsSoft17};
+pub use deviations::{index_play_for, IndexHand, IndexPlay, FAB_4, ILLUSTRIOUS_18};
+pub use engine::{Action, Engine, GameState};
+pub use event::{GameEvent, GameObserver, Recipient};
+#[cfg(feature = "std")]
+pub use input::{LineInput, ScriptedInput, StdinInput};
+pub use keys::{KeyBindings, KeyError, KEY_NAMES};
+pub use limits::{BetError, TableLimits};
+pub use locale::Locale;
+pub use odds::Odds;
+pub use payout::{settle_hands, Settlement, INSURANCE_PAYS};
+pub use progression::{BettingProgression, Martingale, OneThreeTwoSix, Paroli};
+pub use replay::{Replay, REPLAY_VERSION};
+pub use rules::{RuleError, RuleSet, MAX_DECKS};
+pub use side_bet::{LuckyLadies, LuckyLadiesPaytable, RoyalMatch, RoyalMatchPaytable, SideBet};
+pub use snapshot::{Snapshot, SNAPSHOT_VERSION};
+pub use solver::{ActionValues, StrategySolver};
+pub use stats::{hand_kind, up_card_index, Accuracy, SessionStats, HAND_KINDS, UP_CARD_NAMES};
+#[cfg(feature = "std")]
+pub use strategy::PromptStrategy;
+pub use strategy::{GameView, PlayerStrategy};
+pub use table::{Seat, Table, MAX_SEATS};
+#[cfg(feature = "std")]
+pub use table_view::{
+    sparkline, LayoutTableView, SpokenTableView, StatusTableView, TableFrame, TableView,
+    TextTableView,
+};
+pub use theme::{Border, CardBack, SuitSymbols, Theme};
+#[cfg(feature = "std")]
+pub use tournament::{TournamentController, OPPONENT_NAMES};
+pub use variant::{
+    push_22_resolve, standard_resolve, variant_named, BlackjackSwitch, Classic, DoubleExposure,
+    FreeBet, Outcome, Payout, SuperFun21, Variant,
+};
+pub use wager::{PlayerHand, Wager};
@@ -0,0 +1,125 @@
+use alloc::collections::BTreeMap;
+
+use super::DealerPolicy;
+use crate::card::{Card, Rank, Suit};
+use crate::hand::Hand;
+use crate::prelude::*;
+
+/// The chances a player weighs at a decision: of busting if they hit, and of each total the dealer
+/// can finish on from the up card.
+///
+/// The chances are worked out from the cards the player has not seen, those left in the shoe and
+/// the dealer's hole card, as if every card drawn were taken from them at random and put back. The
+/// cards a hand or two take make little difference to a shoe of any size, and leaving them out
+/// keeps the sums quick enough to redo at every decision. When the dealer has peeked, the dealer is
+/// known not to hold a natural, and the totals are worked out without one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Odds {
+    /// The chance, from 0 to 1, that one more card busts the hand.
+    pub bust_if_hit: f64,
+    /// The chance of each total the dealer can finish on, the likeliest first, with a bust as `None`.
+    pub dealer_totals: Vec<(Option<u32>, f64)>,
+}
+
+impl Odds {
+    /// Works out the odds for `hand` against the dealer's `up_card`, drawing from the `unseen`
+    /// cards, or from a full deck if there are none, with the dealer drawing by `dealer`. `peeked`
+    /// is true if the dealer has already looked for a natural and found none.
+    pub fn new(
+        hand: &Hand,
+        up_card: Card,
+        unseen: &[Card],
+        dealer: &dyn DealerPolicy,
+        peeked: bool,
+    ) -> Odds {
+        let draws = chances(unseen);
+        let bust_if_hit = draws
+            .iter()
+            .filter(|(rank, _)| {
+                let mut next = hand.clone();
+                next.add(card(*rank));
+                next.is_bust()
+            })
+            .map(|(_, chance)| chance)
+            .fold(0.0, |sum, chance| sum + chance);
+
+        let mut totals = BTreeMap::new();
+        let mut start = Hand::new();
+        start.add(up_card);
+        for &(rank, chance) in &draws {
+            let mut next = start.clone();
+            next.add(card(rank));
+            if peeked && next.is_blackjack() {
+                continue;
+            }
+            finish(next, chance, &draws, dealer, &mut totals);
+        }
+        // Leaving out the naturals leaves chances that no longer add up to one
+        let known: f64 = totals.values().sum();
+        let mut dealer_totals: Vec<(Option<u32>, f64)> = totals
+            .into_iter()
+            .map(|(total, chance)| (total, chance / known))
+            .collect();
+        dealer_totals.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        Odds {
+            bust_if_hit,
+            dealer_totals,
+        }
+    }
+}
+
+/// Returns the chance of drawing each rank from `unseen`, or from a full deck if it is empty. Tens
+/// and faces are all drawn as tens, as they count the same.
+fn chances(unseen: &[Card]) -> Vec<(Rank, f64)> {
+    let value = |rank: Rank| match rank {
+        Rank::Jack | Rank::Queen | Rank::King => Rank::Ten,
+        rank => rank,
+    };
+    let mut counts: BTreeMap<u32, (Rank, u32)> = BTreeMap::new();
+    let ranks: Vec<Rank> = match unseen {
+        [] => Rank::ALL.to_vec(),
+        cards => cards.iter().map(|card| card.rank).collect(),
+    };
+    for rank in &ranks {
+        let rank = value(*rank);
+        counts.entry(rank.value()).or_insert((rank, 0)).1 += 1;
+    }
+    counts
+        .into_values()
+        .map(|(rank, count)| (rank, count as f64 / ranks.len() as f64))
+        .collect()
+}
+
+/// Returns a card of `rank`, whose suit makes no difference to a hand's total.
+fn card(rank: Rank) -> Card {
+    Card {
+        rank,
+        suit: Suit::Spades,
+    }
+}
+
+/// Has the dealer draw to `hand`, reached with `chance`, and adds the chance of each total it
+/// finishes on to `totals`.
+fn finish(
+    hand: Hand,
+    chance: f64,
+    draws: &[(Rank, f64)],
+    dealer: &dyn DealerPolicy,
+    totals: &mut BTreeMap<Option<u32>, f64>,
+) {
+    if hand.is_bust() {
+        *totals.entry(None).or_insert(0.0) += chance;
+        return;
+    }
+    if !dealer.hits(&hand) {
+        *totals
+            .entry(Some(hand.calculate_hand_total()))
+            .or_insert(0.0) += chance;
+        return;
+    }
+    for &(rank, draw) in draws {
+        let mut next = hand.clone();
+        next.add(card(rank));
+        finish(next, chance * draw, draws, dealer, totals);
+    }
+}
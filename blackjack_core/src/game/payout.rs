@@ -0,0 +1,210 @@
+use core::ops::AddAssign;
+
+use super::{Outcome, Payout, PlayerHand, RuleSet, Variant, Wager};
+use crate::hand::Hand;
+use crate::prelude::*;
+
+/// What insurance pays when the dealer has a natural.
+pub const INSURANCE_PAYS: Payout = Payout::TWO_TO_ONE;
+
+/// The chips put up on one or more wagers and the chips handed back once they are settled.
+///
+/// Every wager at the table, whether a main bet with its doubles and splits, insurance or a side
+/// bet, is settled into a `Settlement`, so the arithmetic lives in one place and the controllers only
+/// add the results up and pay them into the bankroll. Chips covered by the house for free doubles
+/// and splits are not counted as staked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Settlement {
+    pub staked: u32,
+    pub returned: u32,
+}
+
+impl Settlement {
+    /// Settles a main bet, including any doubles, with the given outcome.
+    ///
+    /// A win returns the stake plus winnings on the full amount in action, a push returns
+    /// only the stake, a surrender returns half the stake, rounded down, and a loss returns nothing.
+    /// The free portion is never returned itself.
+    pub fn main_bet(wager: &Wager, outcome: Outcome) -> Self {
+        let returned = match outcome {
+            Outcome::Win(payout) => wager.stake.saturating_add(payout.winnings(wager.total())),
+            Outcome::Push => wager.stake,
+            Outcome::Surrender => wager.stake / 2,
+            Outcome::Lose => 0,
+        };
+        Settlement {
+            staked: wager.stake,
+            returned,
+        }
+    }
+
+    /// Settles an insurance bet of `wager` chips, which pays 2:1 if the dealer has a natural.
+    pub fn insurance(wager: u32, dealer_blackjack: bool) -> Self {
+        Settlement {
+            staked: wager,
+            returned: if dealer_blackjack {
+                wager.saturating_add(INSURANCE_PAYS.winnings(wager))
+            } else {
+                0
+            },
+        }
+    }
+
+    /// Settles a side bet of `wager` chips, which pays at the given ratio if it won.
+    pub fn side_bet(wager: u32, result: Option<Payout>) -> Self {
+        Settlement {
+            staked: wager,
+            returned: result.map_or(0, |payout| wager.saturating_add(payout.winnings(wager))),
+        }
+    }
+
+    /// Returns the chips won, or lost if negative.
+    pub fn net(&self) -> i64 {
+        self.returned as i64 - self.staked as i64
+    }
+}
+
+impl AddAssign for Settlement {
+    fn add_assign(&mut self, other: Settlement) {
+        self.staked = self.staked.saturating_add(other.staked);
+        self.returned = self.returned.saturating_add(other.returned);
+    }
+}
+
+/// Decides the outcome of every player hand against the dealer under the table's rules, and settles
+/// the wager riding on each. Split hands are settled one by one, and a surrendered hand is settled as
+/// a surrender.
+pub fn settle_hands(
+    rules: &RuleSet,
+    variant: &dyn Variant,
+    player_hands: &[PlayerHand],
+    dealer: &Hand,
+) -> Vec<(Outcome, Settlement)> {
+    player_hands
+        .iter()
+        .map(|player_hand| {
+            let outcome = if player_hand.surrendered {
+                Outcome::Surrender
+            } else {
+                rules.resolve(variant, &player_hand.hand, dealer)
+            };
+            (outcome, Settlement::main_bet(&player_hand.wager, outcome))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Card, Rank, Suit};
+    use crate::game::Classic;
+
+    /// Returns a hand holding cards of the given ranks, all in Spades.
+    fn hand(ranks: &[Rank]) -> Hand {
+        let mut hand = Hand::new();
+        for &rank in ranks {
+            hand.add(Card {
+                rank,
+                suit: Suit::Spades,
+            });
+        }
+        hand
+    }
+
+    /// Returns a player hand of the given ranks with a wager of `bet` chips.
+    fn player_hand(ranks: &[Rank], bet: u32) -> PlayerHand {
+        let mut player_hand = PlayerHand::new(1, bet);
+        player_hand.hand = hand(ranks);
+        player_hand
+    }
+
+    #[test]
+    fn a_free_double_is_paid_when_it_wins_but_costs_nothing_when_it_loses() {
+        let mut wager = Wager::new(10);
+        wager.double(true);
+
+        let settle = |outcome| Settlement::main_bet(&wager, outcome);
+        assert_eq!(settle(Outcome::Win(Payout::EVEN_MONEY)).returned, 30);
+        assert_eq!(settle(Outcome::Push).returned, 10);
+        assert_eq!(settle(Outcome::Lose).returned, 0);
+        assert_eq!(settle(Outcome::Lose).staked, 10);
+    }
+
+    #[test]
+    fn insurance_pays_two_to_one_only_against_a_natural() {
+        assert_eq!(Settlement::insurance(5, true).net(), 10);
+        assert_eq!(Settlement::insurance(5, false).net(), -5);
+    }
+
+    #[test]
+    fn settlements_add_up_without_overflowing() {
+        let mut round = Settlement::side_bet(10, Some(Payout(25, 1)));
+        assert_eq!(round.net(), 250);
+        round += Settlement {
+            staked: u32::MAX,
+            returned: u32::MAX,
+        };
+        assert_eq!(round.staked, u32::MAX);
+        assert_eq!(round.returned, u32::MAX);
+    }
+
+    #[test]
+    fn split_hands_are_settled_one_by_one() {
+        let mut first = player_hand(&[Rank::Eight, Rank::Eight], 10);
+        let mut second = first.split(2, Wager::new(10));
+        for rank in [Rank::Ten, Rank::Two] {
+            first.hand.add(Card {
+                rank,
+                suit: Suit::Hearts,
+            });
+        }
+        second.hand.add(Card {
+            rank: Rank::Ten,
+            suit: Suit::Hearts,
+        });
+        second.wager.double(false);
+        let dealer = hand(&[Rank::Ten, Rank::Nine]);
+
+        let settled = settle_hands(&RuleSet::default(), &Classic, &[first, second], &dealer);
+        assert_eq!(
+            settled,
+            [
+                (
+                    Outcome::Win(Payout::EVEN_MONEY),
+                    Settlement {
+                        staked: 10,
+                        returned: 20
+                    }
+                ),
+                (
+                    Outcome::Lose,
+                    Settlement {
+                        staked: 20,
+                        returned: 0
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn a_natural_is_paid_at_the_table_rate() {
+        let natural = player_hand(&[Rank::Ace, Rank::King], 10);
+        let dealer = hand(&[Rank::Ten, Rank::Nine]);
+
+        let settled = settle_hands(&RuleSet::default(), &Classic, &[natural], &dealer);
+        assert_eq!(settled[0].0, Outcome::Win(Payout::THREE_TO_TWO));
+        assert_eq!(settled[0].1.returned, 25);
+    }
+
+    #[test]
+    fn a_surrendered_hand_gets_half_its_stake_back_whatever_the_dealer_has() {
+        let mut surrendered = player_hand(&[Rank::Ten, Rank::Six], 10);
+        surrendered.surrendered = true;
+        let dealer = hand(&[Rank::Ten, Rank::Six, Rank::Nine]);
+
+        let settled = settle_hands(&RuleSet::default(), &Classic, &[surrendered], &dealer);
+        assert_eq!(settled[0].0, Outcome::Surrender);
+        assert_eq!(settled[0].1.net(), -5);
+    }
+}
@@ -0,0 +1,107 @@
+/// The `BettingProgression` trait defines a system that chooses the next bet from previous results.
+///
+/// The `BettingProgression` trait provides the following methods:
+/// - `name(&self) -> &'static str`: Returns the display name of the system.
+/// - `next_bet(&self) -> u32`: Returns the bet the system wants to place on the next round.
+/// - `record(&mut self, net: i64)`: Updates the system with the net result of a round's main bets.
+///
+/// Progressions are measured in multiples of a base unit, normally the table minimum.
+pub trait BettingProgression {
+    fn name(&self) -> &'static str;
+    fn next_bet(&self) -> u32;
+    fn record(&mut self, net: i64);
+}
+
+/// The Martingale system, which doubles the bet after every loss and returns to one unit after a win.
+pub struct Martingale {
+    pub unit: u32,
+    pub bet: u32,
+}
+
+impl Martingale {
+    pub fn new(unit: u32) -> Self {
+        Martingale { unit, bet: unit }
+    }
+}
+
+impl BettingProgression for Martingale {
+    fn name(&self) -> &'static str {
+        "Martingale"
+    }
+
+    fn next_bet(&self) -> u32 {
+        self.bet
+    }
+
+    fn record(&mut self, net: i64) {
+        if net < 0 {
+            self.bet = self.bet.saturating_mul(2);
+        } else if net > 0 {
+            self.bet = self.unit;
+        }
+    }
+}
+
+/// The Paroli system, which doubles the bet after every win and returns to one unit after a loss
+/// or after three wins in a row.
+pub struct Paroli {
+    pub unit: u32,
+    pub streak: u32,
+}
+
+impl Paroli {
+    pub fn new(unit: u32) -> Self {
+        Paroli { unit, streak: 0 }
+    }
+}
+
+impl BettingProgression for Paroli {
+    fn name(&self) -> &'static str {
+        "Paroli"
+    }
+
+    fn next_bet(&self) -> u32 {
+        self.unit << self.streak
+    }
+
+    fn record(&mut self, net: i64) {
+        if net > 0 {
+            self.streak = (self.streak + 1) % 3;
+        } else if net < 0 {
+            self.streak = 0;
+        }
+    }
+}
+
+/// The 1-3-2-6 system, which bets 1, 3, 2 and then 6 units through a winning streak,
+/// returning to the start after a loss or after the fourth win.
+pub struct OneThreeTwoSix {
+    pub unit: u32,
+    pub step: usize,
+}
+
+impl OneThreeTwoSix {
+    pub const UNITS: [u32; 4] = [1, 3, 2, 6];
+
+    pub fn new(unit: u32) -> Self {
+        OneThreeTwoSix { unit, step: 0 }
+    }
+}
+
+impl BettingProgression for OneThreeTwoSix {
+    fn name(&self) -> &'static str {
+        "1-3-2-6"
+    }
+
+    fn next_bet(&self) -> u32 {
+        self.unit * OneThreeTwoSix::UNITS[self.step]
+    }
+
+    fn record(&mut self, net: i64) {
+        if net > 0 {
+            self.step = (self.step + 1) % OneThreeTwoSix::UNITS.len();
+        } else if net < 0 {
+            self.step = 0;
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+
+use super::{variant_named, Action, Bankroll, Engine, RuleSet, TableLimits, Variant};
+use crate::entropy;
+use crate::error::BlackjackError;
+use crate::prelude::*;
+
+/// The version of the replay format written by `to_json()`.
+pub const REPLAY_VERSION: u32 = 1;
+
+/// A record of a game that the `Engine` can play again exactly: the seed it was dealt from and every
+/// action taken, in order.
+///
+/// An engine starts recording a replay when it is seeded, and `Engine::replay()` returns the record
+/// so far. Because the same seed deals the same cards, stepping a freshly seeded engine through the
+/// same actions reproduces the game card for card, which makes a replay enough to report a bug or
+/// to watch a game back. Actions are written as short codes, such as "b25 h s c", to keep the file small.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replay {
+    pub variant: String,
+    pub rules: RuleSet,
+    pub limits: TableLimits,
+    pub bankroll: u32,
+    pub seed: u64,
+    pub actions: Vec<Action>,
+}
+
+/// A replay as it is written, with the version of the format and the actions as codes.
+#[derive(Serialize, Deserialize)]
+struct ReplayFile {
+    version: u32,
+    variant: String,
+    rules: RuleSet,
+    limits: TableLimits,
+    bankroll: u32,
+    seed: u64,
+    actions: String,
+}
+
+impl Replay {
+    /// Starts an empty replay of a game dealt from `seed` with the given settings.
+    pub fn new(
+        variant: &str,
+        rules: RuleSet,
+        limits: TableLimits,
+        bankroll: u32,
+        seed: u64,
+    ) -> Self {
+        Replay {
+            variant: variant.to_string(),
+            rules,
+            limits,
+            bankroll,
+            seed,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Returns an engine playing `variant`, seeded and set up as the recorded game was, before any
+    /// action is taken. A replay viewer steps it through `actions` one at a time.
+    pub fn engine(&self, variant: Box<dyn Variant>) -> Engine {
+        let mut engine = Engine::with_entropy(
+            variant,
+            self.rules,
+            self.limits,
+            Bankroll::new(self.bankroll),
+            entropy::seeded(self.seed),
+        );
+        engine.seed(self.seed);
+        engine
+    }
+
+    /// Returns the variant the game was recorded at, or an `UnknownVariant` error if no built-in
+    /// variant has its name.
+    pub fn variant(&self) -> Result<Box<dyn Variant>, BlackjackError> {
+        variant_named(&self.variant)
+            .ok_or_else(|| BlackjackError::UnknownVariant(self.variant.clone()))
+    }
+
+    /// Plays every recorded action on a fresh engine playing `variant`, and returns the engine as
+    /// the recorded game left it.
+    pub fn play(&self, variant: Box<dyn Variant>) -> Result<Engine, BlackjackError> {
+        let mut engine = self.engine(variant);
+        for &action in &self.actions {
+            engine.step(action)?;
+        }
+        Ok(engine)
+    }
+
+    /// Writes the replay as JSON.
+    pub fn to_json(&self) -> Result<String, BlackjackError> {
+        let file = ReplayFile {
+            version: REPLAY_VERSION,
+            variant: self.variant.clone(),
+            rules: self.rules,
+            limits: self.limits,
+            bankroll: self.bankroll,
+            seed: self.seed,
+            actions: self
+                .actions
+                .iter()
+                .map(Action::code)
+                .collect::<Vec<String>>()
+                .join(" "),
+        };
+        Ok(serde_json::to_string_pretty(&file)?)
+    }
+
+    /// Reads a replay written by `to_json()`.
+    pub fn from_json(json: &str) -> Result<Self, BlackjackError> {
+        let file: ReplayFile = serde_json::from_str(json)?;
+        if file.version > REPLAY_VERSION {
+            return Err(BlackjackError::UnsupportedVersion {
+                found: file.version,
+                supported: REPLAY_VERSION,
+            });
+        }
+        Ok(Replay {
+            variant: file.variant,
+            rules: file.rules,
+            limits: file.limits,
+            bankroll: file.bankroll,
+            seed: file.seed,
+            actions: file
+                .actions
+                .split_whitespace()
+                .map(Action::from_code)
+                .collect::<Result<Vec<Action>, BlackjackError>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Classic, GameState};
+
+    /// Plays `rounds` rounds of a game dealt from `seed`, betting 10 chips and standing on every
+    /// hand, and returns the engine.
+    fn played(seed: u64, rounds: usize) -> Engine {
+        let mut engine = Engine::with_entropy(
+            Box::new(Classic),
+            RuleSet::CLASSIC,
+            TableLimits::TABLES[0],
+            Bankroll::new(1000),
+            entropy::seeded(seed),
+        );
+        engine.seed(seed);
+        for _ in 0..rounds {
+            let mut state = engine.step(Action::Bet(10)).unwrap();
+            while !matches!(state, GameState::Resolved { .. }) {
+                let legal = engine.legal_actions();
+                let action = [
+                    Action::Stand,
+                    Action::DeclineInsurance,
+                    Action::KeepHands,
+                    Action::Continue,
+                ]
+                .into_iter()
+                .find(|action| legal.contains(action))
+                .unwrap();
+                state = engine.step(action).unwrap();
+            }
+        }
+        engine
+    }
+
+    #[test]
+    fn a_replay_is_read_back_from_its_json() {
+        let engine = played(3, 5);
+        let replay = engine.replay().unwrap();
+        assert_eq!(
+            &Replay::from_json(&replay.to_json().unwrap()).unwrap(),
+            replay
+        );
+    }
+
+    #[test]
+    fn a_replay_plays_the_game_again_card_for_card() {
+        let engine = played(11, 20);
+        let replay = engine.replay().unwrap();
+        let again = replay.play(replay.variant().unwrap()).unwrap();
+        assert_eq!(again.snapshot(), engine.snapshot());
+    }
+
+    #[test]
+    fn replays_written_by_a_newer_version_are_refused() {
+        let json = played(3, 1).replay().unwrap().to_json().unwrap().replacen(
+            &format!("\"version\": {}", REPLAY_VERSION),
+            &format!("\"version\": {}", REPLAY_VERSION + 1),
+            1,
+        );
+        assert!(matches!(
+            Replay::from_json(&json),
+            Err(BlackjackError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_variants_are_refused() {
+        let mut replay = played(3, 0).replay().unwrap().clone();
+        replay.variant = "Poker".to_string();
+        assert!(matches!(
+            replay.variant(),
+            Err(BlackjackError::UnknownVariant(_))
+        ));
+    }
+}
@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+
+use super::{DealerPolicy, HitsSoft17, Outcome, Payout, StandsSoft17, Variant};
+use crate::hand::Hand;
+use crate::prelude::*;
+use crate::shoe::DEFAULT_PENETRATION;
+
+/// The most decks a shoe holds, as in the largest shoes dealt in casinos.
+pub const MAX_DECKS: usize = 8;
+
+/// A rule, or a combination of rules, that cannot be played, found by `RuleSet::validate()`.
+///
+/// Each message starts with the name of the setting at fault and says why it is wrong, so it can
+/// be shown as it is to whoever wrote the rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum RuleError {
+    /// The shoe has no decks.
+    #[error("decks: the shoe needs at least one deck")]
+    NoDecks,
+    /// The shoe has more than `MAX_DECKS` decks; holds the decks asked for.
+    #[error("decks: a shoe holds at most {max} decks, not {0}", max = MAX_DECKS)]
+    TooManyDecks(usize),
+    /// The player is not allowed even one hand.
+    #[error("max_hands: the player must be allowed at least one hand")]
+    NoHands,
+    /// Doubling after a split is allowed, but pairs can never be split.
+    #[error(
+        "double_after_split: doubling after a split is allowed, but with max_hands at 1 no hand can be split"
+    )]
+    DoubleAfterSplitWithoutSplits,
+    /// A natural pays a ratio with nothing to divide by.
+    #[error("blackjack_pays: {0} cannot be paid, as the second number must not be 0")]
+    ZeroDivisor(Payout),
+    /// A natural pays less than an ordinary win.
+    #[error("blackjack_pays: a natural must pay at least even money, not {0}")]
+    BelowEvenMoney(Payout),
+    /// The charlie takes so few cards that every hand would win as soon as it is dealt; holds the
+    /// cards asked for.
+    #[error("charlie: a charlie needs at least 3 cards, as every hand is dealt {0} or more")]
+    CharlieTooShort(usize),
+    /// The cut card is not placed within the shoe.
+    #[error("penetration: the cut card must be placed between 0 and 1 of the way into the shoe")]
+    PenetrationOutOfRange,
+}
+
+/// The table rules that can be set independently of the variant being played.
+///
+/// The `RuleSet` collects the rule options a casino posts on the table: how many decks are in the
+/// shoe, whether the dealer hits a soft 17, whether the player may double after splitting or
+/// surrender, what a natural pays, whether the dealer peeks for blackjack, how many hands a pair may
+/// be split into, whether a hand of enough cards without busting wins outright (a "charlie"), and
+/// the share of the shoe dealt before the cut card comes out (the "penetration").
+/// The game asks the `RuleSet` rather than assuming any of these rules.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuleSet {
+    pub decks: usize,
+    pub dealer_hits_soft_17: bool,
+    pub double_after_split: bool,
+    pub surrender: bool,
+    pub blackjack_pays: Payout,
+    pub dealer_peeks: bool,
+    pub max_hands: usize,
+    pub charlie: Option<usize>,
+    pub penetration: f64,
+}
+
+impl RuleSet {
+    /// Named sets of rules as posted in well-known casinos, starting with the game's own default
+    /// rules as "classic".
+    pub const PRESETS: [(&'static str, RuleSet); 5] = [
+        ("classic", RuleSet::CLASSIC),
+        (
+            "vegas-strip",
+            RuleSet {
+                decks: 4,
+                dealer_hits_soft_17: false,
+                double_after_split: true,
+                surrender: true,
+                blackjack_pays: Payout::THREE_TO_TWO,
+                dealer_peeks: true,
+                max_hands: 4,
+                charlie: None,
+                penetration: DEFAULT_PENETRATION,
+            },
+        ),
+        (
+            "downtown",
+            RuleSet {
+                decks: 2,
+                dealer_hits_soft_17: true,
+                double_after_split: true,
+                surrender: false,
+                blackjack_pays: Payout::THREE_TO_TWO,
+                dealer_peeks: true,
+                max_hands: 4,
+                charlie: None,
+                penetration: DEFAULT_PENETRATION,
+            },
+        ),
+        (
+            "atlantic-city",
+            RuleSet {
+                decks: 8,
+                dealer_hits_soft_17: false,
+                double_after_split: true,
+                surrender: true,
+                blackjack_pays: Payout::THREE_TO_TWO,
+                dealer_peeks: true,
+                max_hands: 4,
+                charlie: None,
+                penetration: DEFAULT_PENETRATION,
+            },
+        ),
+        (
+            "european",
+            RuleSet {
+                decks: 6,
+                dealer_hits_soft_17: false,
+                double_after_split: false,
+                surrender: false,
+                blackjack_pays: Payout::THREE_TO_TWO,
+                dealer_peeks: false,
+                max_hands: 3,
+                charlie: None,
+                penetration: DEFAULT_PENETRATION,
+            },
+        ),
+    ];
+
+    /// The rules the game has always been played with: a single deck, the dealer stands on all 17s
+    /// without peeking, doubling after a split is allowed, naturals pay 3:2, a pair may be split into
+    /// up to four hands, there is no surrender or charlie, and three quarters of the shoe are dealt.
+    pub const CLASSIC: RuleSet = RuleSet {
+        decks: 1,
+        dealer_hits_soft_17: false,
+        double_after_split: true,
+        surrender: false,
+        blackjack_pays: Payout::THREE_TO_TWO,
+        dealer_peeks: false,
+        max_hands: 4,
+        charlie: None,
+        penetration: DEFAULT_PENETRATION,
+    };
+
+    /// Returns the preset rules with the given name, if there is one.
+    pub fn preset(name: &str) -> Option<RuleSet> {
+        RuleSet::PRESETS
+            .iter()
+            .find(|(preset, _)| *preset == name)
+            .map(|&(_, rules)| rules)
+    }
+
+    /// Checks that the rules can be played together, returning the first setting found to be wrong.
+    pub fn validate(&self) -> Result<(), RuleError> {
+        if self.decks == 0 {
+            return Err(RuleError::NoDecks);
+        }
+        if self.decks > MAX_DECKS {
+            return Err(RuleError::TooManyDecks(self.decks));
+        }
+        if self.max_hands == 0 {
+            return Err(RuleError::NoHands);
+        }
+        if self.double_after_split && self.max_hands == 1 {
+            return Err(RuleError::DoubleAfterSplitWithoutSplits);
+        }
+        let Payout(paid, staked) = self.blackjack_pays;
+        if staked == 0 {
+            return Err(RuleError::ZeroDivisor(self.blackjack_pays));
+        }
+        if paid < staked {
+            return Err(RuleError::BelowEvenMoney(self.blackjack_pays));
+        }
+        if let Some(cards) = self.charlie.filter(|&cards| cards < 3) {
+            return Err(RuleError::CharlieTooShort(cards));
+        }
+        if !(0.0..=1.0).contains(&self.penetration) {
+            return Err(RuleError::PenetrationOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Returns the dealer policy these rules post: hitting or standing on a soft 17.
+    pub fn dealer_policy(&self) -> Box<dyn DealerPolicy> {
+        if self.dealer_hits_soft_17 {
+            Box::new(HitsSoft17)
+        } else {
+            Box::new(StandsSoft17)
+        }
+    }
+
+    /// Returns true if the rules allow `hand` to be doubled.
+    pub fn allows_double(&self, hand: &Hand) -> bool {
+        hand.can_double() && (self.double_after_split || !hand.is_split())
+    }
+
+    /// Returns true if the rules allow `hand` to be split while the player holds `hands` hands.
+    pub fn allows_split(&self, hand: &Hand, hands: usize) -> bool {
+        hand.can_split() && hands < self.max_hands
+    }
+
+    /// Returns true if the rules allow `hand` to be surrendered: only the first two cards of a hand
+    /// that did not come from a split.
+    pub fn allows_surrender(&self, hand: &Hand) -> bool {
+        self.surrender && hand.len() == 2 && !hand.is_split()
+    }
+
+    /// Returns true if `hand` has reached the charlie, if one is played: enough cards without busting.
+    pub fn is_charlie(&self, hand: &Hand) -> bool {
+        self.charlie
+            .is_some_and(|cards| hand.len() >= cards && !hand.is_bust())
+    }
+
+    /// Returns true if the dealer peeks at the hole card and finds a natural, ending the round
+    /// before the player acts.
+    pub fn dealer_peeks_blackjack(&self, dealer: &Hand) -> bool {
+        self.dealer_peeks && dealer.is_blackjack()
+    }
+
+    /// Settles a hand that was played out against the dealer, applying the charlie before the
+    /// variant's own rules. A charlie wins even money unless the dealer has a natural.
+    pub fn resolve(&self, variant: &dyn Variant, player: &Hand, dealer: &Hand) -> Outcome {
+        if self.is_charlie(player) && !dealer.is_blackjack() {
+            Outcome::Win(Payout::EVEN_MONEY)
+        } else {
+            variant.resolve(player, dealer, self)
+        }
+    }
+}
+
+/// The default rules are the classic rules the game has always been played with.
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet::CLASSIC
+    }
+}
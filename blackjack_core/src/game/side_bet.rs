@@ -0,0 +1,229 @@
+use super::Payout;
+use crate::card::{Card, Rank, Suit};
+use crate::hand::Hand;
+
+/// The `SideBet` trait defines an optional wager that is settled on the opening cards of a round.
+///
+/// The `SideBet` trait provides the following methods:
+/// - `name(&self) -> &'static str`: Returns the display name of the side bet.
+/// - `resolve(&self, player: &Hand, dealer: &Hand) -> Option<(&'static str, Payout)>`: Returns the winning
+///   combination and its payout, or `None` if the side bet loses. Both hands hold the first two cards dealt.
+///
+/// Each side bet carries its own paytable, so different tables can offer the same bet at different odds.
+pub trait SideBet {
+    fn name(&self) -> &'static str;
+    fn resolve(&self, player: &Hand, dealer: &Hand) -> Option<(&'static str, Payout)>;
+}
+
+/// The odds paid by the Lucky Ladies side bet for each winning combination.
+pub struct LuckyLadiesPaytable {
+    pub queen_of_hearts_pair_with_dealer_blackjack: Payout,
+    pub queen_of_hearts_pair: Payout,
+    pub matched_twenty: Payout,
+    pub suited_twenty: Payout,
+    pub any_twenty: Payout,
+}
+
+impl LuckyLadiesPaytable {
+    pub const CLASSIC: LuckyLadiesPaytable = LuckyLadiesPaytable {
+        queen_of_hearts_pair_with_dealer_blackjack: Payout(1000, 1),
+        queen_of_hearts_pair: Payout(200, 1),
+        matched_twenty: Payout(25, 1),
+        suited_twenty: Payout(10, 1),
+        any_twenty: Payout(4, 1),
+    };
+    pub const REDUCED: LuckyLadiesPaytable = LuckyLadiesPaytable {
+        queen_of_hearts_pair_with_dealer_blackjack: Payout(1000, 1),
+        queen_of_hearts_pair: Payout(125, 1),
+        matched_twenty: Payout(19, 1),
+        suited_twenty: Payout(9, 1),
+        any_twenty: Payout(4, 1),
+    };
+}
+
+/// The Lucky Ladies side bet, which wins whenever the player's first two cards total 20.
+///
+/// Suited and identical twenties pay more, and a pair of Queens of Hearts pays the top prize,
+/// which is boosted further when the dealer also holds a blackjack.
+pub struct LuckyLadies {
+    pub paytable: LuckyLadiesPaytable,
+}
+
+impl SideBet for LuckyLadies {
+    fn name(&self) -> &'static str {
+        "Lucky Ladies"
+    }
+
+    fn resolve(&self, player: &Hand, dealer: &Hand) -> Option<(&'static str, Payout)> {
+        if player.calculate_hand_total() != 20 {
+            return None;
+        }
+
+        let cards = player.cards();
+        let (first, second) = (&cards[0], &cards[1]);
+        let queen_of_hearts = |card: &Card| card.rank == Rank::Queen && card.suit == Suit::Hearts;
+
+        if queen_of_hearts(first) && queen_of_hearts(second) {
+            if dealer.is_blackjack() {
+                Some((
+                    "Queen of Hearts pair with dealer blackjack",
+                    self.paytable.queen_of_hearts_pair_with_dealer_blackjack,
+                ))
+            } else {
+                Some(("Queen of Hearts pair", self.paytable.queen_of_hearts_pair))
+            }
+        } else if first.rank == second.rank && first.suit == second.suit {
+            Some(("Matched 20", self.paytable.matched_twenty))
+        } else if first.suit == second.suit {
+            Some(("Suited 20", self.paytable.suited_twenty))
+        } else {
+            Some(("Any 20", self.paytable.any_twenty))
+        }
+    }
+}
+
+/// The odds paid by the Royal Match side bet for each winning combination.
+pub struct RoyalMatchPaytable {
+    pub royal_match: Payout,
+    pub suited: Payout,
+}
+
+impl RoyalMatchPaytable {
+    pub const CLASSIC: RoyalMatchPaytable = RoyalMatchPaytable {
+        royal_match: Payout(25, 1),
+        suited: Payout(5, 2),
+    };
+    pub const EASY_MATCH: RoyalMatchPaytable = RoyalMatchPaytable {
+        royal_match: Payout(10, 1),
+        suited: Payout(3, 1),
+    };
+}
+
+/// The Royal Match side bet, which wins when the player's first two cards are suited.
+///
+/// A suited King and Queen is a royal match and pays the top prize.
+pub struct RoyalMatch {
+    pub paytable: RoyalMatchPaytable,
+}
+
+impl SideBet for RoyalMatch {
+    fn name(&self) -> &'static str {
+        "Royal Match"
+    }
+
+    fn resolve(&self, player: &Hand, _dealer: &Hand) -> Option<(&'static str, Payout)> {
+        let cards = player.cards();
+        let (first, second) = (&cards[0], &cards[1]);
+
+        if first.suit != second.suit {
+            None
+        } else if first.rank.min(second.rank) == Rank::Queen
+            && first.rank.max(second.rank) == Rank::King
+        {
+            Some(("Royal Match", self.paytable.royal_match))
+        } else {
+            Some(("Suited", self.paytable.suited))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a hand holding cards of the given ranks, all in Spades.
+    fn hand(ranks: &[Rank]) -> Hand {
+        let mut hand = Hand::new();
+        for &rank in ranks {
+            hand.add(Card {
+                rank,
+                suit: Suit::Spades,
+            });
+        }
+        hand
+    }
+
+    /// Returns a hand holding the given cards, as a rank and a suit each.
+    fn suited_hand(cards: &[(Rank, Suit)]) -> Hand {
+        let mut hand = Hand::new();
+        for &(rank, suit) in cards {
+            hand.add(Card { rank, suit });
+        }
+        hand
+    }
+
+    #[test]
+    fn lucky_ladies_pays_each_twenty_by_its_paytable() {
+        let classic = LuckyLadies {
+            paytable: LuckyLadiesPaytable::CLASSIC,
+        };
+        let dealer = hand(&[Rank::Ten, Rank::Seven]);
+        let pays = |player: &[(Rank, Suit)]| classic.resolve(&suited_hand(player), &dealer);
+
+        assert_eq!(
+            pays(&[(Rank::Queen, Suit::Hearts), (Rank::Queen, Suit::Hearts)]),
+            Some(("Queen of Hearts pair", Payout(200, 1)))
+        );
+        assert_eq!(
+            pays(&[(Rank::King, Suit::Spades), (Rank::King, Suit::Spades)]),
+            Some(("Matched 20", Payout(25, 1)))
+        );
+        assert_eq!(
+            pays(&[(Rank::Ten, Suit::Clubs), (Rank::Queen, Suit::Clubs)]),
+            Some(("Suited 20", Payout(10, 1)))
+        );
+        assert_eq!(
+            pays(&[(Rank::Ace, Suit::Clubs), (Rank::Nine, Suit::Hearts)]),
+            Some(("Any 20", Payout(4, 1)))
+        );
+        assert_eq!(
+            pays(&[(Rank::Ten, Suit::Clubs), (Rank::Nine, Suit::Clubs)]),
+            None
+        );
+    }
+
+    #[test]
+    fn lucky_ladies_pays_the_top_prize_for_queens_of_hearts_against_a_dealer_blackjack() {
+        let reduced = LuckyLadies {
+            paytable: LuckyLadiesPaytable::REDUCED,
+        };
+        let queens = suited_hand(&[(Rank::Queen, Suit::Hearts), (Rank::Queen, Suit::Hearts)]);
+
+        assert_eq!(
+            reduced.resolve(&queens, &hand(&[Rank::Ace, Rank::King])),
+            Some((
+                "Queen of Hearts pair with dealer blackjack",
+                Payout(1000, 1)
+            ))
+        );
+        assert_eq!(
+            reduced.resolve(&queens, &hand(&[Rank::Ten, Rank::Seven])),
+            Some(("Queen of Hearts pair", Payout(125, 1)))
+        );
+    }
+
+    #[test]
+    fn royal_match_pays_suited_cards_and_a_suited_king_and_queen() {
+        let dealer = hand(&[Rank::Ten, Rank::Seven]);
+        for (paytable, royal, suited) in [
+            (RoyalMatchPaytable::CLASSIC, Payout(25, 1), Payout(5, 2)),
+            (RoyalMatchPaytable::EASY_MATCH, Payout(10, 1), Payout(3, 1)),
+        ] {
+            let royal_match = RoyalMatch { paytable };
+            let pays = |player: &[(Rank, Suit)]| royal_match.resolve(&suited_hand(player), &dealer);
+
+            assert_eq!(
+                pays(&[(Rank::King, Suit::Hearts), (Rank::Queen, Suit::Hearts)]),
+                Some(("Royal Match", royal))
+            );
+            assert_eq!(
+                pays(&[(Rank::Two, Suit::Clubs), (Rank::Nine, Suit::Clubs)]),
+                Some(("Suited", suited))
+            );
+            assert_eq!(
+                pays(&[(Rank::King, Suit::Hearts), (Rank::Queen, Suit::Spades)]),
+                None
+            );
+        }
+    }
+}
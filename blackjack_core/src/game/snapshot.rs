@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::{Bankroll, GameState, PlayerHand, RuleSet, SessionStats, TableLimits};
+use crate::error::BlackjackError;
+use crate::hand::Hand;
+use crate::prelude::*;
+use crate::shoe::Shoe;
+
+/// The complete state of a game at one moment, which can be saved as JSON and restored later.
+///
+/// A snapshot can be taken at any decision point, including in the middle of a round. It holds the
+/// table rules and limits, the shoe with its cards in dealing order, the player's hands and wagers,
+/// the dealer's hand, the hands of any other players dealt a round the player sat out, the
+/// bankroll, the session statistics and the point the round has reached.
+/// The variant is recorded by name, since the caller supplies the variant itself when restoring.
+/// The random number generator is not saved, so a restored game deals exactly the same cards until
+/// the shoe is next shuffled.
+///
+/// Snapshots are written with the format version they were saved in, and older formats are migrated
+/// when they are read, so saves outlive changes to the game's types. Rules added since a game was
+/// saved take their default values.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub variant: String,
+    pub rules: RuleSet,
+    pub limits: TableLimits,
+    pub shoe: Shoe,
+    pub bankroll: Bankroll,
+    pub stats: SessionStats,
+    pub player_hands: Vec<PlayerHand>,
+    pub dealer_hand: Hand,
+    #[serde(default)]
+    pub others: Vec<Hand>,
+    #[serde(default)]
+    pub insurance: u32,
+    pub state: GameState,
+}
+
+/// The version of the snapshot format written by `to_json()`.
+pub const SNAPSHOT_VERSION: u32 = 3;
+
+/// A snapshot as it is written, with the version of the format.
+#[derive(Serialize)]
+struct VersionedSnapshot<'a> {
+    version: u32,
+    game: &'a Snapshot,
+}
+
+/// The migrations that bring a snapshot from each format version to the next, in order.
+const MIGRATIONS: [fn(Value) -> Value; SNAPSHOT_VERSION as usize] =
+    [wrap_unversioned, number_hands, list_face_down_cards];
+
+/// Migrates a snapshot saved before the format was versioned, which held the game on its own.
+fn wrap_unversioned(game: Value) -> Value {
+    json!({ "version": 1, "game": game })
+}
+
+/// Migrates a snapshot saved before hands had IDs, numbering the player's hands in table order.
+fn number_hands(mut saved: Value) -> Value {
+    if let Some(hands) = saved["game"]["player_hands"].as_array_mut() {
+        for (index, hand) in hands.iter_mut().enumerate() {
+            hand["id"] = json!(index + 1);
+            hand["parent"] = Value::Null;
+        }
+    }
+    saved["version"] = json!(2);
+    saved
+}
+
+/// Migrates a snapshot saved when only a hand's second card could be face-down, listing the
+/// face-down cards of each hand instead.
+fn list_face_down_cards(mut saved: Value) -> Value {
+    fn list_face_down(hand: &mut Value) {
+        if let Some(hand) = hand.as_object_mut() {
+            let hidden = hand
+                .remove("hole_card_hidden")
+                .and_then(|hidden| hidden.as_bool());
+            let face_down = if hidden == Some(true) {
+                json!([1])
+            } else {
+                json!([])
+            };
+            hand.insert("face_down".to_string(), face_down);
+        }
+    }
+
+    list_face_down(&mut saved["game"]["dealer_hand"]);
+    if let Some(hands) = saved["game"]["player_hands"].as_array_mut() {
+        for player_hand in hands {
+            list_face_down(&mut player_hand["hand"]);
+        }
+    }
+    saved["version"] = json!(3);
+    saved
+}
+
+impl Snapshot {
+    /// Writes the snapshot as JSON, together with the version of the format.
+    pub fn to_json(&self) -> Result<String, BlackjackError> {
+        let saved = VersionedSnapshot {
+            version: SNAPSHOT_VERSION,
+            game: self,
+        };
+        Ok(serde_json::to_string_pretty(&saved)?)
+    }
+
+    /// Reads a snapshot written by `to_json()` in this or any earlier format version.
+    pub fn from_json(json: &str) -> Result<Self, BlackjackError> {
+        let mut saved: Value = serde_json::from_str(json)?;
+        let version = saved.get("version").and_then(Value::as_u64).unwrap_or(0) as u32;
+        if version > SNAPSHOT_VERSION {
+            return Err(BlackjackError::UnsupportedVersion {
+                found: version,
+                supported: SNAPSHOT_VERSION,
+            });
+        }
+        for migrate in &MIGRATIONS[version as usize..] {
+            saved = migrate(saved);
+        }
+        Ok(serde_json::from_value(saved["game"].take())?)
+    }
+}
@@ -0,0 +1,458 @@
+use alloc::collections::BTreeMap;
+use core::cmp::Ordering;
+
+use super::{Action, RuleSet};
+use crate::card::{Card, Rank};
+use crate::hand::{Hand, HandValue};
+use crate::prelude::*;
+
+/// The totals the dealer can finish on, 17 through 21, and a bust, in the order `DealerTotals`
+/// keeps their chances.
+const DEALER_FINISHES: usize = 6;
+
+/// The chance of each way the dealer can finish: on 17, 18, 19, 20 or 21, or bust, last.
+type DealerTotals = [f64; DEALER_FINISHES];
+
+/// What each play is worth on average for a hand, in stakes won or lost per stake bet: 0.1 is a
+/// tenth of the bet won in the long run, and -0.5 is half of it lost.
+///
+/// Every play is valued whether or not it can be made, as `best()` only picks among those that can;
+/// `split` is `None` for a hand that is not a pair.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActionValues {
+    pub stand: f64,
+    pub hit: f64,
+    pub double: f64,
+    pub split: Option<f64>,
+    pub surrender: f64,
+}
+
+impl ActionValues {
+    /// Returns the value of `action`, or `None` if it is not a play made on a hand or, for a split,
+    /// the hand is not a pair.
+    pub fn of(&self, action: Action) -> Option<f64> {
+        match action {
+            Action::Stand => Some(self.stand),
+            Action::Hit => Some(self.hit),
+            Action::Double => Some(self.double),
+            Action::Split => self.split,
+            Action::Surrender => Some(self.surrender),
+            _ => None,
+        }
+    }
+
+    /// Returns the play worth the most among the `legal` actions, standing if none of them is a
+    /// play made on a hand.
+    pub fn best(&self, legal: &[Action]) -> Action {
+        let mut best = (Action::Stand, f64::NEG_INFINITY);
+        for &action in legal {
+            if let Some(value) = self.of(action) {
+                if value > best.1 {
+                    best = (action, value);
+                }
+            }
+        }
+        best.0
+    }
+}
+
+/// Works out basic strategy from the rules of the table, rather than reading it from a chart, so
+/// the plays stay right whatever the rules: the number of decks, whether the dealer hits a soft 17
+/// or peeks for a natural, and whether doubling after a split and surrender are allowed.
+///
+/// A solver is made for one up card. It reckons the chance of each card drawn from the shoe as dealt
+/// by the rules, less the up card and the `seen` cards of the player's hand, and as if every card
+/// drawn afterwards were put back, which keeps the sums quick enough to redo at every decision; the
+/// cards removed are what makes a single deck play differently from eight. From there it finds how
+/// the dealer finishes, and what standing, hitting, doubling, splitting and surrendering are each
+/// worth for a hand, hitting on only while that is worth more than standing. A split is valued as
+/// two hands that are not split again, where split Aces take one card each. A hand's charlie is not
+/// taken into account.
+///
+/// When the dealer peeks, a decision is only ever made once the dealer is known not to hold a
+/// natural. When the dealer does not, a natural found at the end takes the whole of a double or
+/// split, but not a surrender, which is settled straight away.
+pub struct StrategySolver {
+    rules: RuleSet,
+    /// The chance of drawing each rank, with tens and faces all drawn as tens.
+    draws: Vec<(Rank, f64)>,
+    /// How the dealer finishes, given that the dealer does not hold a natural.
+    dealer: DealerTotals,
+    /// The chance that the dealer holds a natural the player does not know about yet.
+    natural: f64,
+    /// What hitting a hand on is worth, by its total and whether it is soft, once worked out.
+    hits: BTreeMap<(u32, bool), f64>,
+}
+
+impl StrategySolver {
+    /// Makes a solver for the dealer's `up_card` under `rules`, with the `seen` cards taken out of
+    /// the shoe as well.
+    pub fn new(rules: &RuleSet, up_card: Card, seen: &[Card]) -> StrategySolver {
+        let draws = shoe_draws(rules.decks, seen.iter().chain([&up_card]));
+        let up = draw(
+            HandValue {
+                total: 0,
+                soft: false,
+            },
+            up_card.rank,
+        );
+
+        let mut finishes = BTreeMap::new();
+        let mut totals = [0.0; DEALER_FINISHES];
+        let mut natural = 0.0;
+        for &(rank, chance) in &draws {
+            let next = draw(up, rank);
+            if next.total == 21 {
+                natural += chance;
+                continue;
+            }
+            let after = dealer_finishes(next, &draws, rules, &mut finishes);
+            for (total, after) in totals.iter_mut().zip(after) {
+                *total += chance * after;
+            }
+        }
+        // Leaving out the naturals leaves chances that no longer add up to one
+        let known = 1.0 - natural;
+        for total in &mut totals {
+            *total /= known;
+        }
+
+        StrategySolver {
+            rules: *rules,
+            draws,
+            dealer: totals,
+            natural: if rules.dealer_peeks { 0.0 } else { natural },
+            hits: BTreeMap::new(),
+        }
+    }
+
+    /// Returns what each play is worth for `hand`.
+    pub fn values(&mut self, hand: &Hand) -> ActionValues {
+        ActionValues {
+            stand: self.value(hand, Action::Stand).unwrap_or_default(),
+            hit: self.value(hand, Action::Hit).unwrap_or_default(),
+            double: self.value(hand, Action::Double).unwrap_or_default(),
+            split: self.value(hand, Action::Split),
+            surrender: self.value(hand, Action::Surrender).unwrap_or_default(),
+        }
+    }
+
+    /// Returns the basic strategy play for `hand`, chosen from the `legal` actions. Only the legal
+    /// plays are valued, as a split takes far longer to work out than the others.
+    pub fn play(&mut self, hand: &Hand, legal: &[Action]) -> Action {
+        let mut best = (Action::Stand, f64::NEG_INFINITY);
+        for &action in legal {
+            if let Some(value) = self.value(hand, action) {
+                if value > best.1 {
+                    best = (action, value);
+                }
+            }
+        }
+        best.0
+    }
+
+    /// Returns what `action` is worth for `hand`, or `None` if it is not a play made on a hand or,
+    /// for a split, the hand is not a pair.
+    pub fn value(&mut self, hand: &Hand, action: Action) -> Option<f64> {
+        let (value, staked) = match action {
+            Action::Stand => (self.stand(hand.value()), 1.0),
+            Action::Hit => (self.hit(hand.value()), 1.0),
+            Action::Double => (self.double(hand.value()), 2.0),
+            Action::Split if hand.is_pair() => (self.split(hand.cards()[0].rank), 2.0),
+            // A surrender is settled before the dealer's hole card is turned up
+            Action::Surrender => return Some(-0.5),
+            _ => return None,
+        };
+        // A natural the dealer turns up at the end takes whatever has been staked on the hand
+        Some((1.0 - self.natural) * value - self.natural * staked)
+    }
+
+    /// Returns what standing on a hand of `value` is worth against a dealer without a natural.
+    fn stand(&self, value: HandValue) -> f64 {
+        if value.total > 21 {
+            return -1.0;
+        }
+        let (finishes, bust) = self.dealer.split_at(DEALER_FINISHES - 1);
+        finishes
+            .iter()
+            .zip(17..)
+            .map(|(chance, dealer)| match value.total.cmp(&dealer) {
+                Ordering::Greater => *chance,
+                Ordering::Less => -chance,
+                Ordering::Equal => 0.0,
+            })
+            .sum::<f64>()
+            + bust[0]
+    }
+
+    /// Returns what hitting a hand of `value` is worth, hitting on after that while it is worth
+    /// more than standing.
+    fn hit(&mut self, value: HandValue) -> f64 {
+        if let Some(&hit) = self.hits.get(&(value.total, value.soft)) {
+            return hit;
+        }
+        let mut hit = 0.0;
+        for (rank, chance) in self.draws.clone() {
+            let next = draw(value, rank);
+            let after = match next.total > 21 {
+                true => -1.0,
+                false => self.stand(next).max(self.hit(next)),
+            };
+            hit += chance * after;
+        }
+        self.hits.insert((value.total, value.soft), hit);
+        hit
+    }
+
+    /// Returns what doubling a hand of `value` is worth: twice the stake on one more card.
+    fn double(&self, value: HandValue) -> f64 {
+        self.draws
+            .iter()
+            .map(|&(rank, chance)| chance * 2.0 * self.stand(draw(value, rank)))
+            .sum()
+    }
+
+    /// Returns what splitting a pair of `pair` cards is worth, as two hands played on their own.
+    fn split(&mut self, pair: Rank) -> f64 {
+        let start = draw(
+            HandValue {
+                total: 0,
+                soft: false,
+            },
+            pair,
+        );
+        let mut hand = 0.0;
+        for (rank, chance) in self.draws.clone() {
+            let next = draw(start, rank);
+            let after = if pair == Rank::Ace {
+                // Split Aces take one card each, and a ten on one makes 21 rather than a natural
+                self.stand(next)
+            } else if self.rules.double_after_split {
+                self.stand(next).max(self.hit(next)).max(self.double(next))
+            } else {
+                self.stand(next).max(self.hit(next))
+            };
+            hand += chance * after;
+        }
+        2.0 * hand
+    }
+}
+
+/// Returns the value of a hand of `value` once a card of `rank` is added to it, counting an Ace as
+/// 11 where that does not bust the hand.
+fn draw(value: HandValue, rank: Rank) -> HandValue {
+    let total = value.total + rank.value();
+    if rank == Rank::Ace && !value.soft && total + 10 <= 21 {
+        HandValue {
+            total: total + 10,
+            soft: true,
+        }
+    } else if value.soft && total > 21 {
+        HandValue {
+            total: total - 10,
+            soft: false,
+        }
+    } else {
+        HandValue {
+            total,
+            soft: value.soft,
+        }
+    }
+}
+
+/// Returns the chance of drawing each rank from a shoe of `decks` decks with the `seen` cards taken
+/// out. Tens and faces are all drawn as tens, as they count the same.
+fn shoe_draws<'a>(decks: usize, seen: impl Iterator<Item = &'a Card>) -> Vec<(Rank, f64)> {
+    let as_ten = |rank: Rank| match rank {
+        Rank::Jack | Rank::Queen | Rank::King => Rank::Ten,
+        rank => rank,
+    };
+    let mut counts: BTreeMap<u32, (Rank, f64)> = BTreeMap::new();
+    for rank in Rank::ALL {
+        let rank = as_ten(rank);
+        counts.entry(rank.value()).or_insert((rank, 0.0)).1 += 4.0 * decks as f64;
+    }
+    for card in seen {
+        if let Some((_, count)) = counts.get_mut(&card.rank.value()) {
+            // A shoe is never so short of a rank that a hand uses it all up
+            *count = (*count - 1.0).max(0.0);
+        }
+    }
+    let left: f64 = counts.values().map(|(_, count)| count).sum();
+    counts
+        .into_values()
+        .map(|(rank, count)| (rank, count / left))
+        .collect()
+}
+
+/// Returns how the dealer finishes drawing to a hand of `value` by the `rules`, remembering in
+/// `finishes` how the dealer finishes from each total already worked out.
+///
+/// The dealer draws to 16, and to a soft 17 if the rules say so, as `RuleSet::dealer_policy()`
+/// does; the policy is asked of whole hands, where here only their values are followed.
+fn dealer_finishes(
+    value: HandValue,
+    draws: &[(Rank, f64)],
+    rules: &RuleSet,
+    finishes: &mut BTreeMap<(u32, bool), DealerTotals>,
+) -> DealerTotals {
+    let mut totals = [0.0; DEALER_FINISHES];
+    if value.total > 21 {
+        totals[DEALER_FINISHES - 1] = 1.0;
+        return totals;
+    }
+    let hits = value.total < 17 || (value.total == 17 && value.soft && rules.dealer_hits_soft_17);
+    if !hits {
+        totals[(value.total - 17) as usize] = 1.0;
+        return totals;
+    }
+    if let Some(known) = finishes.get(&(value.total, value.soft)) {
+        return *known;
+    }
+    for &(rank, chance) in draws {
+        let after = dealer_finishes(draw(value, rank), draws, rules, finishes);
+        for (total, after) in totals.iter_mut().zip(after) {
+            *total += chance * after;
+        }
+    }
+    finishes.insert((value.total, value.soft), totals);
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Suit;
+
+    fn hand(ranks: &[Rank]) -> Hand {
+        let mut hand = Hand::new();
+        for &rank in ranks {
+            hand.add(Card::new(rank, Suit::Spades));
+        }
+        hand
+    }
+
+    /// Returns the play the solver picks under `rules` for `ranks` against `up`, from hitting,
+    /// standing, doubling and splitting.
+    fn play(rules: &RuleSet, ranks: &[Rank], up: Rank) -> Action {
+        let hand = hand(ranks);
+        let mut solver = StrategySolver::new(rules, Card::new(up, Suit::Hearts), hand.cards());
+        let legal = [Action::Hit, Action::Stand, Action::Double, Action::Split];
+        solver.play(&hand, &legal)
+    }
+
+    #[test]
+    fn the_draws_add_up_to_one_less_the_cards_seen() {
+        let seen = [Card::new(Rank::King, Suit::Spades)];
+        let draws = shoe_draws(1, seen.iter());
+        let total: f64 = draws.iter().map(|(_, chance)| chance).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert_eq!(draws.len(), 10);
+        let (_, tens) = draws.iter().find(|(rank, _)| *rank == Rank::Ten).unwrap();
+        assert!((tens - 15.0 / 51.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn the_dealer_finishes_add_up_to_one() {
+        for up in [Rank::Two, Rank::Six, Rank::Ten, Rank::Ace] {
+            let solver = StrategySolver::new(&RuleSet::CLASSIC, Card::new(up, Suit::Hearts), &[]);
+            let total: f64 = solver.dealer.iter().sum();
+            assert!(
+                (total - 1.0).abs() < 1e-9,
+                "{:?} finishes add up to {}",
+                up,
+                total
+            );
+        }
+    }
+
+    #[test]
+    fn a_dealer_hitting_soft_17_busts_more_often() {
+        let six = Card::new(Rank::Six, Suit::Hearts);
+        let stands = StrategySolver::new(&RuleSet::CLASSIC, six, &[]);
+        let hits_rules = RuleSet {
+            dealer_hits_soft_17: true,
+            ..RuleSet::CLASSIC
+        };
+        let hits = StrategySolver::new(&hits_rules, six, &[]);
+        assert!(hits.dealer[DEALER_FINISHES - 1] > stands.dealer[DEALER_FINISHES - 1]);
+    }
+
+    #[test]
+    fn the_plays_follow_basic_strategy() {
+        let rules = RuleSet::CLASSIC;
+        assert_eq!(
+            play(&rules, &[Rank::Ten, Rank::Six], Rank::Ten),
+            Action::Hit
+        );
+        assert_eq!(
+            play(&rules, &[Rank::Ten, Rank::Two], Rank::Six),
+            Action::Stand
+        );
+        assert_eq!(
+            play(&rules, &[Rank::Six, Rank::Five], Rank::Six),
+            Action::Double
+        );
+        assert_eq!(
+            play(&rules, &[Rank::Ace, Rank::Ace], Rank::Six),
+            Action::Split
+        );
+        assert_eq!(
+            play(&rules, &[Rank::Ten, Rank::King], Rank::Six),
+            Action::Stand
+        );
+        assert_eq!(
+            play(&rules, &[Rank::Ace, Rank::Seven], Rank::Nine),
+            Action::Hit
+        );
+    }
+
+    #[test]
+    fn only_legal_plays_are_picked() {
+        let hand = hand(&[Rank::Ten, Rank::Six]);
+        let ten = Card::new(Rank::Ten, Suit::Hearts);
+        let mut solver = StrategySolver::new(&RuleSet::CLASSIC, ten, hand.cards());
+        assert_eq!(solver.play(&hand, &[Action::Stand]), Action::Stand);
+        let values = solver.values(&hand);
+        assert_eq!(values.best(&[Action::Stand, Action::Double]), Action::Stand);
+    }
+
+    #[test]
+    fn only_pairs_are_valued_as_splits() {
+        let ten = Card::new(Rank::Ten, Suit::Hearts);
+        let mut solver = StrategySolver::new(&RuleSet::CLASSIC, ten, &[]);
+        assert_eq!(
+            solver.value(&hand(&[Rank::Ten, Rank::Six]), Action::Split),
+            None
+        );
+        assert!(solver
+            .values(&hand(&[Rank::Eight, Rank::Eight]))
+            .split
+            .is_some());
+    }
+
+    #[test]
+    fn a_surrender_loses_half_the_stake() {
+        let ace = Card::new(Rank::Ace, Suit::Hearts);
+        let mut solver = StrategySolver::new(&RuleSet::CLASSIC, ace, &[]);
+        let hand = hand(&[Rank::Ten, Rank::Six]);
+        assert_eq!(solver.value(&hand, Action::Surrender), Some(-0.5));
+    }
+
+    #[test]
+    fn a_natural_the_dealer_has_not_peeked_for_takes_a_double() {
+        let ace = Card::new(Rank::Ace, Suit::Hearts);
+        let hand = hand(&[Rank::Six, Rank::Five]);
+        let peeks = RuleSet {
+            dealer_peeks: true,
+            ..RuleSet::CLASSIC
+        };
+        let peeked = StrategySolver::new(&peeks, ace, hand.cards())
+            .values(&hand)
+            .double;
+        let unpeeked = StrategySolver::new(&RuleSet::CLASSIC, ace, hand.cards())
+            .values(&hand)
+            .double;
+        assert!(unpeeked < peeked);
+    }
+}
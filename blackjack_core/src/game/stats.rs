@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use super::Outcome;
+use crate::card::{Card, Rank};
+use crate::hand::Hand;
+use crate::prelude::*;
+
+/// The kinds of hand that graded decisions are counted by, in the order `by_hand` keeps them.
+pub const HAND_KINDS: [&str; 3] = ["hard", "soft", "pairs"];
+
+/// The dealer's up cards that graded decisions are counted by, in the order `by_up_card` keeps
+/// them, with every ten and face card counted as a ten.
+pub const UP_CARD_NAMES: [&str; 10] = ["2", "3", "4", "5", "6", "7", "8", "9", "10", "A"];
+
+/// How many graded decisions were made, and how many of them matched basic strategy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Accuracy {
+    pub decisions: u32,
+    pub correct: u32,
+}
+
+impl Accuracy {
+    /// Records a decision, which matched basic strategy if `correct` is true.
+    pub fn record(&mut self, correct: bool) {
+        self.decisions += 1;
+        if correct {
+            self.correct += 1;
+        }
+    }
+
+    /// Adds the decisions counted in `other`.
+    pub fn add(&mut self, other: &Accuracy) {
+        self.decisions += other.decisions;
+        self.correct += other.correct;
+    }
+
+    /// Returns the percentage of the decisions that matched basic strategy, or `None` if there
+    /// were none.
+    pub fn percentage(&self) -> Option<f64> {
+        (self.decisions > 0).then(|| self.correct as f64 / self.decisions as f64 * 100.0)
+    }
+}
+
+/// Returns where in `HAND_KINDS` a decision on `hand` is counted: a pair of its first two cards,
+/// and otherwise a soft or hard total.
+pub fn hand_kind(hand: &Hand) -> usize {
+    let value = hand.value();
+    if hand.is_pair() {
+        2
+    } else if value.soft {
+        1
+    } else {
+        0
+    }
+}
+
+/// Returns where in `UP_CARD_NAMES` a decision against `up_card` is counted.
+pub fn up_card_index(up_card: Card) -> usize {
+    match up_card.rank {
+        Rank::Ace => 9,
+        rank => rank.value() as usize - 2,
+    }
+}
+
+/// Running totals for the current session, shown on the game over screen and the session summary.
+///
+/// Besides hand results, the statistics keep the session's accounts: the chips bought in (the starting
+/// bankroll plus any rebuys), the chips wagered and the chips returned by the table, which are tracked
+/// separately from the running bankroll. The `bankroll_history` is the bankroll at the start of the
+/// session and at the end of every round since, so the session's trajectory can be drawn.
+///
+/// The biggest win and loss are the most chips won and lost in a single round, side bets included.
+/// When the player's decisions are graded, `decisions` counts them and `basic_strategy_plays` the
+/// ones that matched basic strategy, and `by_hand` and `by_up_card` break them down by the kind of
+/// hand, as in `HAND_KINDS`, and the dealer's up card, as in `UP_CARD_NAMES`. `seconds_played` is the time played before the session was
+/// last saved, as the time since is kept by the controller.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionStats {
+    pub rounds_played: u32,
+    pub hands_won: u32,
+    pub hands_lost: u32,
+    pub hands_pushed: u32,
+    pub blackjacks: u32,
+    pub peak_bankroll: u32,
+    pub rebuys: u32,
+    pub bought_in: u64,
+    pub wagered: u64,
+    pub returned: u64,
+    // Saved games from before the history was kept carry on with an empty one
+    #[serde(default)]
+    pub bankroll_history: Vec<u32>,
+    #[serde(default)]
+    pub biggest_win: u64,
+    #[serde(default)]
+    pub biggest_loss: u64,
+    #[serde(default)]
+    pub decisions: u32,
+    #[serde(default)]
+    pub basic_strategy_plays: u32,
+    #[serde(default)]
+    pub by_hand: [Accuracy; 3],
+    #[serde(default)]
+    pub by_up_card: [Accuracy; 10],
+    #[serde(default)]
+    pub seconds_played: u64,
+}
+
+impl SessionStats {
+    /// Creates empty statistics for a session starting with `bankroll` chips.
+    pub fn new(bankroll: u32) -> Self {
+        SessionStats {
+            rounds_played: 0,
+            hands_won: 0,
+            hands_lost: 0,
+            hands_pushed: 0,
+            blackjacks: 0,
+            peak_bankroll: bankroll,
+            rebuys: 0,
+            bought_in: bankroll as u64,
+            wagered: 0,
+            returned: 0,
+            bankroll_history: vec![bankroll],
+            biggest_win: 0,
+            biggest_loss: 0,
+            decisions: 0,
+            basic_strategy_plays: 0,
+            by_hand: [Accuracy::default(); 3],
+            by_up_card: [Accuracy::default(); 10],
+            seconds_played: 0,
+        }
+    }
+
+    /// Records `amount` chips bought in during the session, bringing the bankroll to `balance`.
+    pub fn record_buy_in(&mut self, amount: u32, balance: u32) {
+        self.bought_in += amount as u64;
+        self.peak_bankroll = self.peak_bankroll.max(balance);
+    }
+
+    /// Returns the percentage of wagered chips returned by the table, or `None` if nothing was wagered.
+    pub fn return_to_player(&self) -> Option<f64> {
+        (self.wagered > 0).then(|| self.returned as f64 / self.wagered as f64 * 100.0)
+    }
+
+    /// Returns the percentage of graded decisions that matched basic strategy, or `None` if no
+    /// decision was graded.
+    pub fn accuracy(&self) -> Option<f64> {
+        (self.decisions > 0)
+            .then(|| self.basic_strategy_plays as f64 / self.decisions as f64 * 100.0)
+    }
+
+    /// Records a graded decision on `hand` against the dealer's `up_card`, which matched basic
+    /// strategy if `correct` is true.
+    pub fn record_decision(&mut self, hand: &Hand, up_card: Card, correct: bool) {
+        self.decisions += 1;
+        if correct {
+            self.basic_strategy_plays += 1;
+        }
+        self.by_hand[hand_kind(hand)].record(correct);
+        self.by_up_card[up_card_index(up_card)].record(correct);
+    }
+
+    /// Records the outcome of a settled player hand.
+    pub fn record_hand(&mut self, hand: &Hand, outcome: Outcome) {
+        match outcome {
+            Outcome::Win(_) => self.hands_won += 1,
+            Outcome::Push => self.hands_pushed += 1,
+            Outcome::Lose | Outcome::Surrender => self.hands_lost += 1,
+        }
+        if hand.is_blackjack() {
+            self.blackjacks += 1;
+        }
+    }
+
+    /// Records the end of a round in which the player won `net` chips, or lost them if it is
+    /// negative, leaving the player with `bankroll` chips.
+    pub fn record_round(&mut self, bankroll: u32, net: i64) {
+        self.rounds_played += 1;
+        if net > 0 {
+            self.biggest_win = self.biggest_win.max(net.unsigned_abs());
+        } else {
+            self.biggest_loss = self.biggest_loss.max(net.unsigned_abs());
+        }
+        self.peak_bankroll = self.peak_bankroll.max(bankroll);
+        self.bankroll_history.push(bankroll);
+    }
+}
@@ -0,0 +1,282 @@
+#[cfg(feature = "std")]
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+use super::{Action, CardCounter, RuleSet, TableLimits};
+#[cfg(feature = "std")]
+use super::{BasicStrategy, BetError, KeyBindings, Locale};
+use crate::card::Card;
+use crate::error::BlackjackError;
+use crate::hand::Hand;
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::PlayerInput;
+
+/// What a seat at the table can see when it has to bet or act.
+///
+/// The view holds the hand to act on, the dealer's up card, the actions allowed right now, the seat's
+/// bankroll and the table's limits and rules, together with the count of the cards seen so far and
+/// the decks left in the shoe, for strategies that count cards.
+pub struct GameView {
+    /// The hand to act on, or `None` while betting.
+    pub hand: Option<Hand>,
+    /// The dealer's face-up card, or `None` while betting.
+    pub dealer_up_card: Option<Card>,
+    /// The actions allowed right now. While betting, the bet is asked for with `bet()` instead.
+    pub legal_actions: Vec<Action>,
+    /// Whether the house covers the extra wager on a double of this hand.
+    pub free_double: bool,
+    /// Whether the house covers the wager on the new hand if this hand is split.
+    pub free_split: bool,
+    pub bankroll: u32,
+    pub limits: TableLimits,
+    pub rules: RuleSet,
+    pub count: CardCounter,
+}
+
+impl GameView {
+    /// Returns the true count, as the counter reckons it.
+    pub fn true_count(&self) -> f64 {
+        self.count.true_count()
+    }
+
+    /// Returns the largest bet the table and the seat's bankroll allow.
+    pub fn max_bet(&self) -> u32 {
+        self.limits.maximum.min(self.bankroll)
+    }
+}
+
+/// The `PlayerStrategy` trait decides how a seat at the table bets and plays its hands.
+///
+/// The `PlayerStrategy` trait provides the following methods:
+/// - `name(&self) -> String`: Returns a description of the strategy.
+/// - `bet(&mut self, view: &GameView) -> Result<u32, BlackjackError>`: Returns the main bet for the
+///   next round, or 0 to sit the round out.
+/// - `decide(&mut self, view: &GameView) -> Result<Action, BlackjackError>`: Returns one of the
+///   view's legal actions for the hand being played.
+///
+/// A person at the keyboard is one strategy, `PromptStrategy` (with the `std` feature), and bots such as `BasicStrategy` are
+/// others, so any seat can be played by either.
+pub trait PlayerStrategy {
+    fn name(&self) -> String;
+    fn bet(&mut self, view: &GameView) -> Result<u32, BlackjackError>;
+    fn decide(&mut self, view: &GameView) -> Result<Action, BlackjackError>;
+}
+
+/// A strategy that asks a person for every bet and decision, reading their choices from a
+/// `PlayerInput` and writing the prompts to an output.
+///
+/// At a decision the person can also choose to quit. Once they confirm, the hand stands and
+/// `quit_requested` is set, so whoever runs the game can settle the round and let them leave. The
+/// keys for each choice are the default ones unless `keys` is changed, and the prompts are taken
+/// from the `locale`, which is English unless changed. With a `time_limit`, a person who has not
+/// decided when the time runs out stands.
+///
+/// The advice key writes the basic strategy play for the hand against the dealer's up card, chosen
+/// from the actions the table's rules allow, and asks the decision again. With `index_plays` set,
+/// the advice departs from basic strategy by the index plays at the true count, while the count is
+/// kept by Hi-Lo.
+///
+/// The count key flips `show_count` and asks the decision again: the Hi-Lo count is written when it
+/// is turned on, for counting practice, and whoever runs the game can keep showing it from then on.
+#[cfg(feature = "std")]
+pub struct PromptStrategy<I: PlayerInput, W: Write> {
+    pub input: I,
+    pub output: W,
+    pub keys: KeyBindings,
+    pub time_limit: Option<Duration>,
+    pub locale: Locale,
+    pub quit_requested: bool,
+    pub show_count: bool,
+    pub index_plays: bool,
+}
+
+#[cfg(feature = "std")]
+impl<I: PlayerInput, W: Write> PromptStrategy<I, W> {
+    /// Creates a strategy that prompts on `output` and reads the answers from `input`.
+    pub fn new(input: I, output: W) -> Self {
+        PromptStrategy {
+            input,
+            output,
+            keys: KeyBindings::default(),
+            time_limit: None,
+            locale: Locale::default(),
+            quit_requested: false,
+            show_count: false,
+            index_plays: false,
+        }
+    }
+
+    /// Asks the person to confirm that they want to quit, and returns true if they do.
+    fn confirm_quit(&mut self) -> Result<bool, BlackjackError> {
+        loop {
+            writeln!(self.output, "{}", self.locale.text("quit-prompt"))?;
+            match self.input.read_key(&["y", "n"], None)?.as_deref() {
+                Some("y") => return Ok(true),
+                Some("n") => return Ok(false),
+                _ => writeln!(self.output, "{}", self.locale.text("quit-invalid"))?,
+            }
+        }
+    }
+
+    /// Returns the action the person chose at a decision by typing `choice`, or `None` after
+    /// telling them the choice cannot be played, if they chose to quit and then changed their mind,
+    /// or if they asked for advice or showed or hid the count, so the decision is asked again.
+    fn choose(&mut self, view: &GameView, choice: &str) -> Result<Option<Action>, BlackjackError> {
+        let keys = &self.keys;
+        let action = match choice {
+            key if key == keys.quit => {
+                if self.confirm_quit()? {
+                    self.quit_requested = true;
+                    return Ok(Some(Action::Stand));
+                }
+                return Ok(None);
+            }
+            key if key == keys.advice => {
+                if let Some(play) = BasicStrategy::advise(view, self.index_plays) {
+                    let message = self
+                        .locale
+                        .message("advice-play", &[("action", &self.locale.action(play))]);
+                    writeln!(self.output, "{}", message)?;
+                }
+                return Ok(None);
+            }
+            key if key == keys.count => {
+                self.show_count = !self.show_count;
+                let message = match self.show_count {
+                    true => self.locale.count(&view.count),
+                    false => self.locale.text("count-hidden"),
+                };
+                writeln!(self.output, "{}", message)?;
+                return Ok(None);
+            }
+            key if key == keys.hit => Some(Action::Hit),
+            key if key == keys.stand => Some(Action::Stand),
+            key if key == keys.double => Some(Action::Double),
+            key if key == keys.split => Some(Action::Split),
+            key if key == keys.surrender => Some(Action::Surrender),
+            _ => None,
+        };
+        match action {
+            Some(action) if view.legal_actions.contains(&action) => Ok(Some(action)),
+            _ => {
+                writeln!(self.output, "{}", self.locale.text("invalid-choice"))?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Asks whether to switch the second cards of the two hands, in variants that allow it.
+    fn decide_switch(&mut self) -> Result<Action, BlackjackError> {
+        loop {
+            writeln!(self.output, "{}", self.locale.text("switch-prompt"))?;
+            match self.input.read_key(&["y", "n"], None)?.as_deref() {
+                Some("y") => return Ok(Action::Switch),
+                Some("n") => return Ok(Action::KeepHands),
+                _ => writeln!(self.output, "{}", self.locale.text("switch-invalid"))?,
+            }
+        }
+    }
+
+    /// Asks whether to take insurance against the dealer's Ace.
+    fn decide_insurance(&mut self) -> Result<Action, BlackjackError> {
+        loop {
+            writeln!(self.output, "{}", self.locale.text("insurance-prompt"))?;
+            match self.input.read_key(&["y", "n"], None)?.as_deref() {
+                Some("y") => return Ok(Action::Insure),
+                Some("n") => return Ok(Action::DeclineInsurance),
+                _ => writeln!(self.output, "{}", self.locale.text("insurance-invalid"))?,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: PlayerInput, W: Write> PlayerStrategy for PromptStrategy<I, W> {
+    fn name(&self) -> String {
+        self.locale.text("you")
+    }
+
+    fn bet(&mut self, view: &GameView) -> Result<u32, BlackjackError> {
+        loop {
+            let prompt = self.locale.message(
+                "bet-prompt",
+                &[
+                    ("minimum", &view.limits.minimum),
+                    ("maximum", &view.limits.maximum),
+                ],
+            );
+            writeln!(self.output, "{}", prompt)?;
+            let Ok(bet) = self.input.read_choice()?.parse::<u32>() else {
+                writeln!(self.output, "{}", self.locale.text("invalid-amount"))?;
+                continue;
+            };
+            match view.limits.validate(bet) {
+                Ok(()) if bet <= view.bankroll => return Ok(bet),
+                Ok(()) => {
+                    let error = BetError::InsufficientChips(view.bankroll);
+                    writeln!(self.output, "{}", self.locale.bet_error(&error))?
+                }
+                Err(error) => writeln!(self.output, "{}", self.locale.bet_error(&error))?,
+            }
+        }
+    }
+
+    fn decide(&mut self, view: &GameView) -> Result<Action, BlackjackError> {
+        if view.legal_actions.contains(&Action::Switch) {
+            return self.decide_switch();
+        }
+        if view.legal_actions.contains(&Action::Insure) {
+            return self.decide_insurance();
+        }
+
+        let keys = &self.keys;
+        let locale = &self.locale;
+        let label = |name: &str| keys.label_as(name, &locale.text(&format!("choice-{}", name)));
+        let for_free = |choice: String| locale.message("choice-for-free", &[("choice", &choice)]);
+        let options: Vec<String> = view
+            .legal_actions
+            .iter()
+            .filter_map(|action| match action {
+                Action::Hit => Some(label("hit")),
+                Action::Stand => Some(label("stand")),
+                Action::Double if view.free_double => Some(for_free(label("double"))),
+                Action::Double => Some(label("double")),
+                Action::Split if view.free_split => Some(for_free(label("split"))),
+                Action::Split => Some(label("split")),
+                Action::Surrender => Some(label("surrender")),
+                _ => None,
+            })
+            .chain([label("advice"), label("count"), label("quit")])
+            .collect();
+        // The keys are copied, as `choose()` borrows the whole strategy
+        let answers: Vec<String> = keys.keys().iter().map(|(_, key)| key.to_string()).collect();
+        let answers: Vec<&str> = answers.iter().map(String::as_str).collect();
+        let deadline = self.time_limit.map(|limit| Instant::now() + limit);
+        loop {
+            let left = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            let choices = options.join(", ");
+            let prompt = match left {
+                None => self
+                    .locale
+                    .message("decide-prompt", &[("choices", &choices)]),
+                Some(left) => self.locale.message(
+                    "decide-prompt-timed",
+                    &[
+                        ("choices", &choices),
+                        ("seconds", &left.as_secs_f64().ceil()),
+                    ],
+                ),
+            };
+            writeln!(self.output, "{}", prompt)?;
+            let Some(choice) = self.input.read_key(&answers, left)? else {
+                writeln!(self.output, "{}", self.locale.text("time-up"))?;
+                return Ok(Action::Stand);
+            };
+            if let Some(action) = self.choose(view, &choice)? {
+                return Ok(action);
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use super::{Bankroll, GameView, PlayerHand, PlayerStrategy};
+use crate::error::BlackjackError;
+use crate::prelude::*;
+
+/// The most seats a table has, counting the player's.
+pub const MAX_SEATS: usize = 7;
+
+/// A place at the table: the chips of whoever sits there, the hands they are playing with the
+/// wagers on them, and the strategy that bets and plays for them.
+///
+/// A seat with no strategy belongs to the person at the keyboard, who is prompted for every decision;
+/// any other seat is played by its `PlayerStrategy`, such as `DealerMimic`, which bets a random amount
+/// between one and three times the table minimum and hits until it reaches 17. A seat holds one
+/// hand per spot it plays, more once a pair is split, and none while it sits a round out.
+pub struct Seat {
+    pub name: &'static str,
+    pub bankroll: Bankroll,
+    pub hands: Vec<PlayerHand>,
+    pub strategy: Option<Box<dyn PlayerStrategy>>,
+}
+
+impl Seat {
+    /// Creates a seat with the given name, starting chips and strategy, or `None` for the player.
+    pub fn new(name: &'static str, chips: u32, strategy: Option<Box<dyn PlayerStrategy>>) -> Self {
+        Seat {
+            name,
+            bankroll: Bankroll::new(chips),
+            hands: Vec::new(),
+            strategy,
+        }
+    }
+
+    /// Returns true if the seat has hands in play this round.
+    pub fn is_playing(&self) -> bool {
+        !self.hands.is_empty()
+    }
+
+    /// Asks the seat's strategy for a bet and places it on a single hand, returning false if the
+    /// seat sits the round out: when it bets nothing, cannot cover its bet, or has no strategy.
+    pub fn place_bet(&mut self, view: &GameView) -> Result<bool, BlackjackError> {
+        self.hands.clear();
+        let Some(strategy) = &mut self.strategy else {
+            return Ok(false);
+        };
+        let bet = strategy.bet(view)?;
+        if bet == 0 || view.limits.validate(bet).is_err() || !self.bankroll.withdraw(bet) {
+            return Ok(false);
+        }
+        self.hands.push(PlayerHand::new(1, bet));
+        Ok(true)
+    }
+}
+
+/// The seats at the table in casino order, from first base, which is dealt to and acts first,
+/// round to third base, which acts just before the dealer.
+///
+/// One seat, at `player`, belongs to the player whose chips, statistics and side bets the game
+/// keeps track of; the other seats are opponents. Every seat is dealt, played and settled the same
+/// way, so opponents, bots and a player with several hands all go through one path.
+pub struct Table {
+    pub seats: Vec<Seat>,
+    pub player: usize,
+}
+
+impl Table {
+    /// Creates a table with the player at first base, followed by `opponents` in order.
+    pub fn new(player: Seat, opponents: Vec<Seat>) -> Self {
+        let mut seats = vec![player];
+        seats.extend(opponents);
+        Table { seats, player: 0 }
+    }
+
+    /// Returns the player's seat.
+    pub fn player(&self) -> &Seat {
+        &self.seats[self.player]
+    }
+
+    /// Returns the player's seat for changing.
+    pub fn player_mut(&mut self) -> &mut Seat {
+        &mut self.seats[self.player]
+    }
+
+    /// Returns the seats other than the player's, in casino order.
+    pub fn opponents(&self) -> impl Iterator<Item = &Seat> {
+        let player = self.player;
+        self.seats
+            .iter()
+            .enumerate()
+            .filter(move |(seat, _)| *seat != player)
+            .map(|(_, seat)| seat)
+    }
+
+    /// Replaces every seat other than the player's with `opponents`, moving the player to first base.
+    pub fn seat_opponents(&mut self, opponents: Vec<Seat>) {
+        let player = self.seats.swap_remove(self.player);
+        *self = Table::new(player, opponents);
+    }
+}
@@ -0,0 +1,620 @@
+use std::io::{self, Write};
+
+use super::{CardCounter, CurrencyFormat, Locale, PlayerHand, Seat, Theme};
+use crate::hand::Hand;
+use crate::Renderer;
+
+/// Everything on the table at one moment: the dealer's hand, the player's hands and the wagers on
+/// them, the opponents' hands and bets, the player's chips and how much of the shoe is left. The
+/// `bankroll_history` is the player's bankroll at the start of the session and after every round,
+/// and the `count` is kept of the cards seen so far, while the count is shown.
+pub struct TableFrame<'a> {
+    pub dealer_hand: &'a Hand,
+    pub player_hands: &'a [PlayerHand],
+    pub opponents: Vec<&'a Seat>,
+    pub bankroll: u32,
+    pub bankroll_history: &'a [u32],
+    pub count: Option<CardCounter>,
+    pub cards_remaining: usize,
+    pub decks: usize,
+}
+
+/// The bars a sparkline is drawn with, from the lowest to the highest.
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Returns the last `width` of `values` drawn as a sparkline, one bar each, such as "▁▃▂▅█", scaled
+/// so the lowest is the shortest bar and the highest the tallest.
+///
+/// A series that never changes is drawn at half height, and one with fewer than two values, which
+/// has no trajectory to show, as an empty string.
+pub fn sparkline(values: &[u32], width: usize) -> String {
+    let values = &values[values.len().saturating_sub(width)..];
+    if values.len() < 2 {
+        return String::new();
+    }
+    let low = values.iter().copied().min().unwrap_or(0);
+    let high = values.iter().copied().max().unwrap_or(0);
+    values
+        .iter()
+        .map(|value| match high - low {
+            0 => SPARKS[SPARKS.len() / 2 - 1],
+            range => {
+                let step = u64::from(value - low) * (SPARKS.len() as u64 - 1) / u64::from(range);
+                SPARKS[step as usize]
+            }
+        })
+        .collect()
+}
+
+impl TableFrame<'_> {
+    /// Returns the count as the counting overlay shows it in `locale`, or `None` while it is hidden.
+    fn count(&self, locale: &Locale) -> Option<String> {
+        self.count.as_ref().map(|counter| locale.count(counter))
+    }
+}
+
+/// The `TableView` trait draws the whole table as a single frame.
+///
+/// The `TableView` trait provides the following methods:
+/// - `render(&self, table: &TableFrame, cards: &dyn Renderer, out: &mut dyn Write) -> io::Result<()>`:
+///   Writes the frame to `out`, drawing the cards of each hand with `cards`.
+/// - `redraws(&self) -> bool`: Returns true if the whole table should be drawn again after every
+///   card and decision, in place of the lines that show a single hand. Views that only draw the
+///   table once the cards are dealt can keep the default, false.
+///
+/// Where a `Renderer` decides how the cards of one hand look, a `TableView` decides how the table
+/// is laid out around them, so frontends can change either without the other.
+pub trait TableView {
+    fn render(
+        &self,
+        table: &TableFrame,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()>;
+
+    fn redraws(&self) -> bool {
+        false
+    }
+}
+
+/// Returns the cards of `hand` as drawn by `cards`, to be written as part of a line.
+fn draw_cards(hand: &Hand, cards: &dyn Renderer) -> io::Result<String> {
+    let mut drawn = Vec::new();
+    cards.render(hand, &mut drawn)?;
+    Ok(String::from_utf8_lossy(&drawn).into_owned())
+}
+
+/// A table view that writes one line per hand, with its total and bet, followed by the player's
+/// bankroll and the cards left in the shoe, and the count while it is shown. The dealer's total counts only the face-up cards.
+/// Everything but the cards is written in the words of the `locale`.
+pub struct TextTableView {
+    pub currency: CurrencyFormat,
+    pub locale: Locale,
+}
+
+impl TextTableView {
+    /// Creates a table view that shows amounts of chips with `currency`, in English.
+    pub fn new(currency: CurrencyFormat) -> Self {
+        TextTableView {
+            currency,
+            locale: Locale::default(),
+        }
+    }
+
+    /// Writes one hand at the table: whose it is, its cards, its total and its bet.
+    fn hand(
+        &self,
+        owner: &str,
+        player_hand: &PlayerHand,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let detail = self.locale.message(
+            "table-hand-detail",
+            &[
+                ("total", &self.locale.value(player_hand.hand.value())),
+                ("bet", &self.currency.draw(player_hand.wager.total())),
+            ],
+        );
+        writeln!(
+            out,
+            "{}: {} ({})",
+            owner,
+            draw_cards(&player_hand.hand, cards)?,
+            detail
+        )
+    }
+}
+
+impl TableView for TextTableView {
+    fn render(
+        &self,
+        table: &TableFrame,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let locale = &self.locale;
+        let dealer = if table.dealer_hand.has_face_down() {
+            locale.message(
+                "table-showing",
+                &[(
+                    "total",
+                    &self.locale.value(table.dealer_hand.visible_value()),
+                )],
+            )
+        } else {
+            self.locale.value(table.dealer_hand.value())
+        };
+        writeln!(
+            out,
+            "{}: {} ({})",
+            locale.text("table-dealer"),
+            draw_cards(table.dealer_hand, cards)?,
+            dealer
+        )?;
+
+        for player_hand in table.player_hands {
+            let owner = match table.player_hands.len() {
+                1 => locale.text("table-you"),
+                count => locale.message(
+                    "table-you-hand-of",
+                    &[("hand", &player_hand.id), ("hands", &count)],
+                ),
+            };
+            self.hand(&owner, player_hand, cards, out)?;
+        }
+
+        for opponent in &table.opponents {
+            for player_hand in &opponent.hands {
+                self.hand(opponent.name, player_hand, cards, out)?;
+            }
+        }
+
+        writeln!(
+            out,
+            "{}",
+            locale.message(
+                "table-shoe",
+                &[
+                    ("bankroll", &self.currency.draw(table.bankroll)),
+                    ("remaining", &table.cards_remaining),
+                    ("size", &(table.decks * 52)),
+                ],
+            )
+        )?;
+        if let Some(count) = table.count(locale) {
+            writeln!(out, "{}", count)?;
+        }
+        Ok(())
+    }
+}
+
+/// A table view that composes the whole table into one frame, drawn again after every card and
+/// decision: the dealer's hand at the top, the player's hands and the opponents' below with their
+/// totals and bets, and a status bar with the shoe, the discards and the player's bankroll, with a
+/// sparkline of the bankroll over the session's last rounds under it once a round has been played.
+/// While the count is shown, it is written under the status bar.
+///
+/// Hands are written after a name column, so the table reads the same with any `Renderer`. The
+/// rules across the table are drawn with the `theme`'s border, in its felt colour, and everything
+/// but the cards is written in the words of the `locale`.
+pub struct LayoutTableView {
+    pub currency: CurrencyFormat,
+    pub theme: Theme,
+    pub locale: Locale,
+}
+
+impl LayoutTableView {
+    /// The width of the frame's rules, in characters.
+    const WIDTH: usize = 60;
+
+    /// The width of the shoe gauge in the status bar, in characters.
+    const GAUGE: usize = 10;
+
+    /// The number of rounds the bankroll trend under the status bar goes back.
+    const TREND: usize = 40;
+
+    /// Creates a table view that shows amounts of chips with `currency`, in the default theme and
+    /// in English.
+    pub fn new(currency: CurrencyFormat) -> Self {
+        LayoutTableView {
+            currency,
+            theme: Theme::default(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Returns a rule across the table drawn with `fill`, with `title` in the middle.
+    fn rule(&self, fill: char, title: &str) -> String {
+        let space = LayoutTableView::WIDTH.saturating_sub(title.chars().count());
+        let left = fill.to_string().repeat(space / 2);
+        let right = fill.to_string().repeat(space - space / 2);
+        Theme::paint(&format!("{}{}{}", left, title, right), &self.theme.felt)
+    }
+
+    /// Returns a rule across the table drawn with `fill`, with the text for `key` in the middle.
+    fn titled_rule(&self, fill: char, key: &str) -> String {
+        self.rule(fill, &format!(" {} ", self.locale.text(key)))
+    }
+
+    /// Returns what is said about a hand after its cards: its total and its bet.
+    fn detail(&self, player_hand: &PlayerHand) -> String {
+        self.locale.message(
+            "table-hand-detail",
+            &[
+                ("total", &self.locale.value(player_hand.hand.value())),
+                ("bet", &self.currency.draw(player_hand.wager.total())),
+            ],
+        )
+    }
+
+    /// Writes one spot at the table: the name, the cards and what is said about them.
+    fn spot(
+        name: &str,
+        hand: &Hand,
+        detail: &str,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        write!(out, "  {:<14}", name)?;
+        cards.render(hand, out)?;
+        writeln!(out, "  ({})", detail)
+    }
+}
+
+impl TableView for LayoutTableView {
+    fn render(
+        &self,
+        table: &TableFrame,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let border = self.theme.border;
+        let locale = &self.locale;
+        writeln!(out, "{}", self.titled_rule(border.heavy(), "table-dealer"))?;
+        let dealer = if table.dealer_hand.has_face_down() {
+            locale.message(
+                "table-showing",
+                &[(
+                    "total",
+                    &self.locale.value(table.dealer_hand.visible_value()),
+                )],
+            )
+        } else {
+            self.locale.value(table.dealer_hand.value())
+        };
+        LayoutTableView::spot(
+            &locale.text("table-dealer"),
+            table.dealer_hand,
+            &dealer,
+            cards,
+            out,
+        )?;
+
+        writeln!(
+            out,
+            "{}",
+            self.titled_rule(border.horizontal(), "table-players")
+        )?;
+        let mut in_play = table.dealer_hand.len();
+        for player_hand in table.player_hands {
+            let name = match table.player_hands.len() {
+                1 => locale.text("table-you"),
+                _ => locale.message("table-you-hand", &[("hand", &player_hand.id)]),
+            };
+            let detail = self.detail(player_hand);
+            LayoutTableView::spot(&name, &player_hand.hand, &detail, cards, out)?;
+            in_play += player_hand.hand.len();
+        }
+        for opponent in &table.opponents {
+            for player_hand in &opponent.hands {
+                let detail = self.detail(player_hand);
+                LayoutTableView::spot(opponent.name, &player_hand.hand, &detail, cards, out)?;
+                in_play += player_hand.hand.len();
+            }
+        }
+
+        let size = table.decks * 52;
+        let discards = size.saturating_sub(table.cards_remaining + in_play);
+        let full = (table.cards_remaining * LayoutTableView::GAUGE)
+            .checked_div(size)
+            .unwrap_or(0);
+        let gauge = format!(
+            "{}{}",
+            "#".repeat(full),
+            ".".repeat(LayoutTableView::GAUGE - full)
+        );
+        writeln!(out, "{}", self.rule(border.horizontal(), ""))?;
+        writeln!(
+            out,
+            "{}",
+            locale.message(
+                "table-status",
+                &[
+                    ("gauge", &gauge),
+                    ("remaining", &table.cards_remaining),
+                    ("size", &size),
+                    ("discards", &discards),
+                    ("bankroll", &self.currency.draw(table.bankroll)),
+                ],
+            )
+        )?;
+        if let Some(count) = table.count(locale) {
+            writeln!(out, "{}", count)?;
+        }
+        let trend = sparkline(table.bankroll_history, LayoutTableView::TREND);
+        if !trend.is_empty() {
+            writeln!(
+                out,
+                "{}",
+                locale.message("table-trend", &[("trend", &trend)])
+            )?;
+        }
+        writeln!(out, "{}", self.rule(border.heavy(), ""))
+    }
+
+    fn redraws(&self) -> bool {
+        true
+    }
+}
+
+/// A table view for screen readers, which says what is on the table in full sentences, such as
+/// "Dealer shows Queen of Spades. Your hand: Ace of Hearts, Seven of Clubs, soft eighteen, bet $10."
+///
+/// Totals are written in words, and nothing is drawn with rules or columns, so the table reads
+/// well aloud; it is best paired with a `Renderer` that names the cards in words. The sentences
+/// are those of the `locale`, and the count is said last while it is shown. The table is only said
+/// once the cards are dealt, and not again after every card.
+pub struct SpokenTableView {
+    pub currency: CurrencyFormat,
+    pub locale: Locale,
+}
+
+impl SpokenTableView {
+    /// Creates a table view that says amounts of chips with `currency`, in English.
+    pub fn new(currency: CurrencyFormat) -> Self {
+        SpokenTableView {
+            currency,
+            locale: Locale::default(),
+        }
+    }
+
+    /// Says one hand at the table: whose it is, its cards, its total and its bet.
+    fn hand(
+        &self,
+        owner: &str,
+        player_hand: &PlayerHand,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let sentence = self.locale.message(
+            "spoken-hand",
+            &[
+                ("owner", &owner),
+                ("cards", &draw_cards(&player_hand.hand, cards)?),
+                (
+                    "total",
+                    &self.locale.value_in_words(player_hand.hand.value()),
+                ),
+                ("bet", &self.currency.draw(player_hand.wager.total())),
+            ],
+        );
+        writeln!(out, "{}", sentence)
+    }
+}
+
+impl TableView for SpokenTableView {
+    fn render(
+        &self,
+        table: &TableFrame,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let locale = &self.locale;
+        let dealer = table.dealer_hand;
+        let sentence = if dealer.has_face_down() {
+            // Only the face-up cards are said, as a player at the table would see them
+            let mut showing = Hand::new();
+            for (index, card) in dealer.iter().enumerate() {
+                if !dealer.is_face_down(index) {
+                    showing.add(*card);
+                }
+            }
+            let drawn = draw_cards(&showing, cards)?;
+            if showing.len() > 1 {
+                locale.message(
+                    "spoken-dealer-shows-total",
+                    &[
+                        ("cards", &drawn),
+                        ("total", &locale.value_in_words(showing.value())),
+                    ],
+                )
+            } else {
+                locale.message("spoken-dealer-shows", &[("cards", &drawn)])
+            }
+        } else {
+            locale.message(
+                "spoken-dealer-hand",
+                &[
+                    ("cards", &draw_cards(dealer, cards)?),
+                    ("total", &locale.value_in_words(dealer.value())),
+                ],
+            )
+        };
+        writeln!(out, "{}", sentence)?;
+
+        for player_hand in table.player_hands {
+            let owner = match table.player_hands.len() {
+                1 => locale.text("spoken-your-hand"),
+                count => locale.message(
+                    "spoken-your-hand-of",
+                    &[("hand", &player_hand.id), ("hands", &count)],
+                ),
+            };
+            self.hand(&owner, player_hand, cards, out)?;
+        }
+        for opponent in &table.opponents {
+            for player_hand in &opponent.hands {
+                let owner = locale.message("spoken-opponent-hand", &[("name", &opponent.name)]);
+                self.hand(&owner, player_hand, cards, out)?;
+            }
+        }
+
+        writeln!(
+            out,
+            "{}",
+            locale.message(
+                "spoken-bankroll",
+                &[
+                    ("bankroll", &self.currency.draw(table.bankroll)),
+                    ("remaining", &table.cards_remaining),
+                    ("size", &(table.decks * 52)),
+                ],
+            )
+        )?;
+        if let Some(count) = table.count(locale) {
+            writeln!(out, "{}.", count)?;
+        }
+        Ok(())
+    }
+}
+
+/// A table view for narrow terminals and tmux panes, which says the whole table on one terse line,
+/// drawn again after every card and decision, such as
+/// `You: A♥ 7♣ (18s) | Dealer: Q♠ ?? | Bank: 480 | Bet: 25`.
+///
+/// Whatever the `Renderer`, each card is written as its rank and the `theme`'s symbol for its suit,
+/// in the theme's colours, and face-down cards with the theme's card back. A soft total is marked with an "s", the
+/// dealer's total is only given once the hole card is turned over, split hands are separated by
+/// " / ", and the bet is the total riding on them. The bankroll is followed by a short sparkline of
+/// its last few rounds, and the line ends with the running and true counts while they are shown.
+/// The words are those of the `locale`.
+///
+/// If `row` is set, the line is kept on that row of the terminal, its last, and updated in place,
+/// while the rows above scroll as usual; the terminal is given back when the view is dropped.
+/// Otherwise a line is written for every frame.
+pub struct StatusTableView {
+    pub theme: Theme,
+    pub locale: Locale,
+    pub row: Option<u16>,
+}
+
+impl StatusTableView {
+    /// The number of rounds the sparkline after the bankroll goes back.
+    const TREND: usize = 12;
+
+    /// Returns the cards of `hand`, followed by its total if `total` is true.
+    fn hand(&self, hand: &Hand, total: bool) -> String {
+        let mut cards: Vec<String> = hand
+            .iter()
+            .enumerate()
+            .map(|(index, card)| {
+                if hand.is_face_down(index) {
+                    self.theme.back.text(false)
+                } else {
+                    let text = format!(
+                        "{}{}",
+                        card.rank.symbol(),
+                        self.theme.suits.symbol(card.suit)
+                    );
+                    Theme::paint(&text, self.theme.suit_color(card.suit))
+                }
+            })
+            .collect();
+        if total {
+            let value = hand.value();
+            cards.push(format!(
+                "({}{})",
+                value.total,
+                if value.soft { "s" } else { "" }
+            ));
+        }
+        cards.join(" ")
+    }
+}
+
+impl TableView for StatusTableView {
+    fn render(
+        &self,
+        table: &TableFrame,
+        _cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let you = table
+            .player_hands
+            .iter()
+            .map(|player_hand| self.hand(&player_hand.hand, !player_hand.hand.is_empty()))
+            .collect::<Vec<String>>()
+            .join(" / ");
+        let bet: u32 = table
+            .player_hands
+            .iter()
+            .map(|player_hand| player_hand.wager.total())
+            .sum();
+        let trend = sparkline(table.bankroll_history, StatusTableView::TREND);
+        let bankroll = if trend.is_empty() {
+            table.bankroll.to_string()
+        } else {
+            format!("{} {}", table.bankroll, trend)
+        };
+        let mut line = self.locale.message(
+            "status-line",
+            &[
+                ("you", &you),
+                (
+                    "dealer",
+                    &self.hand(
+                        table.dealer_hand,
+                        !table.dealer_hand.is_empty() && !table.dealer_hand.has_face_down(),
+                    ),
+                ),
+                ("bankroll", &bankroll),
+                ("bet", &bet),
+            ],
+        );
+        if let Some(counter) = &table.count {
+            line.push_str(" | ");
+            line.push_str(&self.locale.message(
+                "status-count",
+                &[
+                    ("running", &format!("{:+}", counter.running_count())),
+                    ("true", &format!("{:+.1}", counter.true_count())),
+                ],
+            ));
+            if counter.ace_side_count {
+                line.push(' ');
+                line.push_str(&self.locale.message(
+                    "status-aces",
+                    &[("aces", &counter.aces_remaining().to_string())],
+                ));
+            }
+        }
+        match self.row {
+            // The rows above the line scroll, with the cursor kept among them; the new line moves
+            // the cursor off the last row if it is there
+            Some(row) => write!(
+                out,
+                "\n\x1b[1A\x1b[s\x1b[1;{}r\x1b[{};1H\x1b[2K{}\x1b[u",
+                row.saturating_sub(1),
+                row,
+                line
+            ),
+            None => writeln!(out, "{}", line),
+        }
+    }
+
+    fn redraws(&self) -> bool {
+        true
+    }
+}
+
+impl Drop for StatusTableView {
+    /// Lets the whole terminal scroll again if the line was kept on a row of its own.
+    fn drop(&mut self) {
+        if self.row.is_some() {
+            let mut stdout = io::stdout();
+            let _ = write!(stdout, "\x1b[s\x1b[r\x1b[u");
+            let _ = stdout.flush();
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use crate::card::Suit;
+use crate::prelude::*;
+
+/// The ways the edges of card boxes and the rules across the table are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Border {
+    /// `+`, `-` and `|`, which every terminal can show.
+    Ascii,
+    /// Thin box-drawing lines with square corners.
+    Light,
+    /// Thin box-drawing lines with rounded corners.
+    Rounded,
+    /// Thick box-drawing lines.
+    Heavy,
+    /// Double box-drawing lines.
+    Double,
+}
+
+impl Border {
+    /// The names of the borders, as written in the config file.
+    pub const NAMES: [&'static str; 5] = ["ascii", "light", "rounded", "heavy", "double"];
+
+    /// Returns the border called `name`, one of `NAMES`.
+    pub fn from_name(name: &str) -> Option<Border> {
+        match name {
+            "ascii" => Some(Border::Ascii),
+            "light" => Some(Border::Light),
+            "rounded" => Some(Border::Rounded),
+            "heavy" => Some(Border::Heavy),
+            "double" => Some(Border::Double),
+            _ => None,
+        }
+    }
+
+    /// Returns the top-left, top-right, bottom-left and bottom-right corners of a box.
+    pub fn corners(self) -> [char; 4] {
+        match self {
+            Border::Ascii => ['+', '+', '+', '+'],
+            Border::Light => ['┌', '┐', '└', '┘'],
+            Border::Rounded => ['╭', '╮', '╰', '╯'],
+            Border::Heavy => ['┏', '┓', '┗', '┛'],
+            Border::Double => ['╔', '╗', '╚', '╝'],
+        }
+    }
+
+    /// Returns the line along the top and bottom of a box, which also divides the table.
+    pub fn horizontal(self) -> char {
+        match self {
+            Border::Ascii => '-',
+            Border::Light | Border::Rounded => '─',
+            Border::Heavy => '━',
+            Border::Double => '═',
+        }
+    }
+
+    /// Returns the line down the sides of a box.
+    pub fn vertical(self) -> char {
+        match self {
+            Border::Ascii => '|',
+            Border::Light | Border::Rounded => '│',
+            Border::Heavy => '┃',
+            Border::Double => '║',
+        }
+    }
+
+    /// Returns the heavier line drawn above and below the whole table.
+    pub fn heavy(self) -> char {
+        match self {
+            Border::Ascii => '=',
+            Border::Light | Border::Rounded | Border::Double => '═',
+            Border::Heavy => '━',
+        }
+    }
+}
+
+/// The symbols suits are drawn with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuitSymbols {
+    /// Solid symbols: ♠, ♥, ♦ and ♣.
+    Filled,
+    /// Hollow symbols for the red suits and solid ones for the black, as on cards printed in one
+    /// colour: ♠, ♡, ♢ and ♣.
+    Mixed,
+    /// The suits' initials: S, H, D and C.
+    Letters,
+    /// Each suit's solid symbol followed by its initial, so suits can be told apart by shape and
+    /// letter as well as colour: ♠S, ♥H, ♦D and ♣C.
+    Tagged,
+}
+
+impl SuitSymbols {
+    /// The names of the suit symbols, as written in the config file.
+    pub const NAMES: [&'static str; 4] = ["filled", "mixed", "letters", "tagged"];
+
+    /// Returns the suit symbols called `name`, one of `NAMES`.
+    pub fn from_name(name: &str) -> Option<SuitSymbols> {
+        match name {
+            "filled" => Some(SuitSymbols::Filled),
+            "mixed" => Some(SuitSymbols::Mixed),
+            "letters" => Some(SuitSymbols::Letters),
+            "tagged" => Some(SuitSymbols::Tagged),
+            _ => None,
+        }
+    }
+
+    /// Returns the symbol for `suit`.
+    pub fn symbol(self, suit: Suit) -> &'static str {
+        match (self, suit) {
+            (SuitSymbols::Letters, suit) => suit.letter(),
+            (SuitSymbols::Tagged, Suit::Spades) => "♠S",
+            (SuitSymbols::Tagged, Suit::Hearts) => "♥H",
+            (SuitSymbols::Tagged, Suit::Diamonds) => "♦D",
+            (SuitSymbols::Tagged, Suit::Clubs) => "♣C",
+            (SuitSymbols::Mixed, Suit::Hearts) => "♡",
+            (SuitSymbols::Mixed, Suit::Diamonds) => "♢",
+            (_, suit) => suit.symbol(),
+        }
+    }
+}
+
+/// The patterns on the backs of face-down cards, each with an ASCII form for terminals and fonts
+/// that cannot show anything else and a Unicode form for those that can.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardBack {
+    /// Question marks on a single line, `??`, and a solid back of `#` in a box.
+    Plain,
+    /// Diagonal hatching: `//` or `▒▒`.
+    Hatched,
+    /// A criss-cross lattice: `XX` or `╳╳`.
+    Lattice,
+    /// A fine dotted pattern: `::` or `░░`.
+    Dotted,
+    /// Rows of diamonds, offset on each line of a box: `<>` or `◆◇`.
+    Diamonds,
+}
+
+impl CardBack {
+    /// The names of the card backs, as written in the config file.
+    pub const NAMES: [&'static str; 5] = ["plain", "hatched", "lattice", "dotted", "diamonds"];
+
+    /// Returns the card back called `name`, one of `NAMES`.
+    pub fn from_name(name: &str) -> Option<CardBack> {
+        match name {
+            "plain" => Some(CardBack::Plain),
+            "hatched" => Some(CardBack::Hatched),
+            "lattice" => Some(CardBack::Lattice),
+            "dotted" => Some(CardBack::Dotted),
+            "diamonds" => Some(CardBack::Diamonds),
+            _ => None,
+        }
+    }
+
+    /// Returns the characters the pattern repeats, in ASCII if `ascii` is true.
+    fn pattern(self, ascii: bool) -> &'static str {
+        match (self, ascii) {
+            (CardBack::Plain, _) => "#",
+            (CardBack::Hatched, true) => "/",
+            (CardBack::Hatched, false) => "▒",
+            (CardBack::Lattice, true) => "X",
+            (CardBack::Lattice, false) => "╳",
+            (CardBack::Dotted, true) => ":",
+            (CardBack::Dotted, false) => "░",
+            (CardBack::Diamonds, true) => "<>",
+            (CardBack::Diamonds, false) => "◆◇",
+        }
+    }
+
+    /// Returns the back as it is written among cards on a single line, two characters wide, in
+    /// ASCII if `ascii` is true.
+    pub fn text(self, ascii: bool) -> String {
+        match self {
+            CardBack::Plain => "??".to_string(),
+            back => back.row(ascii, 2, 0),
+        }
+    }
+
+    /// Returns line `line` of the back drawn `width` characters wide inside a box, in ASCII if
+    /// `ascii` is true. The pattern starts one character further along on each line.
+    pub fn row(self, ascii: bool, width: usize, line: usize) -> String {
+        self.pattern(ascii)
+            .chars()
+            .cycle()
+            .skip(line)
+            .take(width)
+            .collect()
+    }
+}
+
+/// The look of the game: the colours it writes, the borders it draws and the symbols of the suits.
+///
+/// Each colour is the parameter of an ANSI "select graphic rendition" code, such as "31" for red or
+/// "1;33" for bold yellow, or empty to leave the terminal's own colour; `color_code()` reads one
+/// from a name such as "bold bright-red". `felt` colours the borders of cards and the rules across
+/// the table, and `back` is the pattern face-down cards are drawn with. Colours are only written where colours are on, so output that is not coloured uses
+/// the theme `without_colors()`.
+///
+/// The default theme is the game's own look, and `named()` gives it and the other built-in themes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    pub red_suits: String,
+    pub black_suits: String,
+    pub win: String,
+    pub blackjack: String,
+    pub bust: String,
+    pub felt: String,
+    pub border: Border,
+    pub suits: SuitSymbols,
+    pub back: CardBack,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            red_suits: "31".to_string(),
+            black_suits: String::new(),
+            win: "32".to_string(),
+            blackjack: "1;33".to_string(),
+            bust: "31".to_string(),
+            felt: String::new(),
+            border: Border::Ascii,
+            suits: SuitSymbols::Filled,
+            back: CardBack::Plain,
+        }
+    }
+}
+
+/// The names of the colours `color_code()` reads, in the order of their ANSI codes.
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+impl Theme {
+    /// The names of the built-in themes.
+    pub const NAMES: [&'static str; 4] = ["default", "green-felt", "high-contrast", "monochrome"];
+
+    /// Returns the built-in theme called `name`, one of `NAMES`:
+    /// - `default`: the game's own look, with red suits and results in colour.
+    /// - `green-felt`: a casino table, with green borders and rounded cards.
+    /// - `high-contrast`: bold, bright colours and heavy borders, for dim screens and tired eyes.
+    /// - `monochrome`: no colours, with hollow symbols for the red suits so they can still be told
+    ///   apart.
+    pub fn named(name: &str) -> Option<Theme> {
+        match name {
+            "default" => Some(Theme::default()),
+            "green-felt" => Some(Theme {
+                felt: "32".to_string(),
+                border: Border::Rounded,
+                ..Theme::default()
+            }),
+            "high-contrast" => Some(Theme {
+                red_suits: "1;91".to_string(),
+                black_suits: "1;97".to_string(),
+                win: "1;92".to_string(),
+                blackjack: "1;93".to_string(),
+                bust: "1;91".to_string(),
+                felt: "1;97".to_string(),
+                border: Border::Heavy,
+                suits: SuitSymbols::Filled,
+                back: CardBack::Plain,
+            }),
+            "monochrome" => Some(Theme {
+                suits: SuitSymbols::Mixed,
+                ..Theme::default().without_colors()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns the ANSI code for a colour named like "red", "bright-cyan" or "bold yellow", or an
+    /// empty code for "none". Returns `None` if the name is not a colour.
+    pub fn color_code(name: &str) -> Option<String> {
+        let mut codes = Vec::new();
+        for word in name.split_whitespace() {
+            let (bright, color) = match word.strip_prefix("bright-") {
+                Some(color) => (true, color),
+                None => (false, word),
+            };
+            if word == "bold" {
+                codes.push("1".to_string());
+            } else if word == "none" {
+                continue;
+            } else {
+                let index = COLOR_NAMES.iter().position(|&other| other == color)?;
+                let base = if bright { 90 } else { 30 };
+                codes.push((base + index).to_string());
+            }
+        }
+        Some(codes.join(";"))
+    }
+
+    /// Returns `text` written in the colour with ANSI code `color`, or as it is if the code is
+    /// empty.
+    pub fn paint(text: &str, color: &str) -> String {
+        if color.is_empty() {
+            text.to_string()
+        } else {
+            format!("\x1b[{}m{}\x1b[0m", color, text)
+        }
+    }
+
+    /// Returns the colour for cards of `suit`.
+    pub fn suit_color(&self, suit: Suit) -> &str {
+        if suit.is_red() {
+            &self.red_suits
+        } else {
+            &self.black_suits
+        }
+    }
+
+    /// Returns the theme made easier to read without telling red from black: the suits are tagged
+    /// with their initials, and the red suits are drawn in bold so they stand out by brightness
+    /// as well as colour.
+    pub fn colorblind(self) -> Theme {
+        let red_suits = if self.red_suits.is_empty() {
+            "1".to_string()
+        } else {
+            format!("1;{}", self.red_suits)
+        };
+        Theme {
+            red_suits,
+            suits: SuitSymbols::Tagged,
+            ..self
+        }
+    }
+
+    /// Returns the theme with every colour taken out, keeping its borders, suit symbols and card
+    /// back.
+    pub fn without_colors(self) -> Theme {
+        Theme {
+            red_suits: String::new(),
+            black_suits: String::new(),
+            win: String::new(),
+            blackjack: String::new(),
+            bust: String::new(),
+            felt: String::new(),
+            ..self
+        }
+    }
+}
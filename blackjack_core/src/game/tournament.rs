@@ -0,0 +1,186 @@
+use std::io::Write;
+
+use super::{
+    Bankroll, BuildError, CurrencyFormat, DealerMimic, GameBuilder, GameController, Seat,
+    TableLimits,
+};
+use crate::error::BlackjackError;
+use crate::{GameEngine, Renderer};
+
+/// The chips every entrant starts each tournament round with.
+pub const TOURNAMENT_BANKROLL: u32 = 1000;
+
+/// Names given to the computer-controlled opponents.
+pub const OPPONENT_NAMES: [&str; 12] = [
+    "Ava", "Ben", "Chloe", "Dmitri", "Elena", "Farid", "Grace", "Hiro", "Isla", "Jonas", "Keiko",
+    "Luis",
+];
+
+/// A controller that runs an elimination tournament against computer-controlled opponents.
+///
+/// Each tournament round is played at a fresh table where every entrant starts with the same bankroll
+/// and must bet at least the table minimum on every hand. After a fixed number of hands, the entrants
+/// with the most chips advance to the next round and the rest are eliminated. The player wins the
+/// tournament by finishing on top of the final round.
+///
+/// The `TournamentController` drives a `GameController` for the hands themselves, and its `run()`
+/// method plays one tournament round, returning whether the player goes on to another one.
+pub struct TournamentController {
+    pub game: GameController,
+    pub round: u32,
+    pub rounds: u32,
+    pub hands_per_round: u32,
+    pub opponents_per_table: usize,
+    pub advancing: usize,
+}
+
+impl TournamentController {
+    /// Creates a tournament of `rounds` rounds, seating the player at a classic blackjack table.
+    pub fn new(
+        viewer: Box<dyn Renderer>,
+        currency: CurrencyFormat,
+        rounds: u32,
+    ) -> Result<Self, BuildError> {
+        let game = GameBuilder::new(viewer)
+            .limits(TableLimits {
+                minimum: 25,
+                maximum: 500,
+            })
+            .bankroll(TOURNAMENT_BANKROLL)
+            .currency(currency)
+            .build()?;
+        Ok(TournamentController {
+            game,
+            round: 1,
+            rounds,
+            hands_per_round: 10,
+            opponents_per_table: 4,
+            advancing: 2,
+        })
+    }
+
+    /// Seats a fresh table of opponents and resets every entrant to the tournament bankroll.
+    pub fn seat_table(&mut self) {
+        let engine = &mut self.game.engine;
+        let mut names = OPPONENT_NAMES.to_vec();
+        engine.entropy_mut().shuffle(&mut names);
+        let opponents = names
+            .into_iter()
+            .take(self.opponents_per_table)
+            .map(|name| {
+                let strategy = Box::new(DealerMimic::new(engine.entropy_mut().next_u64()));
+                Seat::new(name, TOURNAMENT_BANKROLL, Some(strategy))
+            })
+            .collect();
+        engine.seat_opponents(opponents);
+        *engine.bankroll_mut() = Bankroll::new(TOURNAMENT_BANKROLL);
+        self.game.last_bet = None;
+    }
+
+    /// Shows the chip counts at the end of a round, highest first, and returns the player's place.
+    pub fn show_standings(&mut self) -> Result<usize, BlackjackError> {
+        let table = self.game.engine.table();
+        let player = table.player;
+        let mut standings: Vec<(usize, u32)> = table
+            .seats
+            .iter()
+            .map(|seat| seat.bankroll.balance())
+            .enumerate()
+            .collect();
+        standings.sort_by_key(|(_, chips)| std::cmp::Reverse(*chips));
+
+        let locale = &self.game.locale;
+        writeln!(
+            self.game.output,
+            "{}",
+            locale.message("standings", &[("round", &self.round)])
+        )?;
+        for (place, (seat, chips)) in standings.iter().enumerate() {
+            let name = if *seat == player {
+                locale.text("you")
+            } else {
+                self.game.engine.table().seats[*seat].name.to_string()
+            };
+            let status = if place < self.advancing {
+                locale.text("standing-advances")
+            } else {
+                locale.text("standing-eliminated")
+            };
+            let line = locale.message(
+                "standing",
+                &[
+                    ("place", &(place + 1)),
+                    ("name", &name),
+                    ("amount", &self.game.currency.draw(*chips)),
+                    ("status", &status),
+                ],
+            );
+            writeln!(self.game.output, "  {}", line)?;
+        }
+
+        // The player is always in the standings, so the fallback place is never used
+        Ok(standings
+            .iter()
+            .position(|(seat, _)| *seat == player)
+            .map_or(standings.len(), |index| index + 1))
+    }
+}
+
+impl GameEngine for TournamentController {
+    fn run(&mut self) -> Result<bool, BlackjackError> {
+        if self.round == 1 {
+            self.seat_table();
+        }
+        let message = self.game.locale.message(
+            "tournament-round",
+            &[
+                ("round", &self.round),
+                ("rounds", &self.rounds),
+                ("hands", &self.hands_per_round),
+                ("advancing", &self.advancing),
+            ],
+        );
+        writeln!(self.game.output, "{}", message)?;
+
+        for hand in 1..=self.hands_per_round {
+            if !self.game.can_cover_minimum() {
+                let message = self.game.locale.text("tournament-sit-out");
+                writeln!(self.game.output, "{}", message)?;
+                break;
+            }
+            let message = self.game.locale.message(
+                "tournament-hand",
+                &[("hand", &hand), ("hands", &self.hands_per_round)],
+            );
+            writeln!(self.game.output, "{}", message)?;
+            self.game.play_round()?;
+            if self.game.quit_requested {
+                let message = self.game.locale.text("tournament-leave");
+                writeln!(self.game.output, "{}", message)?;
+                return Ok(false);
+            }
+        }
+
+        let place = self.show_standings()?;
+        let locale = &self.game.locale;
+        if place > self.advancing {
+            let message = locale.message("tournament-eliminated", &[("place", &place)]);
+            writeln!(self.game.output, "{}", message)?;
+            Ok(false)
+        } else if self.round == self.rounds {
+            let message = if place == 1 {
+                locale.text("tournament-won")
+            } else {
+                locale.message("tournament-finished", &[("place", &place)])
+            };
+            writeln!(self.game.output, "{}", message)?;
+            Ok(false)
+        } else {
+            let message = locale.message("tournament-advance", &[("round", &(self.round + 1))]);
+            writeln!(self.game.output, "{}", message)?;
+            self.round += 1;
+            self.seat_table();
+            Ok(true)
+        }
+    }
+}
@@ -0,0 +1,482 @@
+use super::{DealerPolicy, RuleSet};
+use crate::card::Suit;
+use crate::hand::Hand;
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A payout ratio for a winning wager, such as 3:2 for a classic blackjack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Payout(pub u32, pub u32);
+
+impl Payout {
+    pub const EVEN_MONEY: Payout = Payout(1, 1);
+    pub const THREE_TO_TWO: Payout = Payout(3, 2);
+    pub const TWO_TO_ONE: Payout = Payout(2, 1);
+
+    /// Returns the winnings on a wager of `amount` chips, rounded down to a whole chip, or as many
+    /// chips as a `u32` holds if they come to more.
+    pub fn winnings(&self, amount: u32) -> u32 {
+        let winnings = amount as u64 * self.0 as u64 / self.1 as u64;
+        u32::try_from(winnings).unwrap_or(u32::MAX)
+    }
+}
+
+impl core::fmt::Display for Payout {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}:{}", self.0, self.1)
+    }
+}
+
+/// The result of a single player hand once the dealer has finished drawing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    /// The player wins and is paid at the given ratio.
+    Win(Payout),
+    /// The hand is a tie and the wager is returned.
+    Push,
+    /// The dealer wins.
+    Lose,
+    /// The player gave up the hand and gets half the stake back.
+    Surrender,
+}
+
+/// The `Variant` trait defines the rules that differ between flavors of blackjack.
+///
+/// The `Variant` trait provides the following methods:
+/// - `name(&self) -> &'static str`: Returns the display name of the variant.
+/// - `hands_per_player(&self) -> usize`: Returns how many hands the player is dealt each round.
+/// - `allows_switch(&self) -> bool`: Returns whether the player may swap the second cards of their hands.
+/// - `dealer_cards_face_up(&self) -> bool`: Returns whether both dealer cards are dealt face-up.
+/// - `free_double(&self, hand: &Hand) -> bool`: Returns whether doubling the given hand is paid for by the house.
+/// - `free_split(&self, hand: &Hand) -> bool`: Returns whether splitting the given pair is paid for by the house.
+/// - `dealer_policy(&self, rules: &RuleSet) -> Box<dyn DealerPolicy>`: Returns when the dealer draws,
+///   which by default is the policy the table's rules post.
+/// - `resolve(&self, player: &Hand, dealer: &Hand, rules: &RuleSet) -> Outcome`: Settles a player hand
+///   against the dealer under the table's rules.
+///
+/// This trait allows the `GameController` to play any variant without knowing its specific rules.
+pub trait Variant {
+    fn name(&self) -> &'static str;
+
+    fn hands_per_player(&self) -> usize {
+        1
+    }
+
+    fn allows_switch(&self) -> bool {
+        false
+    }
+
+    fn dealer_cards_face_up(&self) -> bool {
+        false
+    }
+
+    fn free_double(&self, _hand: &Hand) -> bool {
+        false
+    }
+
+    fn free_split(&self, _hand: &Hand) -> bool {
+        false
+    }
+
+    fn dealer_policy(&self, rules: &RuleSet) -> Box<dyn DealerPolicy> {
+        rules.dealer_policy()
+    }
+
+    fn resolve(&self, player: &Hand, dealer: &Hand, rules: &RuleSet) -> Outcome;
+}
+
+/// Settles a hand using the standard comparison rules, paying naturals at `blackjack_pays`.
+pub fn standard_resolve(player: &Hand, dealer: &Hand, blackjack_pays: Payout) -> Outcome {
+    let player_total = player.calculate_hand_total();
+    let dealer_total = dealer.calculate_hand_total();
+
+    if player.is_bust() {
+        Outcome::Lose
+    } else if player.is_blackjack() && !dealer.is_blackjack() {
+        Outcome::Win(blackjack_pays)
+    } else if dealer.is_blackjack() && !player.is_blackjack() {
+        Outcome::Lose
+    } else if dealer.is_bust() || player_total > dealer_total {
+        Outcome::Win(Payout::EVEN_MONEY)
+    } else if player_total < dealer_total {
+        Outcome::Lose
+    } else {
+        Outcome::Push
+    }
+}
+
+/// Settles a hand where a dealer total of exactly 22 pushes every standing hand except a natural.
+pub fn push_22_resolve(player: &Hand, dealer: &Hand, blackjack_pays: Payout) -> Outcome {
+    let dealer_total = dealer.calculate_hand_total();
+
+    if !player.is_bust() && dealer_total == 22 && !player.is_blackjack() {
+        Outcome::Push
+    } else {
+        standard_resolve(player, dealer, blackjack_pays)
+    }
+}
+
+/// Classic single-hand blackjack where naturals pay as the table rules set, usually 3:2.
+pub struct Classic;
+
+impl Variant for Classic {
+    fn name(&self) -> &'static str {
+        "Classic"
+    }
+
+    fn resolve(&self, player: &Hand, dealer: &Hand, rules: &RuleSet) -> Outcome {
+        standard_resolve(player, dealer, rules.blackjack_pays)
+    }
+}
+
+/// Blackjack Switch, where the player plays two hands and may swap their second cards.
+///
+/// In exchange for the switch, naturals only pay even money and a dealer total of
+/// exactly 22 pushes every hand that is still standing, except a player blackjack. A 21 made by
+/// switching is an ordinary 21, not a natural.
+pub struct BlackjackSwitch;
+
+impl Variant for BlackjackSwitch {
+    fn name(&self) -> &'static str {
+        "Blackjack Switch"
+    }
+
+    fn hands_per_player(&self) -> usize {
+        2
+    }
+
+    fn allows_switch(&self) -> bool {
+        true
+    }
+
+    fn resolve(&self, player: &Hand, dealer: &Hand, _rules: &RuleSet) -> Outcome {
+        push_22_resolve(player, dealer, Payout::EVEN_MONEY)
+    }
+}
+
+/// Double Exposure, where both of the dealer's cards are dealt face-up.
+///
+/// To pay for the extra information, the dealer wins every tie except a tie between
+/// naturals, which goes to the player, and naturals only pay even money.
+pub struct DoubleExposure;
+
+impl Variant for DoubleExposure {
+    fn name(&self) -> &'static str {
+        "Double Exposure"
+    }
+
+    fn dealer_cards_face_up(&self) -> bool {
+        true
+    }
+
+    fn resolve(&self, player: &Hand, dealer: &Hand, _rules: &RuleSet) -> Outcome {
+        let player_total = player.calculate_hand_total();
+        let dealer_total = dealer.calculate_hand_total();
+
+        if player.is_bust() {
+            Outcome::Lose
+        } else if player.is_blackjack() {
+            Outcome::Win(Payout::EVEN_MONEY)
+        } else if dealer.is_blackjack() {
+            Outcome::Lose
+        } else if dealer.is_bust() || player_total > dealer_total {
+            Outcome::Win(Payout::EVEN_MONEY)
+        } else {
+            Outcome::Lose
+        }
+    }
+}
+
+/// Free Bet Blackjack, where the house pays for doubles on hard 9, 10 and 11 and for splitting pairs.
+///
+/// Tens are not split for free, and paid doubles and splits are still allowed on any other hand.
+/// Naturals pay 3:2, but a dealer total of exactly 22 pushes every hand that is still standing.
+pub struct FreeBet;
+
+impl Variant for FreeBet {
+    fn name(&self) -> &'static str {
+        "Free Bet Blackjack"
+    }
+
+    fn free_double(&self, hand: &Hand) -> bool {
+        let value = hand.value();
+        hand.len() == 2 && !value.soft && (9..=11).contains(&value.total)
+    }
+
+    fn free_split(&self, hand: &Hand) -> bool {
+        hand.is_pair()
+            && hand
+                .cards()
+                .first()
+                .is_some_and(|card| card.rank.value() < 10)
+    }
+
+    fn resolve(&self, player: &Hand, dealer: &Hand, rules: &RuleSet) -> Outcome {
+        push_22_resolve(player, dealer, rules.blackjack_pays)
+    }
+}
+
+/// Super Fun 21, a player-friendly variant that makes up for paying most naturals at even money.
+///
+/// A player 21 always wins, even against a dealer blackjack, a hand of six or more cards totalling
+/// exactly 20 wins a 2:1 bonus, and a blackjack made of two diamonds pays 2:1.
+pub struct SuperFun21;
+
+impl Variant for SuperFun21 {
+    fn name(&self) -> &'static str {
+        "Super Fun 21"
+    }
+
+    fn resolve(&self, player: &Hand, dealer: &Hand, _rules: &RuleSet) -> Outcome {
+        let player_total = player.calculate_hand_total();
+        let all_diamonds = player.iter().all(|card| card.suit == Suit::Diamonds);
+
+        if player.is_blackjack() && all_diamonds {
+            Outcome::Win(Payout::TWO_TO_ONE)
+        } else if player_total == 21 {
+            Outcome::Win(Payout::EVEN_MONEY)
+        } else if player_total == 20 && player.len() >= 6 {
+            Outcome::Win(Payout::TWO_TO_ONE)
+        } else {
+            standard_resolve(player, dealer, Payout::EVEN_MONEY)
+        }
+    }
+}
+
+/// Creates the variant called `name`, as `Variant::name()` gives it, such as "Blackjack Switch", or
+/// returns `None` if no built-in variant has that name.
+pub fn variant_named(name: &str) -> Option<Box<dyn Variant>> {
+    let variants: [Box<dyn Variant>; 5] = [
+        Box::new(Classic),
+        Box::new(BlackjackSwitch),
+        Box::new(DoubleExposure),
+        Box::new(FreeBet),
+        Box::new(SuperFun21),
+    ];
+    variants.into_iter().find(|variant| variant.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Card, Rank};
+
+    /// Returns a hand holding cards of the given ranks, all in Spades.
+    fn hand(ranks: &[Rank]) -> Hand {
+        let mut hand = Hand::new();
+        for &rank in ranks {
+            hand.add(Card {
+                rank,
+                suit: Suit::Spades,
+            });
+        }
+        hand
+    }
+
+    #[test]
+    fn switch_pushes_a_standing_hand_against_a_dealer_22() {
+        let outcome = BlackjackSwitch.resolve(
+            &hand(&[Rank::Ten, Rank::Nine]),
+            &hand(&[Rank::Ten, Rank::Six, Rank::Six]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Push);
+    }
+
+    #[test]
+    fn switch_pays_a_natural_even_money_even_against_a_dealer_22() {
+        let outcome = BlackjackSwitch.resolve(
+            &hand(&[Rank::Ace, Rank::King]),
+            &hand(&[Rank::Ten, Rank::Six, Rank::Six]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Win(Payout::EVEN_MONEY));
+    }
+
+    #[test]
+    fn switch_loses_a_bust_hand_against_a_dealer_22() {
+        let outcome = BlackjackSwitch.resolve(
+            &hand(&[Rank::Ten, Rank::Six, Rank::Eight]),
+            &hand(&[Rank::Ten, Rank::Six, Rank::Six]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Lose);
+    }
+
+    #[test]
+    fn switch_pays_even_money_when_the_dealer_busts_past_22() {
+        let outcome = BlackjackSwitch.resolve(
+            &hand(&[Rank::Ten, Rank::Eight]),
+            &hand(&[Rank::Ten, Rank::Six, Rank::Seven]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Win(Payout::EVEN_MONEY));
+    }
+
+    #[test]
+    fn a_21_made_by_switching_is_not_a_natural() {
+        let mut first = hand(&[Rank::Ace, Rank::Seven]);
+        let mut second = hand(&[Rank::Nine, Rank::King]);
+        first.swap_second_card(&mut second);
+
+        assert!(!first.is_blackjack());
+        assert_eq!(
+            BlackjackSwitch.resolve(
+                &first,
+                &hand(&[Rank::Ten, Rank::Six, Rank::Six]),
+                &RuleSet::default()
+            ),
+            Outcome::Push
+        );
+        assert_eq!(
+            BlackjackSwitch.resolve(&first, &hand(&[Rank::Ace, Rank::Ten]), &RuleSet::default()),
+            Outcome::Lose
+        );
+    }
+
+    #[test]
+    fn double_exposure_gives_the_dealer_every_tie_but_naturals() {
+        let outcome = DoubleExposure.resolve(
+            &hand(&[Rank::Ten, Rank::Eight]),
+            &hand(&[Rank::Nine, Rank::Nine]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Lose);
+        let outcome = DoubleExposure.resolve(
+            &hand(&[Rank::Ace, Rank::Queen]),
+            &hand(&[Rank::Ace, Rank::King]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Win(Payout::EVEN_MONEY));
+    }
+
+    #[test]
+    fn double_exposure_pays_a_natural_even_money() {
+        let outcome = DoubleExposure.resolve(
+            &hand(&[Rank::Ace, Rank::Ten]),
+            &hand(&[Rank::Ten, Rank::Nine]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Win(Payout::EVEN_MONEY));
+    }
+
+    #[test]
+    fn double_exposure_loses_a_bust_hand_even_when_the_dealer_busts() {
+        let outcome = DoubleExposure.resolve(
+            &hand(&[Rank::Ten, Rank::Six, Rank::Nine]),
+            &hand(&[Rank::Ten, Rank::Six, Rank::Eight]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Lose);
+    }
+
+    #[test]
+    fn free_bet_pushes_a_standing_hand_against_a_dealer_22() {
+        let outcome = FreeBet.resolve(
+            &hand(&[Rank::Ten, Rank::Eight]),
+            &hand(&[Rank::Ten, Rank::Six, Rank::Six]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Push);
+    }
+
+    #[test]
+    fn free_bet_pays_a_natural_3_to_2_against_a_dealer_22() {
+        let outcome = FreeBet.resolve(
+            &hand(&[Rank::Ace, Rank::Jack]),
+            &hand(&[Rank::Ten, Rank::Six, Rank::Six]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Win(Payout::THREE_TO_TWO));
+    }
+
+    #[test]
+    fn free_bet_doubles_hard_9_to_11_for_free() {
+        assert!(FreeBet.free_double(&hand(&[Rank::Five, Rank::Six])));
+        assert!(FreeBet.free_double(&hand(&[Rank::Four, Rank::Five])));
+        assert!(!FreeBet.free_double(&hand(&[Rank::Ace, Rank::Eight])));
+        assert!(!FreeBet.free_double(&hand(&[Rank::Six, Rank::Six])));
+        assert!(!FreeBet.free_double(&hand(&[Rank::Two, Rank::Three, Rank::Five])));
+    }
+
+    #[test]
+    fn free_bet_splits_pairs_below_ten_for_free() {
+        assert!(FreeBet.free_split(&hand(&[Rank::Eight, Rank::Eight])));
+        assert!(FreeBet.free_split(&hand(&[Rank::Ace, Rank::Ace])));
+        assert!(!FreeBet.free_split(&hand(&[Rank::Ten, Rank::Ten])));
+        assert!(!FreeBet.free_split(&hand(&[Rank::Queen, Rank::King])));
+        assert!(!FreeBet.free_split(&hand(&[Rank::Five, Rank::Six])));
+    }
+
+    #[test]
+    fn super_fun_21_pays_a_player_21_even_against_a_dealer_blackjack() {
+        let outcome = SuperFun21.resolve(
+            &hand(&[Rank::Seven, Rank::Seven, Rank::Seven]),
+            &hand(&[Rank::Ace, Rank::King]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Win(Payout::EVEN_MONEY));
+    }
+
+    #[test]
+    fn super_fun_21_pays_2_to_1_for_a_diamond_blackjack() {
+        let mut diamonds = Hand::new();
+        diamonds.add(Card {
+            rank: Rank::Ace,
+            suit: Suit::Diamonds,
+        });
+        diamonds.add(Card {
+            rank: Rank::King,
+            suit: Suit::Diamonds,
+        });
+        let dealer = hand(&[Rank::Ten, Rank::Eight]);
+
+        assert_eq!(
+            SuperFun21.resolve(&diamonds, &dealer, &RuleSet::default()),
+            Outcome::Win(Payout::TWO_TO_ONE)
+        );
+        assert_eq!(
+            SuperFun21.resolve(
+                &hand(&[Rank::Ace, Rank::King]),
+                &dealer,
+                &RuleSet::default()
+            ),
+            Outcome::Win(Payout::EVEN_MONEY)
+        );
+    }
+
+    #[test]
+    fn super_fun_21_pays_a_bonus_for_six_cards_totalling_20() {
+        let outcome = SuperFun21.resolve(
+            &hand(&[
+                Rank::Two,
+                Rank::Two,
+                Rank::Three,
+                Rank::Three,
+                Rank::Four,
+                Rank::Six,
+            ]),
+            &hand(&[Rank::Ten, Rank::Ten]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Win(Payout::TWO_TO_ONE));
+        let outcome = SuperFun21.resolve(
+            &hand(&[Rank::Two, Rank::Three, Rank::Three, Rank::Six, Rank::Six]),
+            &hand(&[Rank::Ten, Rank::Ten]),
+            &RuleSet::default(),
+        );
+        assert_eq!(outcome, Outcome::Push);
+    }
+
+    #[test]
+    fn winnings_round_down_to_a_whole_chip() {
+        assert_eq!(Payout::THREE_TO_TWO.winnings(5), 7);
+        assert_eq!(Payout::EVEN_MONEY.winnings(5), 5);
+    }
+
+    #[test]
+    fn winnings_too_large_for_a_u32_are_capped() {
+        assert_eq!(Payout(1000, 1).winnings(u32::MAX / 2), u32::MAX);
+        assert_eq!(Payout(1000, 1).winnings(4_000_000), 4_000_000_000);
+    }
+}
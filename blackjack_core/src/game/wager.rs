@@ -0,0 +1,87 @@
+use super::Locale;
+use crate::hand::Hand;
+use crate::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The wager riding on a single player hand.
+///
+/// The `stake` is the amount put up by the player, while `free` is the amount covered by the house
+/// for free doubles and free splits. A free wager is paid when the hand wins but costs nothing when it loses.
+/// Amounts are counted in chips, and a wager is settled into a `Settlement`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wager {
+    pub stake: u32,
+    pub free: u32,
+}
+
+impl Wager {
+    /// Creates a wager of `stake` chips put up by the player.
+    pub fn new(stake: u32) -> Self {
+        Wager { stake, free: 0 }
+    }
+
+    /// Returns the full amount in action, including any free portion.
+    pub fn total(&self) -> u32 {
+        self.stake + self.free
+    }
+
+    /// Doubles the amount in action, with the house covering the extra amount if `free` is set.
+    pub fn double(&mut self, free: bool) {
+        if free {
+            self.free += self.total();
+        } else {
+            self.stake += self.total();
+        }
+    }
+}
+
+/// A hand played by the player, together with the wager riding on it and whether it was surrendered.
+///
+/// Every hand a seat plays in a round has an `id`, numbered from 1 in the order the hands were
+/// opened, which it keeps however the hands around it are split. A hand opened by a split records
+/// the hand it was split from as its `parent`, so each hand, its bet and its outcome can be told
+/// apart in events and logs even after the hands have moved around.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerHand {
+    pub id: usize,
+    pub parent: Option<usize>,
+    pub hand: Hand,
+    pub wager: Wager,
+    pub surrendered: bool,
+}
+
+impl PlayerHand {
+    /// Creates an empty player hand with the given ID and a wager of `bet` chips.
+    pub fn new(id: usize, bet: u32) -> Self {
+        PlayerHand {
+            id,
+            parent: None,
+            hand: Hand::new(),
+            wager: Wager::new(bet),
+            surrendered: false,
+        }
+    }
+
+    /// Opens the first `count` hands of a round, each with a wager of `bet` chips.
+    pub fn opening(count: usize, bet: u32) -> Vec<PlayerHand> {
+        (1..=count).map(|id| PlayerHand::new(id, bet)).collect()
+    }
+
+    /// Splits the pair in this hand, moving the second card to a new hand with the given ID that
+    /// carries `wager`.
+    pub fn split(&mut self, id: usize, wager: Wager) -> PlayerHand {
+        PlayerHand {
+            id,
+            parent: Some(self.id),
+            hand: self.hand.split(),
+            wager,
+            surrendered: false,
+        }
+    }
+
+    /// Returns how the hand is referred to in `locale` among the `count` hands of its seat, such as
+    /// "Hand 2 of 3".
+    pub fn label(&self, count: usize, locale: &Locale) -> String {
+        locale.message("hand-label", &[("hand", &self.id), ("hands", &count)])
+    }
+}
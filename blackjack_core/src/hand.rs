@@ -0,0 +1,261 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+
+use crate::card::{Card, Rank};
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::Renderer;
+
+/// The value of a hand: its best total, and whether that total counts an Ace as 11.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HandValue {
+    pub total: u32,
+    pub soft: bool,
+}
+
+/// Shows the value as it is called at the table, such as "soft 17" or "20".
+impl fmt::Display for HandValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.soft {
+            write!(f, "soft {}", self.total)
+        } else {
+            write!(f, "{}", self.total)
+        }
+    }
+}
+
+/// The `Hand` struct represents a player's hand of cards in a card game.
+///
+/// The `Hand` struct contains a `Vec` of `Card` instances, representing the cards in the player's hand.
+/// The `new()` method creates a new empty hand, the `add()` method adds a card to the hand,
+/// the `calculate_hand_total()` method calculates the total value of the cards in the hand,
+/// and the `display()` method writes the cards in the hand to an output using a provided `Renderer`.
+/// Any card can be dealt face-down with `add_face_down()`, as can the dealer's hole card with
+/// `hide_hole_card()`, and stays hidden from renderers and from `visible_value()` until it is revealed.
+/// A pair can be split into two hands with the `split()` method; split hands never count as a natural,
+/// and nor do hands whose second cards were swapped with `swap_second_card()`.
+/// Helpers such as `is_bust()`, `is_soft()` and `can_split()` classify the hand, so the rules of a
+/// game can ask about it rather than working it out from the total.
+/// The cards can be read with `cards()` or `iter()`, but only changed through methods such as `add()`,
+/// `swap_second_card()` and `clear()`, so a hand cannot be put into a state the game never deals.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hand {
+    cards: Vec<Card>,
+    face_down: Vec<usize>,
+    is_split: bool,
+    is_switched: bool,
+}
+
+impl Hand {
+    /// Creates a new empty hand.
+    pub fn new() -> Self {
+        Hand {
+            cards: Vec::new(),
+            face_down: Vec::new(),
+            is_split: false,
+            is_switched: false,
+        }
+    }
+
+    /// Adds a card to the player's hand.
+    pub fn add(&mut self, card: Card) {
+        self.cards.push(card);
+    }
+
+    /// Adds a card to the hand face-down, so it is not shown until the hand is revealed.
+    pub fn add_face_down(&mut self, card: Card) {
+        self.face_down.push(self.cards.len());
+        self.cards.push(card);
+    }
+
+    /// Returns the cards in the hand, in the order they were dealt.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns an iterator over the cards in the hand, in the order they were dealt.
+    pub fn iter(&self) -> core::slice::Iter<'_, Card> {
+        self.cards.iter()
+    }
+
+    /// Returns the number of cards in the hand.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if the hand holds no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns true if the hand came from splitting a pair.
+    pub fn is_split(&self) -> bool {
+        self.is_split
+    }
+
+    /// Returns true if the hand's second card was swapped with another hand's, as in Blackjack Switch.
+    pub fn is_switched(&self) -> bool {
+        self.is_switched
+    }
+
+    /// Returns true if the hole card is face-down.
+    pub fn is_hole_card_hidden(&self) -> bool {
+        self.is_face_down(1)
+    }
+
+    /// Returns true if any card in the hand is face-down.
+    pub fn has_face_down(&self) -> bool {
+        !self.face_down.is_empty()
+    }
+
+    /// Takes every card out of the hand, leaving it empty and ready to be dealt again, and returns
+    /// them so they can go back to the shoe.
+    pub fn clear(&mut self) -> Vec<Card> {
+        core::mem::take(self).cards
+    }
+
+    /// Swaps the second card of this hand with the second card of `other`, as in Blackjack Switch,
+    /// marking both hands as switched.
+    pub fn swap_second_card(&mut self, other: &mut Hand) {
+        core::mem::swap(&mut self.cards[1], &mut other.cards[1]);
+        self.is_switched = true;
+        other.is_switched = true;
+    }
+
+    /// Calculates the value of the hand, counting an Ace as 11 whenever that does not bust the hand.
+    pub fn value(&self) -> HandValue {
+        value_of(&self.cards)
+    }
+
+    /// Calculates the value of the face-up cards only, which is all the other players can see.
+    pub fn visible_value(&self) -> HandValue {
+        value_of(
+            self.cards
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !self.is_face_down(*index))
+                .map(|(_, card)| card),
+        )
+    }
+
+    /// Calculates the total value of the hand, adjusting for Ace cards.
+    pub fn calculate_hand_total(&self) -> u32 {
+        self.value().total
+    }
+
+    /// Returns true if the hand totals more than 21.
+    pub fn is_bust(&self) -> bool {
+        self.calculate_hand_total() > 21
+    }
+
+    /// Returns true if the hand counts an Ace as 11, so another card cannot bust it.
+    pub fn is_soft(&self) -> bool {
+        self.value().soft
+    }
+
+    /// Returns true if the hand is two cards of the same value, such as two eights or a King and a Ten.
+    pub fn is_pair(&self) -> bool {
+        self.cards.len() == 2 && self.cards[0].rank.value() == self.cards[1].rank.value()
+    }
+
+    /// Returns true if the hand came from splitting Aces, which receive a single card each.
+    pub fn is_split_aces(&self) -> bool {
+        self.is_split
+            && self
+                .cards
+                .first()
+                .is_some_and(|card| card.rank == Rank::Ace)
+    }
+
+    /// Returns true if the rules allow the hand to be split: a pair, other than split Aces.
+    pub fn can_split(&self) -> bool {
+        self.is_pair() && !self.is_split_aces()
+    }
+
+    /// Returns true if the rules allow the hand to be doubled: two cards, other than split Aces.
+    pub fn can_double(&self) -> bool {
+        self.cards.len() == 2 && !self.is_split_aces()
+    }
+
+    /// Turns the second card of the hand face-down.
+    pub fn hide_hole_card(&mut self) {
+        if !self.is_face_down(1) {
+            self.face_down.push(1);
+        }
+    }
+
+    /// Turns every face-down card face-up.
+    pub fn reveal(&mut self) {
+        self.face_down.clear();
+    }
+
+    /// Returns true if the card at `index` is face-down and should not be shown.
+    pub fn is_face_down(&self, index: usize) -> bool {
+        self.face_down.contains(&index)
+    }
+
+    /// Returns true if the hand is a natural: exactly two cards totalling 21 that did not come from a
+    /// split or a switch.
+    pub fn is_blackjack(&self) -> bool {
+        !self.is_split
+            && !self.is_switched
+            && self.cards.len() == 2
+            && self.calculate_hand_total() == 21
+    }
+
+    /// Splits the second card off into a new hand, marking both hands as split.
+    pub fn split(&mut self) -> Hand {
+        self.is_split = true;
+        let face_down = self.is_face_down(1);
+        self.face_down.retain(|&index| index == 0);
+        Hand {
+            cards: self.cards.split_off(1),
+            face_down: if face_down { vec![0] } else { Vec::new() },
+            is_split: true,
+            is_switched: self.is_switched,
+        }
+    }
+
+    /// Writes the hand to `out` on its own line, using the specified renderer.
+    #[cfg(feature = "std")]
+    pub fn display(&self, renderer: &dyn Renderer, out: &mut dyn Write) -> io::Result<()> {
+        write!(out, "Hand: ")?;
+        renderer.render(self, out)?;
+        writeln!(out)
+    }
+}
+
+/// Calculates the value of `cards`, counting an Ace as 11 whenever that does not bust the hand.
+fn value_of<'a>(cards: impl IntoIterator<Item = &'a Card>) -> HandValue {
+    let mut total = 0;
+    let mut ace_count = 0;
+
+    for card in cards {
+        match card.rank {
+            Rank::Ace => {
+                total += 11;
+                ace_count += 1;
+            }
+            rank => total += rank.value(),
+        }
+    }
+
+    // Adjust for Aces if the total is over 21
+    while total > 21 && ace_count > 0 {
+        total -= 10;
+        ace_count -= 1;
+    }
+
+    HandValue {
+        total,
+        soft: ace_count > 0,
+    }
+}
+
+impl Default for Hand {
+    fn default() -> Self {
+        Hand::new()
+    }
+}
@@ -0,0 +1,98 @@
+//! The core of the blackjack game: cards, decks, shoes, hands and the game engine.
+//!
+//! This library holds everything needed to play a game of blackjack, independent of how the game is
+//! presented. Frontends such as the `blackjack` command-line program supply a `Renderer` to draw hands
+//! and a `PlayerInput` to read the player's choices, and play any mode of the game through `GameEngine`.
+//!
+//! Everything that reads input or writes output needs the `std` feature, which is on by default.
+//! Without it the crate builds with `no_std` and `alloc`, keeping the cards, the rules and the
+//! headless `Engine`, so the game logic can run on embedded and WASM targets. There the caller seeds
+//! the engine's entropy source and draws the table from the engine's view itself. The `rand` feature,
+//! also on by default, deals seeded games from the `rand` crate; see the `entropy` module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod card;
+pub mod deck;
+pub mod entropy;
+pub mod error;
+pub mod game;
+pub mod hand;
+pub mod shoe;
+
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+#[cfg(feature = "std")]
+use error::BlackjackError;
+#[cfg(feature = "std")]
+use hand::Hand;
+
+/// The parts of `alloc` used throughout the crate, which the standard prelude brings in when `std` is
+/// available.
+mod prelude {
+    pub use alloc::boxed::Box;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+    pub use alloc::{format, vec};
+}
+
+/// The `Renderer` trait draws a hand of cards as text for display to the player.
+///
+/// Frontends implement this trait to choose how cards look, for example with card glyphs or with
+/// spelled-out rank names, while the game logic stays the same. The hand is written straight into
+/// `out`, so it can go to the terminal, a buffer or a network stream alike.
+#[cfg(feature = "std")]
+pub trait Renderer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()>;
+}
+
+/// The `PlayerInput` trait is the source of the player's choices.
+///
+/// Each call returns the player's next answer to a prompt, trimmed and lowercased, or an error if no
+/// answer could be read. Keeping input behind this trait lets the game be played from the keyboard or
+/// any other source of choices.
+///
+/// The `PlayerInput` trait provides the following methods:
+/// - `read_choice(&mut self) -> Result<String, BlackjackError>`: Waits for the player's next answer.
+/// - `read_choice_within(&mut self, limit: Duration) -> Result<Option<String>, BlackjackError>`:
+///   Waits at most `limit` for the next answer, returning `None` if the time runs out. Sources that
+///   cannot stop waiting part way, or that always answer at once, can keep the default, which waits
+///   as long as `read_choice` does.
+/// - `read_key(&mut self, keys: &[&str], limit: Option<Duration>) -> Result<Option<String>, BlackjackError>`:
+///   Waits for an answer to a prompt whose answers are `keys`, for at most `limit` if one is given.
+///   Sources that read single key presses can answer as soon as one of the keys is typed, without
+///   waiting for Enter. The default reads a whole answer with `read_choice` or `read_choice_within`.
+#[cfg(feature = "std")]
+pub trait PlayerInput {
+    fn read_choice(&mut self) -> Result<String, BlackjackError>;
+
+    fn read_choice_within(&mut self, _limit: Duration) -> Result<Option<String>, BlackjackError> {
+        self.read_choice().map(Some)
+    }
+
+    fn read_key(
+        &mut self,
+        _keys: &[&str],
+        limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        match limit {
+            Some(limit) => self.read_choice_within(limit),
+            None => self.read_choice().map(Some),
+        }
+    }
+}
+
+/// The `GameEngine` trait is implemented by each mode of the game, such as a cash game or a tournament.
+///
+/// The `run()` method plays the next stage of the mode, usually a round, and returns whether the
+/// player goes on to another one, so a frontend can drive any mode with the same loop. An error ends
+/// the game early, leaving the frontend to report it.
+#[cfg(feature = "std")]
+pub trait GameEngine {
+    fn run(&mut self) -> Result<bool, BlackjackError>;
+}
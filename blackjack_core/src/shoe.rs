@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::entropy::EntropySource;
+use crate::error::BlackjackError;
+use crate::prelude::*;
+
+/// The share of the shoe dealt before the cut card comes out, unless another position is chosen.
+pub const DEFAULT_PENETRATION: f64 = 0.75;
+
+/// The `Shoe` struct represents the dealing shoe: one or more decks shuffled together.
+///
+/// Cards are drawn from the shoe with `draw()` and, once a round is over, returned to its discard pile
+/// with `return_cards()`. The cut card is placed so that `cut_card` cards are dealt before `needs_shuffle()`
+/// reports that the shoe should be shuffled; `shuffle()` gathers the discards back in and starts a new shoe.
+/// The shoe counts the cards dealt since the last shuffle, so the cards left can be read at any time.
+/// The cards left are only read through `cards()`, and only change by drawing, returning and shuffling.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Shoe {
+    decks: usize,
+    cards: Vec<Card>,
+    discards: Vec<Card>,
+    cut_card: usize,
+    dealt: usize,
+}
+
+impl Shoe {
+    /// Creates an unshuffled shoe of `decks` decks, with the cut card at the default penetration.
+    pub fn new(decks: usize) -> Self {
+        Shoe::with_penetration(decks, DEFAULT_PENETRATION)
+    }
+
+    /// Creates an unshuffled shoe of `decks` decks, with the cut card placed after the given share of
+    /// the cards, between 0 and 1 as `RuleSet::validate()` checks.
+    pub fn with_penetration(decks: usize, penetration: f64) -> Self {
+        let cards: Vec<Card> = (0..decks).flat_map(|_| Deck::new().into_cards()).collect();
+        let cut_card = (cards.len() as f64 * penetration + 0.5) as usize;
+        Shoe {
+            decks,
+            cards,
+            discards: Vec::new(),
+            cut_card,
+            dealt: 0,
+        }
+    }
+
+    /// Returns the discards to the shoe and shuffles every card with the given entropy source.
+    pub fn shuffle(&mut self, entropy: &mut dyn EntropySource) {
+        self.cards.append(&mut self.discards);
+        entropy.shuffle(&mut self.cards);
+        self.dealt = 0;
+    }
+
+    /// Draws the top card from the shoe, or returns an error if the shoe is empty.
+    pub fn draw(&mut self) -> Result<Card, BlackjackError> {
+        let card = self.cards.pop().ok_or(BlackjackError::EmptyDeck)?;
+        self.dealt += 1;
+        Ok(card)
+    }
+
+    /// Returns cards that have been played to the shoe, onto the discard pile.
+    pub fn return_cards(&mut self, cards: impl IntoIterator<Item = Card>) {
+        self.discards.extend(cards);
+    }
+
+    /// Returns true once the cut card has come out and the shoe should be shuffled before the next round.
+    pub fn needs_shuffle(&self) -> bool {
+        self.dealt >= self.cut_card
+    }
+
+    /// Returns the number of decks the shoe was filled with.
+    pub fn decks(&self) -> usize {
+        self.decks
+    }
+
+    /// Returns the cards left to deal, the next card to be dealt last.
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns the number of cards left to deal.
+    pub fn cards_remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns the number of decks left to deal, as used to turn a running count into a true count.
+    pub fn decks_remaining(&self) -> f64 {
+        self.cards.len() as f64 / 52.0
+    }
+}
+
+impl Default for Shoe {
+    fn default() -> Self {
+        Shoe::new(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entropy;
+
+    #[test]
+    fn a_new_shoe_holds_every_card_of_each_deck() {
+        let shoe = Shoe::new(6);
+        assert_eq!(shoe.cards_remaining(), 312);
+        for &card in Deck::new().cards() {
+            let copies = shoe.cards().iter().filter(|&&other| other == card).count();
+            assert_eq!(copies, 6, "{} is in the shoe {} times", card, copies);
+        }
+    }
+
+    #[test]
+    fn the_cut_card_comes_out_after_the_penetration() {
+        let mut shoe = Shoe::with_penetration(1, 0.5);
+        for _ in 0..25 {
+            shoe.draw().unwrap();
+        }
+        assert!(!shoe.needs_shuffle());
+        shoe.draw().unwrap();
+        assert!(shoe.needs_shuffle());
+    }
+
+    #[test]
+    fn shuffling_gathers_the_discards_back_in() {
+        let mut shoe = Shoe::with_penetration(1, 0.0);
+        let dealt: Vec<Card> = (0..4).map(|_| shoe.draw().unwrap()).collect();
+        shoe.return_cards(dealt);
+        assert_eq!(shoe.cards_remaining(), 48);
+        assert!(shoe.needs_shuffle());
+
+        shoe.shuffle(&mut *entropy::seeded(0));
+        assert_eq!(shoe.cards_remaining(), 52);
+        assert_eq!(shoe.decks_remaining(), 1.0);
+    }
+
+    #[test]
+    fn the_same_seed_shuffles_the_same_order() {
+        let mut first = Shoe::new(2);
+        let mut second = Shoe::new(2);
+        first.shuffle(&mut *entropy::seeded(7));
+        second.shuffle(&mut *entropy::seeded(7));
+        assert_eq!(first.cards(), second.cards());
+    }
+
+    #[test]
+    fn an_empty_shoe_refuses_to_draw() {
+        let mut shoe = Shoe::new(1);
+        for _ in 0..52 {
+            shoe.draw().unwrap();
+        }
+        assert!(matches!(shoe.draw(), Err(BlackjackError::EmptyDeck)));
+    }
+}
@@ -0,0 +1,264 @@
+use blackjack_core::card::{Card, Rank, Suit};
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{Action, Locale, RuleSet, StrategySolver};
+use blackjack_core::hand::Hand;
+
+use crate::cli::{ChartArgs, Cli};
+use crate::config::Config;
+
+/// The dealer's up cards across the top of the chart, a ten standing for every ten and face card.
+pub const UP_CARDS: [Rank; 10] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Ace,
+];
+
+/// One row of the chart: its label, the two cards of the hand it stands for and whether they are
+/// played as a pair, as a hard 20 is dealt as two tens but read from the hard totals.
+pub struct Row {
+    pub label: String,
+    pub cards: [Rank; 2],
+    pub pair: bool,
+}
+
+impl Row {
+    /// Returns the hand the row stands for.
+    pub fn hand(&self) -> Hand {
+        let mut hand = Hand::new();
+        for rank in self.cards {
+            hand.add(card(rank));
+        }
+        hand
+    }
+
+    /// Returns the plays that can be made on the row's hand under `rules`: a hit, a stand and a
+    /// double, a split for a pair, and a surrender where it is offered.
+    pub fn legal_actions(&self, rules: &RuleSet) -> Vec<Action> {
+        let mut legal = vec![Action::Hit, Action::Stand, Action::Double];
+        if self.pair && rules.max_hands > 1 {
+            legal.push(Action::Split);
+        }
+        if rules.surrender {
+            legal.push(Action::Surrender);
+        }
+        legal
+    }
+}
+
+/// Returns the rows of the chart under the titles of its sections: the hard totals from 5 to 20,
+/// the soft totals from Ace-2 to Ace-9 and every pair.
+///
+/// Each row stands for one hand of its total: a 2 and the rest for hard totals up to 11, and a ten
+/// and the rest above that. The titles are locale keys, and the hard totals are labelled in
+/// `locale`.
+pub fn sections(locale: &Locale) -> [(&'static str, Vec<Row>); 3] {
+    let hard = (5..=20).map(|total| {
+        let low = if total <= 11 { 2 } else { total - 10 };
+        Row {
+            label: locale.message("chart-hard-row", &[("total", &total)]),
+            cards: [rank_of(low), rank_of(total - low)],
+            pair: false,
+        }
+    });
+    let soft = (2..=9).map(|other| Row {
+        label: format!("A,{}", other),
+        cards: [Rank::Ace, rank_of(other)],
+        pair: false,
+    });
+    let pairs = (2..=10).chain([1]).map(|value| {
+        let symbol = if value == 1 {
+            "A"
+        } else {
+            rank_of(value).symbol()
+        };
+        Row {
+            label: format!("{},{}", symbol, symbol),
+            cards: [rank_of(value); 2],
+            pair: true,
+        }
+    });
+    [
+        ("chart-hard", hard.collect()),
+        ("chart-soft", soft.collect()),
+        ("chart-pairs", pairs.collect()),
+    ]
+}
+
+/// Prints the basic strategy chart for the rules played by, `--rules` and any house rules in the
+/// config file, as worked out by a `StrategySolver`: every row of `sections()` against every up
+/// card. The table is drawn in ASCII to print or paste anywhere, or written as CSV with
+/// `args.csv`, for a spreadsheet to lay out. The titles, labels and legend are written in the
+/// locale named with `--lang` or in the config file.
+pub fn run(cli: &Cli, args: &ChartArgs, config: &Config) -> Result<(), BlackjackError> {
+    let rules = cli.rules.unwrap_or_default();
+    let locale = config.locale();
+    let sections = sections(&locale);
+
+    if args.csv {
+        let up_cards: Vec<&str> = UP_CARDS.iter().map(|rank| rank.symbol()).collect();
+        println!("hand,{}", up_cards.join(","));
+        for (_, rows) in &sections {
+            for row in rows {
+                println!("{},{}", row.label, cells(&rules, row).join(","));
+            }
+        }
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        locale.message("chart-title", &[("rules", &describe(&rules, &locale))])
+    );
+    for (title, rows) in &sections {
+        println!();
+        let up_cards: Vec<String> = UP_CARDS
+            .iter()
+            .map(|rank| format!("{:>3}", rank.symbol()))
+            .collect();
+        println!("{:<12}|{}", locale.text(title), up_cards.join(""));
+        println!("{}+{}", "-".repeat(12), "-".repeat(3 * UP_CARDS.len()));
+        for row in rows {
+            let cells: Vec<String> = cells(&rules, row)
+                .iter()
+                .map(|cell| format!("{:>3}", cell))
+                .collect();
+            println!("{:<12}|{}", row.label, cells.join(""));
+        }
+    }
+    println!();
+    println!("{}", locale.text("chart-legend"));
+    Ok(())
+}
+
+/// Returns the codes of the plays for `row` against each up card.
+fn cells(rules: &RuleSet, row: &Row) -> Vec<&'static str> {
+    let hand = row.hand();
+    let legal = row.legal_actions(rules);
+    UP_CARDS
+        .iter()
+        .map(|&up| {
+            let mut solver = StrategySolver::new(rules, card(up), hand.cards());
+            let best = solver.play(&hand, &legal);
+            // A double or surrender can only be made on the first two cards, so the chart says
+            // what to do after a hit or a split
+            let otherwise = solver.play(&hand, &[Action::Hit, Action::Stand]);
+            match (best, otherwise) {
+                (Action::Double, Action::Stand) => "Ds",
+                (Action::Double, _) => "Dh",
+                (Action::Surrender, Action::Stand) => "Rs",
+                (Action::Surrender, _) => "Rh",
+                (Action::Split, _) => "P",
+                (Action::Stand, _) => "S",
+                _ => "H",
+            }
+        })
+        .collect()
+}
+
+/// Describes the rules that change the chart in `locale`, such as "6 decks, dealer stands on soft
+/// 17, double after split, surrender, dealer peeks".
+fn describe(rules: &RuleSet, locale: &Locale) -> String {
+    let parts = [
+        match rules.decks {
+            1 => locale.text("chart-one-deck"),
+            decks => locale.message("chart-decks", &[("decks", &decks)]),
+        },
+        locale.text(match rules.dealer_hits_soft_17 {
+            true => "chart-hits-soft-17",
+            false => "chart-stands-soft-17",
+        }),
+        locale.text(match rules.double_after_split {
+            true => "chart-double-after-split",
+            false => "chart-no-double-after-split",
+        }),
+        locale.text(match rules.surrender {
+            true => "chart-surrender",
+            false => "chart-no-surrender",
+        }),
+        locale.text(match rules.dealer_peeks {
+            true => "chart-dealer-peeks",
+            false => "chart-no-peek",
+        }),
+    ];
+    parts.join(", ")
+}
+
+/// Returns the rank worth `value`, an Ace for 1 and a ten for 10.
+fn rank_of(value: u32) -> Rank {
+    Rank::ALL[value as usize - 1]
+}
+
+/// Returns a card of `rank`, whose suit makes no difference to the play.
+pub fn card(rank: Rank) -> Card {
+    Card {
+        rank,
+        suit: Suit::Spades,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the chart's cell for the two cards `cards`, played as a pair if `pair` is set,
+    /// against the up card `up`, under `rules`.
+    fn cell(rules: &RuleSet, cards: [Rank; 2], pair: bool, up: Rank) -> &'static str {
+        let row = Row {
+            label: String::new(),
+            cards,
+            pair,
+        };
+        let column = UP_CARDS.iter().position(|&rank| rank == up).unwrap();
+        cells(rules, &row)[column]
+    }
+
+    /// Four decks, the dealer standing on soft 17, doubling after a split and late surrender.
+    fn vegas_strip() -> RuleSet {
+        let rules = RuleSet::preset("vegas-strip").unwrap();
+        assert_eq!(rules.decks, 4);
+        assert!(!rules.dealer_hits_soft_17 && rules.double_after_split && rules.surrender);
+        rules
+    }
+
+    #[test]
+    fn hard_16_surrenders_against_a_ten() {
+        let cell = cell(&vegas_strip(), [Rank::Ten, Rank::Six], false, Rank::Ten);
+        assert_eq!(cell, "Rh");
+    }
+
+    #[test]
+    fn hard_11_hits_against_an_ace() {
+        let cell = cell(&vegas_strip(), [Rank::Two, Rank::Nine], false, Rank::Ace);
+        assert_eq!(cell, "H");
+    }
+
+    #[test]
+    fn eights_split_against_an_ace() {
+        let cell = cell(&vegas_strip(), [Rank::Eight, Rank::Eight], true, Rank::Ace);
+        assert_eq!(cell, "P");
+    }
+
+    #[test]
+    fn twenty_is_not_split_when_read_as_a_hard_total() {
+        let cell = cell(&vegas_strip(), [Rank::Ten, Rank::Ten], false, Rank::Six);
+        assert_eq!(cell, "S");
+    }
+
+    #[test]
+    fn the_rules_are_described_in_the_locale() {
+        let english = describe(&vegas_strip(), &Locale::default());
+        assert_eq!(
+            english,
+            "4 decks, dealer stands on soft 17, double after split, surrender, dealer peeks"
+        );
+        let spanish = describe(&RuleSet::CLASSIC, &Locale::named("es").unwrap());
+        assert!(spanish.starts_with("1 baraja, "), "{}", spanish);
+    }
+}
@@ -0,0 +1,410 @@
+use blackjack_core::game::{CountingSystem, DeckEstimation, Locale, RuleSet};
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::config::COLOR_CHOICES;
+use crate::views::{LAYOUT_NAMES, VIEWER_NAMES};
+
+/// Play blackjack in the terminal: cash games, tournaments and the daily challenge, with bots to
+/// simulate or watch, a strategy trainer and a server to play over the network.
+///
+/// The global options can also be set with `BLACKJACK_*` environment variables, which take the
+/// place of the config file's values but give way to flags on the command line.
+#[derive(Clone, Parser)]
+#[command(name = "blackjack", version)]
+pub struct Cli {
+    #[arg(
+        long,
+        global = true,
+        env = "BLACKJACK_RULES",
+        value_name = "PRESET",
+        value_parser = rule_preset,
+        help = format!("The table rules to play by: {}", preset_names())
+    )]
+    pub rules: Option<RuleSet>,
+
+    /// Deals from a shoe seeded with SEED, so a game can be played again with the same cards.
+    #[arg(long, global = true, env = "BLACKJACK_SEED")]
+    pub seed: Option<u64>,
+
+    /// Writes a replay of the game to FILE once it is over, to watch again with `blackjack replay
+    /// FILE` or to attach to a bug report. A game dealt without --seed is given a random seed to
+    /// record. Cash games, scripts, the JSON mode, the window and simulations are recorded; side
+    /// bets and rebuys are not.
+    #[arg(long, global = true, env = "BLACKJACK_RECORD", value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// How cards are drawn: glyph (A of ♠), alpha (Ace of Spades), ascii (A of S), plain (AS), box
+    /// (each card drawn as a box, side by side), unicode (🂡, if the terminal can show it), spoken
+    /// (Seven of Clubs, in words for screen readers) or auto (boxes where they fit across the
+    /// terminal, and glyphs where they do not).
+    #[arg(
+        long,
+        global = true,
+        env = "BLACKJACK_VIEW",
+        value_name = "VIEWER",
+        value_parser = VIEWER_NAMES
+    )]
+    pub view: Option<String>,
+
+    /// How the table is laid out: lines writes one line per hand as the round is played, table
+    /// draws the whole table again after every card and decision, spoken says the table in
+    /// sentences for screen readers, and status says it on one terse line kept at the bottom of the
+    /// terminal, for narrow terminals and tmux panes. Defaults to spoken with the spoken viewer, and to lines
+    /// otherwise.
+    #[arg(
+        long,
+        global = true,
+        env = "BLACKJACK_LAYOUT",
+        value_name = "LAYOUT",
+        value_parser = LAYOUT_NAMES
+    )]
+    pub layout: Option<String>,
+
+    /// Prints plain, line-oriented text for logs, scripts and screen scrapers: cards are drawn as
+    /// AS and 10H unless --view names another viewer, and chips without glyphs.
+    #[arg(long, global = true, env = "BLACKJACK_PLAIN")]
+    pub plain: bool,
+
+    /// When to colour red suits and the results of hands: auto colours a terminal unless NO_COLOR
+    /// is set.
+    #[arg(
+        long,
+        global = true,
+        env = "BLACKJACK_COLOR",
+        value_name = "WHEN",
+        value_parser = COLOR_CHOICES
+    )]
+    pub color: Option<String>,
+
+    /// The language of the game's prompts, messages and card names: en (English) or es (Spanish).
+    #[arg(
+        long,
+        global = true,
+        env = "BLACKJACK_LANG",
+        value_name = "LANG",
+        value_parser = Locale::NAMES
+    )]
+    pub lang: Option<String>,
+
+    /// Deals every card at once, without the pauses between cards set in the config file.
+    #[arg(long, global = true, env = "BLACKJACK_FAST")]
+    pub fast: bool,
+
+    /// Keeps the dealer quiet, without the chatter pack chosen in the config file.
+    #[arg(long, global = true, env = "BLACKJACK_TERSE")]
+    pub terse: bool,
+
+    /// Reads defaults from the config file PATH instead of ~/.config/blackjack/config.toml.
+    #[arg(long, global = true, env = "BLACKJACK_CONFIG", value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// What the program does; without a subcommand, the main menu is shown.
+#[derive(Clone, Subcommand)]
+pub enum Command {
+    /// Play a cash game, a tournament or the daily challenge.
+    Play(PlayArgs),
+    /// Let a bot play many hands headlessly and report how it did.
+    Simulate(SimulateArgs),
+    /// Let a bot play at a relaxed pace, saying why it plays each hand as it does, until a key is
+    /// pressed to play a real game.
+    Demo(DemoArgs),
+    /// Show the lifetime statistics of one or every profile.
+    Stats(StatsArgs),
+    /// Serve cash games to players connecting over TCP, for example with `nc` or `telnet`.
+    Serve(ServeArgs),
+    /// Practice basic strategy: decide how to play each hand dealt and see whether it was right.
+    Trainer,
+    /// Drill basic strategy on hands flashed from the chart, with the ones you get wrong asked again
+    /// more often.
+    Drill(DrillArgs),
+    /// Drill the Hi-Lo count: cards are flashed one at a time, then the running count is asked for.
+    CountDrill(CountDrillArgs),
+    /// Practice back counting: watch other players' rounds while keeping the count, and sit in
+    /// only once the true count is high enough.
+    Wong(WongArgs),
+    /// Print the basic strategy chart for the rules played by, to keep at hand or print out.
+    Chart(ChartArgs),
+    /// Play a cash game with every choice read from a script, one per line, instead of the keyboard.
+    Script(ScriptArgs),
+    /// Watch a game recorded with --record played again, action by action.
+    Replay(ReplayArgs),
+    /// Manage the config file of defaults.
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+/// What to do with the config file.
+#[derive(Clone, Subcommand)]
+pub enum ConfigCommand {
+    /// Write a starter config file with every setting explained and commented out.
+    Init {
+        /// Replace the config file if there already is one.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Change the rules, viewer, starting bankroll and colour-blind suits in the config file from a
+    /// menu.
+    Edit,
+}
+
+#[derive(Args, Clone, Default)]
+pub struct PlayArgs {
+    /// Carry on the cash game saved part way through.
+    #[arg(long)]
+    pub resume: bool,
+
+    /// Play as the profile NAME instead of choosing one from a menu, creating it on first use.
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Start from the starting bankroll and new table settings, ignoring the profile's.
+    #[arg(long)]
+    pub fresh: bool,
+
+    /// The chips a new profile, or one started again with --fresh, starts with.
+    #[arg(long, value_name = "CHIPS")]
+    pub bankroll: Option<u32>,
+
+    /// Give yourself SECONDS for each decision, as at a real table; a hand not played in time
+    /// stands.
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..=600))]
+    pub timer: Option<u64>,
+
+    /// Check each of your decisions against basic strategy, and show how many matched in the
+    /// summary at the end of the session.
+    #[arg(long)]
+    pub grade: bool,
+
+    /// Before each decision, show the chance of busting if you hit, the dealer's likeliest totals
+    /// and the basic strategy play.
+    #[arg(long)]
+    pub hints: bool,
+
+    /// Depart from basic strategy by the Illustrious 18 and Fab 4 index plays at the Hi-Lo true
+    /// count in the hints, the advice and the grading, for counting practice. The indices are
+    /// worked out for Hi-Lo, the count the game keeps, and are made only with it.
+    #[arg(long)]
+    pub index_plays: bool,
+
+    /// Play a classic cash game driven by another program: JSON actions are read from stdin, one
+    /// per line, and the state of the game and each event are written to stdout the same way.
+    #[arg(
+        long,
+        conflicts_with_all = ["resume", "profile", "fresh", "timer", "grade", "hints", "index_plays"]
+    )]
+    pub json: bool,
+
+    /// Play on a full screen of panels, with the table, the choices, the messages and the
+    /// session's statistics, redrawn as the game goes on instead of scrolling by.
+    #[cfg(feature = "tui")]
+    #[arg(long, conflicts_with = "json")]
+    pub tui: bool,
+
+    /// Play a classic cash game in a window of its own, with the cards drawn on a table and a
+    /// button for every decision.
+    #[cfg(feature = "gui")]
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "json",
+            "resume",
+            "profile",
+            "fresh",
+            "timer",
+            "grade",
+            "hints",
+            "index_plays"
+        ]
+    )]
+    pub gui: bool,
+}
+
+impl PlayArgs {
+    /// Returns the time given for each decision with `--timer`, if any.
+    pub fn time_limit(&self) -> Option<Duration> {
+        self.timer.map(Duration::from_secs)
+    }
+}
+
+#[derive(Args, Clone)]
+pub struct SimulateArgs {
+    /// The number of rounds to play.
+    #[arg(long, default_value_t = 10_000)]
+    pub rounds: u32,
+
+    /// The bot that plays: `basic` flat bets and plays basic strategy, `counting` spreads its bets
+    /// with the count, and `dealer` plays like the dealer.
+    #[arg(long, default_value = "basic", value_parser = ["basic", "counting", "dealer"])]
+    pub bot: String,
+
+    /// Have the counting bot depart from basic strategy by the Illustrious 18 and Fab 4 index plays
+    /// at the true count. The indices are Hi-Lo's, so the bot only makes them counting by Hi-Lo,
+    /// and plays basic strategy with any other --system.
+    #[arg(long)]
+    pub index_plays: bool,
+
+    /// The system the counting bot counts with. Given more than once, the bot plays the same cards
+    /// again with each system, so they can be compared; its bet ramp is read from each count as it
+    /// is from Hi-Lo's, and the index plays are only made counting by Hi-Lo.
+    #[arg(long = "system", value_name = "SYSTEM", default_value = "hi-lo", value_parser = CountingSystem::NAMES)]
+    pub systems: Vec<String>,
+
+    /// How closely the counting bot reckons the decks left when it turns its count into a true
+    /// count: to the card, or to the nearest quarter, half or whole deck.
+    #[arg(long, value_name = "STEP", default_value = "exact", value_parser = DeckEstimation::NAMES)]
+    pub deck_estimation: String,
+
+    /// Have the counting bot keep a side count of the Aces, sizing its bets from the count they
+    /// adjust when its system leaves Aces out of the count.
+    #[arg(long)]
+    pub ace_side_count: bool,
+
+    /// Have the counting bot bet by the ramp in FILE instead of 1-1-2-4-6-8, each line giving the
+    /// units bet at a true count, such as `2 = 4`, with `wong_in = TC` and `wong_out = TC` to sit
+    /// rounds out below a count.
+    #[arg(long, value_name = "FILE")]
+    pub ramp: Option<PathBuf>,
+
+    /// How many other players are dealt the rounds the bot sits out.
+    #[arg(long, value_name = "N", default_value_t = 2, value_parser = clap::value_parser!(u32).range(1..=6))]
+    pub others: u32,
+
+    /// The chips the bot starts with.
+    #[arg(long, default_value_t = 100_000)]
+    pub bankroll: u32,
+}
+
+impl Default for SimulateArgs {
+    /// The same defaults as on the command line.
+    fn default() -> Self {
+        SimulateArgs {
+            rounds: 10_000,
+            bot: "basic".to_string(),
+            index_plays: false,
+            systems: vec!["hi-lo".to_string()],
+            deck_estimation: "exact".to_string(),
+            ace_side_count: false,
+            ramp: None,
+            others: 2,
+            bankroll: 100_000,
+        }
+    }
+}
+
+#[derive(Args, Clone)]
+pub struct DemoArgs {
+    /// How long the bot waits before each decision and between rounds.
+    #[arg(long, value_name = "SECONDS", default_value_t = 3, value_parser = clap::value_parser!(u64).range(1..=60))]
+    pub pace: u64,
+}
+
+#[derive(Args, Clone)]
+pub struct DrillArgs {
+    /// Stop after N hands, instead of when 'q' is entered.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub questions: Option<u32>,
+}
+
+#[derive(Args, Clone)]
+pub struct CountDrillArgs {
+    /// How long each card is shown before the next replaces it.
+    #[arg(long, value_name = "MS", default_value_t = 1000, value_parser = clap::value_parser!(u64).range(100..=10_000))]
+    pub pace: u64,
+    /// How many cards are dealt; no more than the shoe holds are.
+    #[arg(long, value_name = "N", default_value_t = 52, value_parser = clap::value_parser!(u32).range(1..))]
+    pub cards: u32,
+    /// Also ask for the count at random points along the way, not only once every card is dealt.
+    #[arg(long)]
+    pub checkpoints: bool,
+    /// The counting system to keep the count with.
+    #[arg(long, value_name = "SYSTEM", default_value = "hi-lo", value_parser = CountingSystem::NAMES)]
+    pub system: String,
+    /// Keep a side count of the Aces as well, and give the Aces dealt along with each count.
+    #[arg(long)]
+    pub aces: bool,
+}
+
+#[derive(Args, Clone)]
+pub struct WongArgs {
+    /// The true count at which you sit in.
+    #[arg(
+        long,
+        value_name = "TC",
+        default_value_t = 2.0,
+        allow_negative_numbers = true
+    )]
+    pub enter: f64,
+    /// The true count below which you leave the table again.
+    #[arg(
+        long,
+        value_name = "TC",
+        default_value_t = 0.0,
+        allow_negative_numbers = true
+    )]
+    pub exit: f64,
+    /// How many other players sit at the table, playing every round whether you watch or play.
+    #[arg(long, value_name = "N", default_value_t = 2, value_parser = clap::value_parser!(u32).range(1..=6))]
+    pub others: u32,
+    /// The counting system to keep the count with.
+    #[arg(long, value_name = "SYSTEM", default_value = "hi-lo", value_parser = CountingSystem::NAMES)]
+    pub system: String,
+    /// Show the true count after every round watched.
+    #[arg(long)]
+    pub show_count: bool,
+}
+
+#[derive(Args, Clone)]
+pub struct ChartArgs {
+    /// Write the chart as CSV, one hand per line, instead of an ASCII table.
+    #[arg(long)]
+    pub csv: bool,
+}
+
+#[derive(Args, Clone, Default)]
+pub struct StatsArgs {
+    /// The profile to show; every profile is shown if none is given.
+    pub profile: Option<String>,
+}
+
+#[derive(Args, Clone)]
+pub struct ScriptArgs {
+    /// The script to play; it is read from stdin if none or "-" is given.
+    pub file: Option<PathBuf>,
+}
+
+#[derive(Args, Clone)]
+pub struct ReplayArgs {
+    /// The replay to watch, as written with --record.
+    pub file: PathBuf,
+
+    /// Wait for Enter before each action instead of playing the whole replay through.
+    #[arg(long)]
+    pub step: bool,
+}
+
+#[derive(Args, Clone)]
+pub struct ServeArgs {
+    /// The address to listen on.
+    #[arg(long, default_value = "127.0.0.1:7878")]
+    pub address: String,
+}
+
+/// Reads the name of a rule preset.
+fn rule_preset(name: &str) -> Result<RuleSet, String> {
+    RuleSet::preset(name).ok_or_else(|| format!("choose one of: {}", preset_names()))
+}
+
+/// Lists the names of the rule presets.
+pub fn preset_names() -> String {
+    RuleSet::PRESETS
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
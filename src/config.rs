@@ -0,0 +1,857 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Border, CardBack, Chatter, CurrencyFormat, DeckEstimation, KeyBindings, Locale, Payout,
+    RuleSet, SuitSymbols, TableView, TextTableView, Theme, STARTING_BANKROLL,
+};
+use blackjack_core::Renderer;
+use crossterm::terminal;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cli::preset_names;
+use crate::settings::{prompt_choice, prompt_until_valid};
+use crate::views::{
+    table_view_from_name, viewer_from_name, CardGlyphViewer, LAYOUT_NAMES, VIEWER_NAMES,
+};
+
+/// The settings for when to write ANSI colours, for `--color` and the config file.
+pub const COLOR_CHOICES: [&str; 3] = ["auto", "always", "never"];
+
+/// The longest pause between cards that can be set, in milliseconds.
+const MAX_DELAY: u64 = 5000;
+
+/// The file written by `config init`, with every setting commented out at its default value.
+const STARTER: &str = r#"# Defaults for blackjack. Every setting is optional: remove the '#' in front of a setting to
+# change it. BLACKJACK_* environment variables and flags given on the command line take precedence
+# over the values in this file.
+
+# The table rules to play by: classic, vegas-strip, downtown, atlantic-city or european.
+# rules = "classic"
+
+# How cards are drawn: "glyph" (A of ♠), "alpha" (Ace of Spades), "ascii" (A of S), "plain" (AS)
+# "box" (each card drawn as a box, side by side), "unicode" (🂡, if the terminal can show it),
+# "spoken" (Seven of Clubs, in words for screen readers) or "auto" (boxes where they fit across
+# the terminal, and glyphs where they do not).
+# viewer = "glyph"
+
+# How the table is laid out: "lines" writes one line per hand as the round is played, "table"
+# draws the whole table again after every card and decision, and "spoken" says the table in
+# sentences for screen readers. "status" says the whole table on one terse line, kept on the
+# terminal's last row, for narrow terminals and tmux panes. It defaults to "spoken" with the spoken
+# viewer and "lines" otherwise.
+# layout = "lines"
+
+# Plain, line-oriented text for logs and scripts, drawing cards as AS unless a viewer is named
+# on the command line.
+# plain = false
+
+# When to colour red suits and the results of hands: "auto" colours a terminal unless NO_COLOR is
+# set, and "always" and "never" say when. Plain output is never coloured.
+# color = "auto"
+
+# The look of the game: "default", "green-felt" (green borders and rounded cards), "high-contrast"
+# (bold, bright colours and heavy borders) or "monochrome" (no colours, and hollow symbols for the
+# red suits), or a theme of your own from [themes] below.
+# theme = "default"
+
+# Tags each suit with its initial, as in ♥H and ♦D, and draws the red suits in bold, so suits can
+# be told apart without telling red from black.
+# colorblind = false
+
+# The pattern on the backs of face-down cards, in place of the theme's: "plain" (??), "hatched",
+# "lattice", "dotted" or "diamonds". Each is drawn in ASCII where the cards are.
+# card_back = "plain"
+
+# The language of the game's prompts, messages and card names: "en" (English) or "es" (Spanish).
+# lang = "en"
+
+# What the dealer says as the game goes on: a built-in chatter pack, "friendly", "gruff" or
+# "amable" (friendly, in Spanish), or the path of a pack of your own, whose lines are written as
+# `situation = text` like those of the built-in packs. The dealer is quiet unless one is chosen,
+# and --terse keeps the dealer quiet anyway.
+# chatter = "friendly"
+
+# The pauses, in milliseconds, before each card dealt to you is shown and before each card the
+# dealer turns over or draws, so hands are dealt one card at a time. They default to 300 and 700
+# when playing at a terminal, and to 0 otherwise; --fast turns them off.
+# deal_delay = 300
+# dealer_delay = 700
+
+# The chips a new profile starts with, and a profile started again with --fresh.
+# bankroll = 1000
+
+# How closely the decks left are reckoned when the running count is turned into a true count:
+# "exact" (to the card), or "quarter", "half" or "full" (to the nearest quarter, half or whole
+# deck), as a counter eyeing the discard tray would. The true count shown, the bets advised from
+# it and the index plays in hints all follow it.
+# deck_estimation = "exact"
+
+# Keep a side count of the Aces alongside the main count. The Aces left are shown with the count,
+# and with a system that leaves Aces out of its count, such as Hi-Opt I, the bets advised are sized
+# from the count they adjust.
+# ace_side_count = true
+
+# House rules change single rules of the preset chosen above, or on the command line.
+# [house_rules]
+# decks = 6                      # from 1 to 8
+# dealer_hits_soft_17 = true
+# double_after_split = true
+# surrender = true
+# blackjack_pays = "6:5"         # at least even money
+# dealer_peeks = true
+# max_hands = 4                  # 1 allows no splits
+# charlie = 5                    # the cards that win outright without busting, or 0 for none
+# penetration = 0.75             # the share of the shoe dealt before the shuffle, from 0 to 1
+
+# The keys typed at each decision, a letter or a whole word, for example to play with the keys of
+# another keyboard layout or another blackjack program. Each key must be different.
+# [keybindings]
+# hit = "h"
+# stand = "s"
+# double = "d"
+# split = "p"
+# surrender = "r"
+# advice = "a"                   # shows the basic strategy play
+# count = "c"                    # shows or hides the running and true counts
+# quit = "q"
+
+# Themes of your own, each changing a built-in theme. Colours are named like "red", "bright-cyan",
+# "bold yellow" or "none". The border is "ascii", "light", "rounded", "heavy" or "double", and the
+# suits are "filled" (♥), "mixed" (♡ for the red suits), "letters" (H) or "tagged" (♥H), and the
+# back of the cards is one of the card backs above.
+# [themes.midnight]
+# base = "default"
+# red_suits = "bright-magenta"
+# black_suits = "none"
+# win = "bright-green"
+# blackjack = "bold yellow"
+# bust = "red"
+# felt = "blue"
+# border = "double"
+# suits = "filled"
+# back = "plain"
+"#;
+
+/// The defaults read from the config file, which flags on the command line override.
+///
+/// Every setting is optional, and a missing file is the same as an empty one. The `house_rules`
+/// change single rules of whichever preset is played, and the `keybindings` map the choices at a
+/// decision, named as in `KEY_NAMES`, to the keys typed for them. `plain` turns on the same
+/// plain output as `--plain`, `layout` chooses the table view as `--layout` does, and `color` says
+/// when to write colours, as `--color` does. `deal_delay` and `dealer_delay` are the pauses between
+/// cards in milliseconds, which `--fast` sets to zero. The `theme` is a built-in theme or one of the
+/// `themes` defined in the file, and is used by every viewer and table view; `colorblind` tags its
+/// suits with their initials. `lang` names the locale the game's text is written in, as `--lang`
+/// does, and `chatter` the dealer's chatter pack, built in or read from a file. `deck_estimation`
+/// says how closely the decks left are reckoned for the true count, and `ace_side_count` keeps a
+/// side count of the Aces.
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub rules: Option<String>,
+    pub viewer: Option<String>,
+    pub layout: Option<String>,
+    pub plain: bool,
+    pub color: Option<String>,
+    pub deal_delay: Option<u64>,
+    pub dealer_delay: Option<u64>,
+    pub bankroll: Option<u32>,
+    pub deck_estimation: Option<String>,
+    pub ace_side_count: bool,
+    pub house_rules: Option<HouseRules>,
+    pub theme: Option<String>,
+    pub colorblind: bool,
+    pub card_back: Option<String>,
+    pub lang: Option<String>,
+    pub chatter: Option<String>,
+    pub themes: BTreeMap<String, ThemeSettings>,
+    pub keybindings: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Returns the path the config file is read from when `--config` is not given:
+    /// `blackjack/config.toml` in `$XDG_CONFIG_HOME`, or in `~/.config` if that is not set.
+    pub fn default_path() -> PathBuf {
+        let directory = env::var_os("XDG_CONFIG_HOME")
+            .filter(|directory| !directory.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| {
+                let home = env::var_os("HOME").map_or_else(|| PathBuf::from("."), PathBuf::from);
+                home.join(".config")
+            });
+        directory.join("blackjack").join("config.toml")
+    }
+
+    /// Reads the config file at `path`, or at the default path if none is given.
+    ///
+    /// A missing file at the default path gives the defaults, but a file named with `--config` must
+    /// exist. The error for a file that cannot be read as TOML, or that names a rule preset or
+    /// viewer that does not exist, says which file and setting are wrong.
+    pub fn load(path: Option<&Path>) -> io::Result<Config> {
+        let default_path = Config::default_path();
+        let contents = match path {
+            Some(path) => fs::read_to_string(path),
+            None => match fs::read_to_string(&default_path) {
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    return Ok(Config::default())
+                }
+                contents => contents,
+            },
+        };
+        let path = path.unwrap_or(&default_path);
+        let invalid = |message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), message),
+            )
+        };
+
+        let config: Config = toml::from_str(&contents.map_err(|error| invalid(error.to_string()))?)
+            .map_err(|error| invalid(error.message().to_string()))?;
+        if let Some(rules) = config
+            .rules
+            .as_deref()
+            .filter(|&rules| RuleSet::preset(rules).is_none())
+        {
+            return Err(invalid(format!(
+                "'{}' is not a rule preset, choose one of: {}",
+                rules,
+                preset_names()
+            )));
+        }
+        if let Some(viewer) = config
+            .viewer
+            .as_deref()
+            .filter(|viewer| !VIEWER_NAMES.contains(viewer))
+        {
+            return Err(invalid(format!(
+                "'{}' is not a viewer, choose one of: {}",
+                viewer,
+                VIEWER_NAMES.join(", ")
+            )));
+        }
+        if let Some(layout) = config
+            .layout
+            .as_deref()
+            .filter(|layout| !LAYOUT_NAMES.contains(layout))
+        {
+            return Err(invalid(format!(
+                "'{}' is not a layout, choose one of: {}",
+                layout,
+                LAYOUT_NAMES.join(", ")
+            )));
+        }
+        if let Some(color) = config
+            .color
+            .as_deref()
+            .filter(|color| !COLOR_CHOICES.contains(color))
+        {
+            return Err(invalid(format!(
+                "'{}' is not a colour setting, choose one of: {}",
+                color,
+                COLOR_CHOICES.join(", ")
+            )));
+        }
+        if let Some(back) = config
+            .card_back
+            .as_deref()
+            .filter(|back| CardBack::from_name(back).is_none())
+        {
+            return Err(invalid(format!(
+                "'{}' is not a card back, choose one of: {}",
+                back,
+                CardBack::NAMES.join(", ")
+            )));
+        }
+        if let Some(estimation) = config
+            .deck_estimation
+            .as_deref()
+            .filter(|estimation| DeckEstimation::from_name(estimation).is_none())
+        {
+            return Err(invalid(format!(
+                "'{}' is not a deck estimation, choose one of: {}",
+                estimation,
+                DeckEstimation::NAMES.join(", ")
+            )));
+        }
+        if let Some(lang) = config
+            .lang
+            .as_deref()
+            .filter(|lang| !Locale::NAMES.contains(lang))
+        {
+            return Err(invalid(format!(
+                "'{}' is not a language, choose one of: {}",
+                lang,
+                Locale::NAMES.join(", ")
+            )));
+        }
+        config.read_chatter().map_err(invalid)?;
+        for (name, delay) in [
+            ("deal_delay", config.deal_delay),
+            ("dealer_delay", config.dealer_delay),
+        ] {
+            if delay.is_some_and(|delay| delay > MAX_DELAY) {
+                return Err(invalid(format!(
+                    "{} must be at most {} milliseconds",
+                    name, MAX_DELAY
+                )));
+            }
+        }
+        if let Some(house_rules) = &config.house_rules {
+            let rules = house_rules
+                .apply(config.rules().unwrap_or_default())
+                .map_err(|message| invalid(format!("house_rules: {}", message)))?;
+            if let Err(error) = rules.validate() {
+                return Err(invalid(format!("house_rules: {}", error)));
+            }
+        }
+        for (name, settings) in &config.themes {
+            settings
+                .apply()
+                .map_err(|message| invalid(format!("themes.{}: {}", name, message)))?;
+        }
+        config.named_theme().map_err(invalid)?;
+        config.key_bindings().map_err(invalid)?;
+        Ok(config)
+    }
+
+    /// Writes the rules, viewer, bankroll, colour-blind suits and card back to the config file at `path`, or at the default path if
+    /// none is given, removing the settings that are not set.
+    ///
+    /// The rest of an existing file, including its comments, is kept as it was, and a setting that
+    /// was not in the file is written below its commented-out example. A new file starts from the
+    /// commented starter file.
+    pub fn save(&self, path: Option<&Path>) -> Result<(), BlackjackError> {
+        let path = path.map_or_else(Config::default_path, Path::to_path_buf);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => STARTER.to_string(),
+            Err(error) => return Err(error.into()),
+        };
+        let mut lines: Vec<String> = contents.lines().map(str::to_string).collect();
+        let quote = |text: &String| toml::Value::String(text.clone()).to_string();
+        set_line(&mut lines, "rules", self.rules.as_ref().map(quote));
+        set_line(&mut lines, "viewer", self.viewer.as_ref().map(quote));
+        set_line(
+            &mut lines,
+            "bankroll",
+            self.bankroll.map(|bankroll| bankroll.to_string()),
+        );
+        set_line(
+            &mut lines,
+            "colorblind",
+            self.colorblind.then(|| "true".to_string()),
+        );
+        set_line(&mut lines, "card_back", self.card_back.as_ref().map(quote));
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(&path, lines.join("\n") + "\n")?;
+        Ok(())
+    }
+
+    /// Returns the rule preset named in the file, if any.
+    pub fn rules(&self) -> Option<RuleSet> {
+        self.rules.as_deref().and_then(RuleSet::preset)
+    }
+
+    /// Returns the rules to play: `preset`, or the classic rules if none is chosen, with the house
+    /// rules applied, checked to be playable.
+    pub fn table_rules(&self, preset: Option<RuleSet>) -> Result<RuleSet, BlackjackError> {
+        let rules = preset.unwrap_or_default();
+        let rules = match &self.house_rules {
+            // The payout was checked when the file was loaded
+            Some(house_rules) => house_rules.apply(rules).unwrap_or(rules),
+            None => rules,
+        };
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    /// Returns how closely the decks left are reckoned, to the card unless the file says otherwise.
+    pub fn deck_estimation(&self) -> DeckEstimation {
+        // The estimation was checked when the file was loaded
+        self.deck_estimation
+            .as_deref()
+            .and_then(DeckEstimation::from_name)
+            .unwrap_or_default()
+    }
+
+    /// Returns the default keys with those bound in the file, or a message saying which binding
+    /// cannot be used.
+    fn key_bindings(&self) -> Result<KeyBindings, String> {
+        let mut keys = KeyBindings::default();
+        for (name, key) in &self.keybindings {
+            keys.bind(name, key)
+                .map_err(|error| format!("keybindings.{}: {}", name, error))?;
+        }
+        keys.validate()
+            .map_err(|error| format!("keybindings: {}", error))?;
+        Ok(keys)
+    }
+
+    /// Returns the keys the player types at each decision.
+    pub fn keys(&self) -> KeyBindings {
+        // The bindings were checked when the file was loaded
+        self.key_bindings().unwrap_or_default()
+    }
+
+    /// Returns the theme named in the file, one of its own or a built-in one, or a message saying
+    /// why it cannot be used.
+    fn named_theme(&self) -> Result<Theme, String> {
+        let name = self.theme.as_deref().unwrap_or("default");
+        match self.themes.get(name) {
+            Some(settings) => settings.apply(),
+            None => Theme::named(name).ok_or_else(|| {
+                format!(
+                    "'{}' is not a theme, choose one of: {} or one defined in [themes]",
+                    name,
+                    Theme::NAMES.join(", ")
+                )
+            }),
+        }
+    }
+
+    /// Returns the theme to draw the game with, with the `card_back` if one is set, made colour-blind
+    /// friendly if `colorblind` is set, and without its colours if colours are off.
+    pub fn theme(&self) -> Theme {
+        if self.color() {
+            self.full_theme()
+        } else {
+            self.full_theme().without_colors()
+        }
+    }
+
+    /// Returns the theme to draw a window with, as `theme()` does, except that a window is not a
+    /// terminal: its colours are only left out for plain output or when colours are "never" used.
+    #[cfg(feature = "gui")]
+    pub fn window_theme(&self) -> Theme {
+        if self.plain || self.color.as_deref() == Some("never") {
+            self.full_theme().without_colors()
+        } else {
+            self.full_theme()
+        }
+    }
+
+    /// Returns the theme with the `card_back` if one is set, made colour-blind friendly if
+    /// `colorblind` is set, whether or not colours are on.
+    fn full_theme(&self) -> Theme {
+        // The theme and card back were checked when the file was loaded
+        let mut theme = self.named_theme().unwrap_or_default();
+        if let Some(back) = self.card_back.as_deref().and_then(CardBack::from_name) {
+            theme.back = back;
+        }
+        if self.colorblind {
+            theme.colorblind()
+        } else {
+            theme
+        }
+    }
+
+    /// Creates the table view for the layout named with `--layout` or in the file, showing chips
+    /// with `currency`, for cards drawn with the viewer named with `--view` or in the file.
+    pub fn table_view(&self, currency: CurrencyFormat) -> Box<dyn TableView> {
+        self.table_view_for(self.viewer.as_deref().unwrap_or("glyph"), currency)
+    }
+
+    /// Creates the table view for the layout named with `--layout` or in the file, showing chips
+    /// with `currency`. If none is named, the table is said in sentences when cards are drawn with
+    /// the `viewer` called "spoken", and written one line per hand otherwise. At a terminal, and
+    /// unless the output is plain, the status line is kept on the terminal's last row.
+    pub fn table_view_for(&self, viewer: &str, currency: CurrencyFormat) -> Box<dyn TableView> {
+        let layout = self.layout.as_deref().unwrap_or(match viewer {
+            "spoken" => "spoken",
+            _ => "lines",
+        });
+        let status_row = if !self.plain && io::stdout().is_terminal() {
+            terminal::size().ok().map(|(_, rows)| rows)
+        } else {
+            None
+        };
+        let locale = self.locale();
+        table_view_from_name(layout, currency.clone(), &self.theme(), &locale, status_row)
+            .unwrap_or_else(|| {
+                Box::new(TextTableView {
+                    currency,
+                    locale: self.locale(),
+                })
+            })
+    }
+
+    /// Reads the dealer's chatter pack: the built-in pack named by `chatter`, or else the pack in
+    /// the file at that path. Returns `None` if no pack is chosen.
+    fn read_chatter(&self) -> Result<Option<Chatter>, String> {
+        let Some(pack) = self.chatter.as_deref() else {
+            return Ok(None);
+        };
+        if let Some(chatter) = Chatter::named(pack) {
+            return Ok(Some(chatter));
+        }
+        let unreadable = |error: String| format!("chatter pack '{}': {}", pack, error);
+        let text = fs::read_to_string(pack).map_err(|error| unreadable(error.to_string()))?;
+        Chatter::parse(pack, &text)
+            .map(Some)
+            .map_err(|error| unreadable(error.to_string()))
+    }
+
+    /// Returns the dealer's chatter pack, if one is chosen and `--terse` was not given.
+    pub fn chatter(&self) -> Option<Chatter> {
+        // The pack was checked when the file was loaded
+        self.read_chatter().ok().flatten()
+    }
+
+    /// Returns the locale named with `--lang` or in the file, or English if none is named.
+    pub fn locale(&self) -> Locale {
+        // The name was checked when the file was loaded
+        self.lang
+            .as_deref()
+            .and_then(Locale::named)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether to write ANSI colours. Plain output is never coloured; otherwise "always" and
+    /// "never" say, and "auto", the default, colours a terminal unless it is dumb or `NO_COLOR` is
+    /// set.
+    pub fn color(&self) -> bool {
+        if self.plain {
+            return false;
+        }
+        match self.color.as_deref() {
+            Some("always") => true,
+            Some("never") => false,
+            _ => {
+                io::stdout().is_terminal()
+                    && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                    && env::var("TERM").map_or(true, |term| term != "dumb")
+            }
+        }
+    }
+
+    /// Returns the pause before each card dealt to the player is shown.
+    pub fn deal_delay(&self) -> Duration {
+        self.delay(self.deal_delay, 300)
+    }
+
+    /// Returns the pause before the dealer turns over the hole card and before each card the dealer
+    /// draws.
+    pub fn dealer_delay(&self) -> Duration {
+        self.delay(self.dealer_delay, 700)
+    }
+
+    /// Returns the pause of `delay` milliseconds if it is set, or of `default` milliseconds when
+    /// the game is played at a terminal; plain output, and output read by another program, is not
+    /// held up.
+    fn delay(&self, delay: Option<u64>, default: u64) -> Duration {
+        let paced = !self.plain && io::stdout().is_terminal();
+        Duration::from_millis(delay.unwrap_or(if paced { default } else { 0 }))
+    }
+
+    /// Creates the viewer named with `--view` or in the file, drawing cards with glyphs if none is
+    /// named.
+    pub fn viewer(&self) -> Box<dyn Renderer> {
+        self.viewer
+            .as_deref()
+            .and_then(|viewer| viewer_from_name(viewer, &self.theme(), &self.locale()))
+            .unwrap_or_else(|| {
+                Box::new(CardGlyphViewer {
+                    theme: self.theme(),
+                    locale: self.locale(),
+                })
+            })
+    }
+}
+
+/// A theme defined in the config file: the built-in theme named `base`, or the default theme, with
+/// any of its colours, its border, its suit symbols and its card back changed.
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeSettings {
+    pub base: Option<String>,
+    pub red_suits: Option<String>,
+    pub black_suits: Option<String>,
+    pub win: Option<String>,
+    pub blackjack: Option<String>,
+    pub bust: Option<String>,
+    pub felt: Option<String>,
+    pub border: Option<String>,
+    pub suits: Option<String>,
+    pub back: Option<String>,
+}
+
+impl ThemeSettings {
+    /// Returns the theme these settings make, or a message saying which setting cannot be used.
+    pub fn apply(&self) -> Result<Theme, String> {
+        let base = self.base.as_deref().unwrap_or("default");
+        let mut theme = Theme::named(base).ok_or_else(|| {
+            format!(
+                "base: '{}' is not a built-in theme, choose one of: {}",
+                base,
+                Theme::NAMES.join(", ")
+            )
+        })?;
+        for (name, setting, color) in [
+            ("red_suits", &self.red_suits, &mut theme.red_suits),
+            ("black_suits", &self.black_suits, &mut theme.black_suits),
+            ("win", &self.win, &mut theme.win),
+            ("blackjack", &self.blackjack, &mut theme.blackjack),
+            ("bust", &self.bust, &mut theme.bust),
+            ("felt", &self.felt, &mut theme.felt),
+        ] {
+            if let Some(setting) = setting {
+                *color = Theme::color_code(setting)
+                    .ok_or_else(|| format!("{}: '{}' is not a colour", name, setting))?;
+            }
+        }
+        if let Some(border) = &self.border {
+            theme.border = Border::from_name(border).ok_or_else(|| {
+                format!(
+                    "border: '{}' is not a border, choose one of: {}",
+                    border,
+                    Border::NAMES.join(", ")
+                )
+            })?;
+        }
+        if let Some(suits) = &self.suits {
+            theme.suits = SuitSymbols::from_name(suits).ok_or_else(|| {
+                format!(
+                    "suits: '{}' is not a set of suit symbols, choose one of: {}",
+                    suits,
+                    SuitSymbols::NAMES.join(", ")
+                )
+            })?;
+        }
+        if let Some(back) = &self.back {
+            theme.back = CardBack::from_name(back).ok_or_else(|| {
+                format!(
+                    "back: '{}' is not a card back, choose one of: {}",
+                    back,
+                    CardBack::NAMES.join(", ")
+                )
+            })?;
+        }
+        Ok(theme)
+    }
+}
+
+/// Single rules in the config file that change those of the preset played, each left as the preset
+/// has it if not set.
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HouseRules {
+    pub decks: Option<usize>,
+    pub dealer_hits_soft_17: Option<bool>,
+    pub double_after_split: Option<bool>,
+    pub surrender: Option<bool>,
+    pub blackjack_pays: Option<String>,
+    pub dealer_peeks: Option<bool>,
+    pub max_hands: Option<usize>,
+    pub charlie: Option<usize>,
+    pub penetration: Option<f64>,
+}
+
+impl HouseRules {
+    /// Returns `rules` with the house rules applied, or a message saying why the payout for a
+    /// natural cannot be read as a ratio such as "6:5".
+    pub fn apply(&self, mut rules: RuleSet) -> Result<RuleSet, String> {
+        if let Some(pays) = &self.blackjack_pays {
+            let ratio = pays.split_once(':').and_then(|(paid, staked)| {
+                Some((paid.trim().parse().ok()?, staked.trim().parse().ok()?))
+            });
+            let Some((paid, staked)) = ratio else {
+                return Err(format!(
+                    "blackjack_pays: '{}' is not a ratio such as \"3:2\"",
+                    pays
+                ));
+            };
+            rules.blackjack_pays = Payout(paid, staked);
+        }
+        rules.decks = self.decks.unwrap_or(rules.decks);
+        rules.dealer_hits_soft_17 = self
+            .dealer_hits_soft_17
+            .unwrap_or(rules.dealer_hits_soft_17);
+        rules.double_after_split = self.double_after_split.unwrap_or(rules.double_after_split);
+        rules.surrender = self.surrender.unwrap_or(rules.surrender);
+        rules.dealer_peeks = self.dealer_peeks.unwrap_or(rules.dealer_peeks);
+        rules.max_hands = self.max_hands.unwrap_or(rules.max_hands);
+        if let Some(cards) = self.charlie {
+            rules.charlie = Some(cards).filter(|&cards| cards > 0);
+        }
+        rules.penetration = self.penetration.unwrap_or(rules.penetration);
+        Ok(rules)
+    }
+}
+
+/// Sets the top-level `key` in the lines of a config file to `value`, or removes it if `value` is
+/// `None`.
+///
+/// A key that is not set yet is written below its commented-out example if there is one, and
+/// otherwise before the first table, so it is not read as part of that table.
+fn set_line(lines: &mut Vec<String>, key: &str, value: Option<String>) {
+    // Top-level keys all come before the first table
+    let top_level = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+    let sets = |line: &str, key: &str| {
+        line.trim_start()
+            .strip_prefix(key)
+            .is_some_and(|rest| rest.trim_start().starts_with('='))
+    };
+    let existing = lines[..top_level].iter().position(|line| sets(line, key));
+    match (existing, value) {
+        (Some(index), Some(value)) => lines[index] = format!("{} = {}", key, value),
+        (Some(index), None) => {
+            lines.remove(index);
+        }
+        (None, Some(value)) => {
+            let example = lines[..top_level].iter().position(|line| {
+                line.trim_start()
+                    .strip_prefix('#')
+                    .is_some_and(|line| sets(line, key))
+            });
+            let index = example.map_or(top_level, |index| index + 1);
+            lines.insert(index, format!("{} = {}", key, value));
+        }
+        (None, None) => {}
+    }
+}
+
+/// Lets the player change the settings in the config file at `path`, or at the default path if none
+/// is given, one at a time until they are done, asking in `locale`.
+///
+/// Each change is written to the file straight away, so it is used by every game started afterwards.
+pub fn edit(
+    config: &mut Config,
+    path: Option<&Path>,
+    locale: &Locale,
+) -> Result<(), BlackjackError> {
+    loop {
+        let card_back = match config.card_back.clone() {
+            Some(back) => back,
+            None => locale.text("setting-theme-back"),
+        };
+        let choice = prompt_choice(
+            &locale.message(
+                "settings-menu",
+                &[
+                    ("rules", &config.rules.as_deref().unwrap_or("classic")),
+                    ("viewer", &config.viewer.as_deref().unwrap_or("glyph")),
+                    ("bankroll", &config.bankroll.unwrap_or(STARTING_BANKROLL)),
+                    (
+                        "colorblind",
+                        &locale.text(if config.colorblind {
+                            "setting-on"
+                        } else {
+                            "setting-off"
+                        }),
+                    ),
+                    ("back", &card_back),
+                ],
+            ),
+            &locale.text("settings-invalid"),
+            &["1", "2", "3", "4", "5", "d"],
+        )?;
+        match choice.as_str() {
+            "1" => {
+                config.rules =
+                    choose_name("setting-rules", &preset_list(), locale)?.or(config.rules.take())
+            }
+            "2" => {
+                config.viewer =
+                    choose_name("setting-viewer", &VIEWER_NAMES, locale)?.or(config.viewer.take())
+            }
+            "3" => {
+                let bankroll = prompt_until_valid(
+                    &locale.text("setting-bankroll-prompt"),
+                    &locale.text("setting-bankroll-invalid"),
+                    |choice| {
+                        choice.is_empty() || choice.parse::<u32>().is_ok_and(|chips| chips > 0)
+                    },
+                )?;
+                if let Ok(bankroll) = bankroll.parse::<u32>() {
+                    config.bankroll = Some(bankroll);
+                }
+            }
+            "4" => config.colorblind = !config.colorblind,
+            "5" => {
+                config.card_back = choose_name("setting-card-back", &CardBack::NAMES, locale)?
+                    .or(config.card_back.take())
+            }
+            _ => return Ok(()),
+        }
+        config.save(path)?;
+        let saved_to = path.map_or_else(Config::default_path, Path::to_path_buf);
+        println!(
+            "{}",
+            locale.message("settings-saved", &[("path", &saved_to.display())])
+        );
+    }
+}
+
+/// Asks for one of `names` by its number, or returns `None` if the player presses Enter to keep the
+/// current setting, named by the key `setting` in `locale`.
+fn choose_name(
+    setting: &str,
+    names: &[&str],
+    locale: &Locale,
+) -> Result<Option<String>, BlackjackError> {
+    let options = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| format!("({}) {}", index + 1, name))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let numbers: Vec<String> = (0..=names.len())
+        .map(|index| match index {
+            0 => String::new(),
+            index => index.to_string(),
+        })
+        .collect();
+    let choice = prompt_choice(
+        &locale.message(
+            "setting-choose",
+            &[("setting", &locale.text(setting)), ("options", &options)],
+        ),
+        &locale.message("number-invalid", &[("count", &names.len())]),
+        &numbers.iter().map(String::as_str).collect::<Vec<&str>>(),
+    )?;
+    Ok(choice
+        .parse::<usize>()
+        .ok()
+        .map(|index| names[index - 1].to_string()))
+}
+
+/// Returns the names of the rule presets.
+fn preset_list() -> Vec<&'static str> {
+    RuleSet::PRESETS.iter().map(|(name, _)| *name).collect()
+}
+
+/// Writes the commented starter file to `path`, or to the default path if none is given, saying
+/// so in `locale`.
+///
+/// An existing file is only replaced if `force` is set, so settings are not lost by accident.
+pub fn init(path: Option<&Path>, force: bool, locale: &Locale) -> Result<(), BlackjackError> {
+    let path = path.map_or_else(Config::default_path, Path::to_path_buf);
+    if path.exists() && !force {
+        println!(
+            "{}",
+            locale.message("config-exists", &[("path", &path.display())])
+        );
+        return Ok(());
+    }
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(&path, STARTER)?;
+    println!(
+        "{}",
+        locale.message("config-written", &[("path", &path.display())])
+    );
+    Ok(())
+}
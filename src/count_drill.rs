@@ -0,0 +1,309 @@
+use blackjack_core::card::Rank;
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{CountingSystem, Locale};
+use blackjack_core::hand::Hand;
+use blackjack_core::shoe::Shoe;
+use blackjack_core::{entropy, PlayerInput};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cli::{Cli, CountDrillArgs};
+use crate::config::Config;
+use crate::data_directory;
+use crate::keypress::KeypressInput;
+
+/// How many of the latest drills the summary after a drill covers.
+const RECENT_DRILLS: usize = 10;
+
+/// One finished drill, as recorded in the history file: how long each card was shown, how many
+/// were dealt, how many of the counts asked for were right, how long the answers took in all and the
+/// name of the counting system kept.
+#[derive(Clone)]
+struct DrillResult {
+    pace: u64,
+    cards: usize,
+    correct: u32,
+    asked: u32,
+    answer_time: Duration,
+    system: String,
+}
+
+impl DrillResult {
+    /// Reads a result from a line of the history file, such as `800,52,2,3,5400,hi-lo`, or returns
+    /// `None` if the line is not one. Drills recorded before systems could be chosen kept Hi-Lo.
+    fn parse(line: &str) -> Option<DrillResult> {
+        let mut fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let system = match fields.len() {
+            6 => fields.pop()?.to_string(),
+            _ => "hi-lo".to_string(),
+        };
+        let numbers: Vec<u64> = fields
+            .iter()
+            .map(|field| field.parse().ok())
+            .collect::<Option<_>>()?;
+        let [pace, cards, correct, asked, answer_ms] = numbers[..] else {
+            return None;
+        };
+        Some(DrillResult {
+            pace,
+            cards: cards as usize,
+            correct: correct as u32,
+            asked: asked as u32,
+            answer_time: Duration::from_millis(answer_ms),
+            system,
+        })
+    }
+
+    /// Returns the result written as a line of the history file.
+    fn line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}\n",
+            self.pace,
+            self.cards,
+            self.correct,
+            self.asked,
+            self.answer_time.as_millis(),
+            self.system
+        )
+    }
+}
+
+/// Returns the path of the file that records every counting drill finished, one per line.
+fn history_path() -> PathBuf {
+    data_directory().join("count_drills.txt")
+}
+
+/// Deals cards face up one at a time, each shown for `args.pace` milliseconds before the next
+/// replaces it, and asks for the running count by `args.system` once `args.cards` have been dealt. With
+/// `args.checkpoints` the count is also asked for at random points along the way. With `args.aces`
+/// the Aces are side counted too, and each count is only right if the Aces dealt are given with it.
+/// Entering 'q' when asked stops the drill early.
+///
+/// Every answer is scored and timed, and the drill is added to a history file in the data
+/// directory, so the summary at the end shows how the latest drills with the same system went as
+/// well: how many counts were right, how fast the cards came and how long the answers took. An
+/// unbalanced system's count starts from below zero, as it does at the table. The cards are dealt from a
+/// shoe of as many decks as `--rules` says, shuffled with `--seed` if given, drawn with the viewer
+/// named in the config file and asked about in its locale.
+pub fn run(cli: &Cli, args: &CountDrillArgs, config: &Config) -> Result<(), BlackjackError> {
+    let rules = cli.rules.unwrap_or_default();
+    let system = CountingSystem::from_name(&args.system).unwrap_or_default();
+    let mut entropy = match cli.seed {
+        Some(seed) => entropy::seeded(seed),
+        None => entropy::from_system(),
+    };
+    let mut shoe = Shoe::with_penetration(rules.decks, rules.penetration);
+    shoe.shuffle(&mut *entropy);
+    let cards = (args.cards as usize).min(shoe.cards_remaining());
+    let pace = Duration::from_millis(args.pace);
+    let viewer = config.viewer();
+    let locale = config.locale();
+    let mut input = KeypressInput;
+    let mut out = io::stdout();
+    let mut result = DrillResult {
+        pace: args.pace,
+        cards: 0,
+        correct: 0,
+        asked: 0,
+        answer_time: Duration::ZERO,
+        system: args.system.clone(),
+    };
+    let mut running_count = system.initial_count(rules.decks);
+    let mut aces = 0;
+
+    println!(
+        "{}",
+        locale.message(
+            "count-drill-start",
+            &[("system", &system.name), ("cards", &cards)],
+        )
+    );
+    if args.aces {
+        println!("{}", locale.text("count-drill-side-count"));
+    }
+    if running_count != 0 {
+        let count = format!("{:+}", running_count);
+        println!(
+            "{}",
+            locale.message("count-drill-starts-from", &[("count", &count)])
+        );
+    }
+    while result.cards < cards {
+        let card = shoe.draw()?;
+        running_count += system.tag(&card);
+        if card.rank == Rank::Ace {
+            aces += 1;
+        }
+        result.cards += 1;
+        let mut shown = Hand::new();
+        shown.add(card);
+        // Each card is drawn over the one before, so only one is ever in sight
+        write!(out, "\r{:<24}\r", "")?;
+        viewer.render(&shown, &mut out)?;
+        out.flush()?;
+        thread::sleep(pace);
+
+        let last = result.cards == cards;
+        let checkpoint = args.checkpoints && entropy.range(1, 10) == 1;
+        if !last && !checkpoint {
+            continue;
+        }
+        write!(out, "\r{:<24}\r", "")?;
+        out.flush()?;
+        let side_count = args.aces.then_some(aces);
+        let Some(correct) = ask_count(&mut input, &locale, running_count, side_count, &mut result)?
+        else {
+            break;
+        };
+        let count = format!("{:+}", running_count);
+        let message = match (correct, side_count) {
+            (true, None) => locale.message("count-drill-correct", &[("count", &count)]),
+            (false, None) => locale.message("count-drill-answer", &[("count", &count)]),
+            (correct, Some(aces)) => {
+                let dealt = match aces {
+                    1 => locale.text("count-drill-one-ace"),
+                    aces => locale.message("count-drill-aces", &[("aces", &aces)]),
+                };
+                let key = match correct {
+                    true => "count-drill-correct-with-aces",
+                    false => "count-drill-answer-with-aces",
+                };
+                locale.message(key, &[("count", &count), ("dealt", &dealt)])
+            }
+        };
+        println!("{}", message);
+    }
+
+    println!(
+        "{}",
+        locale.message(
+            "count-drill-score",
+            &[
+                ("correct", &result.correct),
+                ("asked", &result.asked),
+                ("cards", &result.cards),
+            ],
+        )
+    );
+    if result.asked == 0 {
+        return Ok(());
+    }
+    let mut history = fs::read_to_string(history_path()).unwrap_or_default();
+    history.push_str(&result.line());
+    fs::create_dir_all(data_directory())?;
+    fs::write(history_path(), &history)?;
+    report(&history, &result.system, system, &locale);
+    Ok(())
+}
+
+/// Asks in `locale` for the running count, and for the Aces dealt if they are side counted, adding
+/// the answer and how long it took to `result`. Returns whether the count was `running_count` and
+/// the Aces `aces`, or `None` if the player enters 'q'.
+fn ask_count(
+    input: &mut dyn PlayerInput,
+    locale: &Locale,
+    running_count: i32,
+    aces: Option<usize>,
+    result: &mut DrillResult,
+) -> Result<Option<bool>, BlackjackError> {
+    let asked = Instant::now();
+    let Some(count) = ask_number(
+        input,
+        &locale.text("count-drill-count-prompt"),
+        &locale.text("count-drill-count-invalid"),
+    )?
+    else {
+        return Ok(None);
+    };
+    let mut correct = count == running_count;
+    if let Some(aces) = aces {
+        let Some(answer) = ask_number(
+            input,
+            &locale.text("count-drill-aces-prompt"),
+            &locale.text("count-drill-aces-invalid"),
+        )?
+        else {
+            return Ok(None);
+        };
+        correct &= answer == aces as i32;
+    }
+    result.asked += 1;
+    result.answer_time += asked.elapsed();
+    if correct {
+        result.correct += 1;
+    }
+    Ok(Some(correct))
+}
+
+/// Asks `question` until a whole number is entered, saying `invalid` after anything else, and
+/// returns it, or `None` if the player enters 'q'.
+fn ask_number(
+    input: &mut dyn PlayerInput,
+    question: &str,
+    invalid: &str,
+) -> Result<Option<i32>, BlackjackError> {
+    loop {
+        println!("{}", question);
+        let answer = input.read_choice()?;
+        let answer = answer.trim();
+        if answer == "q" {
+            return Ok(None);
+        }
+        // A positive count is often written with its sign, as the game shows it
+        match answer.trim_start_matches('+').parse() {
+            Ok(number) => return Ok(Some(number)),
+            Err(_) => println!("{}", invalid),
+        }
+    }
+}
+
+/// Prints in `locale` how the latest drills recorded in `history` with the system called `name`
+/// went, altogether: the counts right, the average pace the cards came at and the average time an
+/// answer took.
+fn report(history: &str, name: &str, system: CountingSystem, locale: &Locale) {
+    let results: Vec<DrillResult> = history
+        .lines()
+        .filter_map(DrillResult::parse)
+        .filter(|result| result.system == name)
+        .collect();
+    let recent = &results[results.len().saturating_sub(RECENT_DRILLS)..];
+    let correct: u32 = recent.iter().map(|result| result.correct).sum();
+    let asked: u32 = recent.iter().map(|result| result.asked).sum();
+    let pace = recent.iter().map(|result| result.pace).sum::<u64>() / recent.len() as u64;
+    let answer_time: Duration = recent.iter().map(|result| result.answer_time).sum();
+    let drills = match recent.len() {
+        1 => locale.message("count-drill-one-drill", &[("system", &system.name)]),
+        drills => locale.message(
+            "count-drill-drills",
+            &[("drills", &drills), ("system", &system.name)],
+        ),
+    };
+    let percentage = format!("{:.0}", 100.0 * correct as f64 / asked.max(1) as f64);
+    let seconds = format!("{:.1}", answer_time.as_secs_f64() / asked.max(1) as f64);
+    println!(
+        "{}",
+        locale.message(
+            "count-drill-report",
+            &[
+                ("drills", &drills),
+                ("correct", &correct),
+                ("asked", &asked),
+                ("percentage", &percentage),
+                ("pace", &pace),
+                ("seconds", &seconds),
+            ],
+        )
+    );
+    // The fastest pace kept up with is the one to beat
+    if let Some(best) = results
+        .iter()
+        .filter(|result| result.correct == result.asked)
+        .map(|result| result.pace)
+        .min()
+    {
+        println!("{}", locale.message("count-drill-best", &[("pace", &best)]));
+    }
+}
@@ -0,0 +1,210 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{BuildError, CurrencyFormat, GameBuilder, GameController, RuleSet};
+use blackjack_core::{GameEngine, Renderer};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::data_directory;
+
+/// The number of hands played in a daily challenge.
+pub const DAILY_HANDS: u32 = 20;
+
+/// A date on the calendar, used to seed the daily challenge.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Date {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    /// Returns today's date in UTC, so players in every time zone share the same challenge.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Date::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Converts a count of days since 1970-01-01 into a calendar date.
+    pub fn from_days(days: i64) -> Self {
+        // Howard Hinnant's civil_from_days algorithm, counting in 400-year eras starting in March
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Date { year, month, day }
+    }
+
+    /// Returns the date as a number such as 20240131, which seeds the challenge's shoe.
+    pub fn number(&self) -> u64 {
+        (self.year as u64) * 10_000 + u64::from(self.month) * 100 + u64::from(self.day)
+    }
+}
+
+impl std::fmt::Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Returns a short checksum of a challenge result, so shared result codes cannot be edited by hand.
+pub fn result_checksum(date: Date, score: u32) -> u16 {
+    // FNV-1a over the date and score
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in format!("{}:{}", date.number(), score).bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    (hash ^ (hash >> 16)) as u16
+}
+
+/// A controller that plays the daily challenge: a fixed number of hands dealt from shoes seeded by the date.
+///
+/// Every player who takes on the challenge on the same day faces the identical cards, because each hand
+/// is dealt from a fresh single-deck shoe shuffled with a seed derived from the date and the hand number. The score is
+/// the bankroll left at the end, which is compared against the best score recorded on this machine for
+/// that day, and reported as a result code that can be shared with other players.
+pub struct DailyChallenge {
+    pub game: GameController,
+    pub date: Date,
+}
+
+impl DailyChallenge {
+    /// Creates today's challenge at a classic blackjack table dealt from a single deck.
+    pub fn new(viewer: Box<dyn Renderer>, currency: CurrencyFormat) -> Result<Self, BuildError> {
+        let rules = RuleSet {
+            decks: 1,
+            ..RuleSet::default()
+        };
+        Ok(DailyChallenge {
+            game: GameBuilder::new(viewer)
+                .rules(rules)
+                .currency(currency)
+                .build()?,
+            date: Date::today(),
+        })
+    }
+
+    /// Returns the path of the file that records the best score for each day.
+    pub fn records_path() -> PathBuf {
+        data_directory().join("daily.txt")
+    }
+
+    /// Returns the best score recorded for the challenge's day, if it has been played before.
+    pub fn best_score(&self) -> Option<u32> {
+        let contents = fs::read_to_string(DailyChallenge::records_path()).ok()?;
+        let date = self.date.to_string();
+        contents
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .find(|(day, _)| *day == date)
+            .and_then(|(_, score)| score.parse().ok())
+    }
+
+    /// Records `score` as the best for the challenge's day, replacing any earlier record for that day.
+    pub fn record_best_score(&self, score: u32) -> io::Result<()> {
+        let path = DailyChallenge::records_path();
+        let date = self.date.to_string();
+        let mut contents: String = fs::read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .filter(|line| line.split_once('=').map(|(day, _)| day) != Some(date.as_str()))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        contents.push_str(&format!("{}={}\n", date, score));
+
+        fs::create_dir_all(data_directory())?;
+        fs::write(path, contents)
+    }
+
+    /// Returns a code summarising the result, such as `BJ-20240131-1240-7F3A`.
+    pub fn result_code(&self, score: u32) -> String {
+        format!(
+            "BJ-{}-{}-{:04X}",
+            self.date.number(),
+            score,
+            result_checksum(self.date, score)
+        )
+    }
+}
+
+impl GameEngine for DailyChallenge {
+    fn run(&mut self) -> Result<bool, BlackjackError> {
+        let locale = self.game.locale.clone();
+        writeln!(
+            self.game.output,
+            "{}",
+            locale.message(
+                "daily-start",
+                &[("date", &self.date), ("hands", &DAILY_HANDS)],
+            )
+        )?;
+
+        for hand in 1..=DAILY_HANDS {
+            if !self.game.can_cover_minimum() {
+                writeln!(self.game.output, "{}", locale.text("daily-cannot-cover"))?;
+                break;
+            }
+            writeln!(
+                self.game.output,
+                "{}",
+                locale.message("daily-hand", &[("hand", &hand), ("hands", &DAILY_HANDS)])
+            )?;
+            self.game
+                .engine
+                .seed(self.date.number() * 100 + u64::from(hand));
+            self.game.play_round()?;
+            if self.game.quit_requested {
+                writeln!(self.game.output, "{}", locale.text("daily-leave"))?;
+                return Ok(false);
+            }
+        }
+
+        let score = self.game.bankroll().balance();
+        writeln!(
+            self.game.output,
+            "{}",
+            locale.message(
+                "daily-finished",
+                &[
+                    ("date", &self.date),
+                    ("amount", &self.game.currency.draw(score)),
+                ],
+            )
+        )?;
+        match self.best_score() {
+            Some(best) if best >= score => writeln!(
+                self.game.output,
+                "{}",
+                locale.message("daily-best", &[("amount", &self.game.currency.draw(best))])
+            )?,
+            previous => {
+                if previous.is_some() {
+                    writeln!(self.game.output, "{}", locale.text("daily-new-best"))?;
+                }
+                if let Err(error) = self.record_best_score(score) {
+                    eprintln!("{}", locale.message("daily-unsaved", &[("error", &error)]));
+                }
+            }
+        }
+        writeln!(
+            self.game.output,
+            "{}",
+            locale.message("daily-share", &[("code", &self.result_code(score))])
+        )?;
+        Ok(false)
+    }
+}
@@ -0,0 +1,157 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Action, BasicStrategy, CurrencyFormat, GameBuilder, GameView, Locale, Odds, PlayerStrategy,
+};
+use blackjack_core::{GameEngine, PlayerInput};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::cli::{Cli, DemoArgs};
+use crate::config::Config;
+use crate::keypress::wait_for_key;
+
+/// Lets a basic strategy bot play a cash game on its own, for showing the game off or leaving it
+/// running on a spare screen, until a key is pressed.
+///
+/// The bot waits `args.pace` before each decision and between rounds, and before each decision says
+/// why it plays as it does: the chance of busting if it hits and the dealer's likeliest total. It
+/// buys back in whenever it runs out of chips. Once a key is pressed the round is played out without
+/// waiting and the demo returns, so the caller can start a real game. The table is drawn as
+/// `play` draws it, dealt by `--rules` and from a shoe seeded with `--seed` if given.
+pub fn run(cli: &Cli, args: &DemoArgs, config: &Config) -> Result<(), BlackjackError> {
+    let pace = Duration::from_secs(args.pace);
+    let stopped = Rc::new(Cell::new(false));
+    let locale = config.locale();
+    let mut builder = GameBuilder::new(config.viewer())
+        .strategy(Box::new(Commentator {
+            locale: locale.clone(),
+            pace,
+            stopped: Rc::clone(&stopped),
+        }))
+        .table_view(config.table_view(CurrencyFormat::DOLLARS))
+        .deal_delay(config.deal_delay())
+        .dealer_delay(config.dealer_delay())
+        .plain(config.plain)
+        .color(config.color())
+        .theme(config.theme())
+        .locale(locale.clone());
+    if let Some(rules) = cli.rules {
+        builder = builder.rules(rules);
+    }
+    if let Some(seed) = cli.seed {
+        builder = builder.seed(seed);
+    }
+    if let Some(chatter) = config.chatter() {
+        builder = builder.chatter(chatter);
+    }
+    let mut controller = builder.build()?;
+    controller.input = Box::new(DemoInput {
+        pace,
+        stopped: Rc::clone(&stopped),
+    });
+
+    println!("{}", locale.text("demo-start"));
+    while controller.run()? {}
+    println!("{}", locale.text("demo-over"));
+    Ok(())
+}
+
+/// Plays by basic strategy, saying before each decision why, and waits for the demo's pace after
+/// it unless a key has been pressed.
+struct Commentator {
+    locale: Locale,
+    pace: Duration,
+    stopped: Rc<Cell<bool>>,
+}
+
+impl Commentator {
+    /// Returns what the bot says about the hand it is deciding on in `view`, or `None` if it
+    /// cannot hit it or holds 21, as there is then nothing to weigh.
+    fn commentary(&self, view: &GameView) -> Option<String> {
+        let (Some(hand), Some(up_card)) = (&view.hand, view.dealer_up_card) else {
+            return None;
+        };
+        if !view.legal_actions.contains(&Action::Hit) || hand.calculate_hand_total() == 21 {
+            return None;
+        }
+        // The bot cannot see the shoe, so it reckons from a full deck
+        let odds = Odds::new(
+            hand,
+            up_card,
+            &[],
+            &*view.rules.dealer_policy(),
+            view.rules.dealer_peeks,
+        );
+        let (total, chance) = odds.dealer_totals.first()?;
+        let total = match total {
+            Some(total) => total.to_string(),
+            None => self.locale.text("hint-bust"),
+        };
+        let percent = |chance: f64| format!("{:.0}%", chance * 100.0);
+        Some(self.locale.message(
+            "demo-commentary",
+            &[
+                ("hand", &self.locale.value(hand.value())),
+                ("up", &self.locale.card_in_words(&up_card)),
+                ("bust", &percent(odds.bust_if_hit)),
+                ("total", &total),
+                ("chance", &percent(*chance)),
+            ],
+        ))
+    }
+
+    /// Waits for the demo's pace, or not at all once a key has been pressed, and notes a key
+    /// pressed meanwhile.
+    fn wait(&self) -> Result<(), BlackjackError> {
+        if !self.stopped.get() && wait_for_key(self.pace)? {
+            self.stopped.set(true);
+        }
+        Ok(())
+    }
+}
+
+impl PlayerStrategy for Commentator {
+    fn name(&self) -> String {
+        BasicStrategy.name()
+    }
+
+    fn bet(&mut self, view: &GameView) -> Result<u32, BlackjackError> {
+        BasicStrategy.bet(view)
+    }
+
+    fn decide(&mut self, view: &GameView) -> Result<Action, BlackjackError> {
+        if let Some(commentary) = self.commentary(view) {
+            println!("{}", commentary);
+        }
+        self.wait()?;
+        BasicStrategy.decide(view)
+    }
+}
+
+/// Answers the questions asked between rounds, whether to play again and whether to buy back in:
+/// yes after the demo's pace, or no once a key has been pressed.
+struct DemoInput {
+    pace: Duration,
+    stopped: Rc<Cell<bool>>,
+}
+
+impl PlayerInput for DemoInput {
+    /// The bot places its own bets, so no other choice is asked for.
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        Ok(String::new())
+    }
+
+    fn read_key(
+        &mut self,
+        _keys: &[&str],
+        _limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        if !self.stopped.get() && wait_for_key(self.pace)? {
+            self.stopped.set(true);
+        }
+        let answer = if self.stopped.get() { "n" } else { "y" };
+        println!("{}", answer);
+        Ok(Some(answer.to_string()))
+    }
+}
@@ -0,0 +1,342 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Action, Bankroll, ChipView, Classic, CurrencyFormat, Engine, GameState, Locale, Outcome,
+    TableLimits, Theme, STARTING_BANKROLL,
+};
+use blackjack_core::hand::Hand;
+use eframe::egui::{self, Align2, Color32, FontId, RichText, Sense, Stroke, StrokeKind, Ui, Vec2};
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::cli::{Cli, PlayArgs};
+use crate::config::Config;
+use crate::replay;
+
+/// The size each card is drawn at.
+const CARD_SIZE: Vec2 = Vec2::new(64.0, 90.0);
+
+/// The colour of the table the cards are laid on.
+const FELT: Color32 = Color32::from_rgb(20, 90, 50);
+
+/// The colour of the backs of face-down cards.
+const CARD_BACK: Color32 = Color32::from_rgb(40, 60, 140);
+
+/// The colours of the ANSI codes 30 to 37, which the theme's colours are given as.
+const ANSI_COLORS: [Color32; 8] = [
+    Color32::BLACK,
+    Color32::from_rgb(200, 30, 30),
+    Color32::from_rgb(30, 150, 60),
+    Color32::from_rgb(200, 160, 20),
+    Color32::from_rgb(40, 60, 180),
+    Color32::from_rgb(160, 40, 160),
+    Color32::from_rgb(30, 150, 170),
+    Color32::from_rgb(220, 220, 220),
+];
+
+/// The colours of the bright ANSI codes 90 to 97.
+const BRIGHT_ANSI_COLORS: [Color32; 8] = [
+    Color32::GRAY,
+    Color32::from_rgb(255, 80, 80),
+    Color32::from_rgb(80, 230, 100),
+    Color32::from_rgb(255, 220, 60),
+    Color32::from_rgb(90, 130, 255),
+    Color32::from_rgb(230, 90, 230),
+    Color32::from_rgb(80, 220, 230),
+    Color32::WHITE,
+];
+
+/// Plays classic blackjack in a window of its own, with the cards drawn as shapes on the table and
+/// every decision taken with a button.
+///
+/// The window drives the headless `Engine`, as the JSON mode and the terminal's `GameController`
+/// do, so the game is the same one the terminal plays. A bet is made up by clicking chips and
+/// dealt with Deal; while a hand is played, there is a button for each action the engine allows;
+/// and the dealer's cards are turned over on their own, with the config file's pause between them.
+/// The game is dealt by `--rules` and from a shoe seeded with `--seed` if given, at the lowest
+/// table limits, and starts with the bankroll set by `--bankroll` or the config file. The cards
+/// are drawn with the theme's symbols and colours, the results in its colours for wins, blackjacks
+/// and busts, and every label is in the language named with `--lang` or in the config file. With
+/// `--record`, the game is written as a replay when the window is closed.
+pub fn run(cli: &Cli, args: &PlayArgs, config: &Config) -> Result<(), BlackjackError> {
+    let bankroll = args
+        .bankroll
+        .or(config.bankroll)
+        .unwrap_or(STARTING_BANKROLL);
+    let limits = TableLimits::TABLES[0];
+    let mut engine = Engine::new(
+        Box::new(Classic),
+        cli.rules.unwrap_or_default(),
+        limits,
+        Bankroll::new(bankroll),
+    );
+    if let Some(seed) = cli.seed {
+        engine.seed(seed);
+    }
+    let table = Table {
+        engine,
+        bet: limits.minimum,
+        error: None,
+        dealer_delay: config.dealer_delay(),
+        dealer_due: None,
+        theme: config.window_theme(),
+        locale: config.locale(),
+        currency: CurrencyFormat::DOLLARS,
+        record: cli.record.clone(),
+    };
+    eframe::run_native(
+        "Blackjack",
+        eframe::NativeOptions::default(),
+        Box::new(|_| Ok(Box::new(table))),
+    )
+    .map_err(|error| io::Error::other(error.to_string()))?;
+    Ok(())
+}
+
+/// The game shown in the window.
+struct Table {
+    engine: Engine,
+    /// The bet the next round is dealt with.
+    bet: u32,
+    /// Why the last action was refused, shown until the next one is taken.
+    error: Option<String>,
+    dealer_delay: Duration,
+    /// When the dealer next turns over or draws a card, while the dealer is playing.
+    dealer_due: Option<Instant>,
+    theme: Theme,
+    locale: Locale,
+    currency: CurrencyFormat,
+    /// Where the game is written as a replay when the window is closed, if anywhere.
+    record: Option<PathBuf>,
+}
+
+impl Table {
+    /// Takes `action`, keeping the reason if the engine refuses it.
+    fn step(&mut self, action: Action) {
+        self.error = self
+            .engine
+            .step(action)
+            .err()
+            .map(|error| self.locale.error(&error));
+    }
+
+    /// Lets the dealer play one card at a time, with a pause before each.
+    fn play_dealer(&mut self, ctx: &egui::Context) {
+        if *self.engine.state() != GameState::DealerPlaying {
+            self.dealer_due = None;
+            return;
+        }
+        let now = Instant::now();
+        let due = *self.dealer_due.get_or_insert(now + self.dealer_delay);
+        if now >= due {
+            self.dealer_due = None;
+            self.step(Action::Continue);
+            ctx.request_repaint();
+        } else {
+            ctx.request_repaint_after(due - now);
+        }
+    }
+
+    /// Shows the dealer's hand, the player's hands with their bets and results, and the bankroll.
+    fn show_table(&self, ui: &mut Ui) {
+        let locale = &self.locale;
+        let state = self.engine.state();
+        let dealer = self.engine.dealer_hand();
+        let total = locale.value(dealer.visible_value());
+        ui.label(RichText::new(locale.message("gui-dealer", &[("total", &total)])).heading());
+        draw_hand(ui, dealer, &self.theme);
+        ui.add_space(24.0);
+
+        for (index, player_hand) in self.engine.player_hands().iter().enumerate() {
+            let hand = &player_hand.hand;
+            let title = locale.message(
+                "gui-hand",
+                &[
+                    ("total", &locale.value(hand.value())),
+                    ("bet", &self.currency.draw(player_hand.wager.total())),
+                ],
+            );
+            let (status, color) = match state {
+                GameState::AwaitingAction { hand } if *hand == index => {
+                    (Some(locale.text("gui-to-play")), "")
+                }
+                GameState::Resolved { outcomes, .. } => match outcomes.get(index) {
+                    Some(outcome) => (
+                        Some(locale.outcome(outcome)),
+                        outcome_color(outcome, hand, &self.theme),
+                    ),
+                    None => (None, ""),
+                },
+                _ => (None, ""),
+            };
+            let title = match status {
+                Some(status) => {
+                    locale.message("gui-hand-status", &[("hand", &title), ("status", &status)])
+                }
+                None => title,
+            };
+            let mut title = RichText::new(title).heading();
+            if let Some(color) = ansi_color(color) {
+                title = title.color(color);
+            }
+            ui.label(title);
+            draw_hand(ui, hand, &self.theme);
+        }
+        ui.add_space(24.0);
+
+        let bankroll = self.currency.draw(self.engine.bankroll().balance());
+        ui.label(locale.message("gui-bankroll", &[("amount", &bankroll)]));
+        if let GameState::Resolved { net, .. } = state {
+            let net = self.currency.draw_signed(*net);
+            ui.label(locale.message("gui-round-net", &[("amount", &net)]));
+        }
+        if let Some(error) = &self.error {
+            ui.colored_label(Color32::LIGHT_RED, error);
+        }
+    }
+
+    /// Shows the buttons for what the player can do next: make up a bet and deal, or act on the
+    /// hand being played.
+    fn show_controls(&mut self, ui: &mut Ui) {
+        match self.engine.state().clone() {
+            GameState::AwaitingBet | GameState::Resolved { .. } => {
+                ui.horizontal(|ui| {
+                    let bet = self.currency.draw(self.bet);
+                    ui.label(self.locale.message("gui-bet", &[("amount", &bet)]));
+                    for denomination in ChipView::DENOMINATIONS.iter().rev() {
+                        let chip = format!("+{}", self.currency.draw(*denomination));
+                        if ui.button(chip).clicked() {
+                            self.bet = self.bet.saturating_add(*denomination);
+                        }
+                    }
+                    if ui.button(self.locale.text("gui-clear")).clicked() {
+                        self.bet = 0;
+                    }
+                    let deal = RichText::new(self.locale.text("gui-deal")).strong();
+                    if ui.button(deal).clicked() {
+                        self.step(Action::Bet(self.bet));
+                    }
+                });
+            }
+            GameState::DealerPlaying => {
+                ui.label(self.locale.text("gui-dealer-plays"));
+            }
+            GameState::AwaitingInsurance
+            | GameState::AwaitingSwitch
+            | GameState::AwaitingAction { .. } => {
+                ui.horizontal(|ui| {
+                    for action in self.engine.legal_actions() {
+                        if ui.button(self.locale.action(action)).clicked() {
+                            self.step(action);
+                        }
+                    }
+                });
+            }
+        }
+    }
+}
+
+impl eframe::App for Table {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.play_dealer(ctx);
+        egui::CentralPanel::default()
+            .frame(egui::Frame::new().fill(FELT).inner_margin(16.0))
+            .show(ctx, |ui| {
+                ui.visuals_mut().override_text_color = Some(Color32::WHITE);
+                if let Some(felt) = ansi_color(&self.theme.felt) {
+                    ui.visuals_mut().widgets.noninteractive.bg_stroke.color = felt;
+                }
+                self.show_table(ui);
+                ui.separator();
+                self.show_controls(ui);
+            });
+    }
+
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        // The window is gone by now, so a replay that cannot be written is reported in the terminal
+        if let Err(error) = replay::record(self.record.as_deref(), &self.engine, &self.locale) {
+            eprintln!(
+                "{}",
+                self.locale
+                    .message("replay-unsaved", &[("error", &self.locale.error(&error))])
+            );
+        }
+    }
+}
+
+/// Draws the cards of `hand` side by side, with the suits drawn in the `theme`'s symbols and colours
+/// and the cards edged in its felt colour. Face-down cards are drawn as their backs.
+fn draw_hand(ui: &mut Ui, hand: &Hand, theme: &Theme) {
+    let edge = ansi_color(&theme.felt).unwrap_or(Color32::DARK_GRAY);
+    ui.horizontal(|ui| {
+        // An empty hand keeps its room, so the table does not jump when the cards are dealt
+        if hand.is_empty() {
+            ui.allocate_exact_size(CARD_SIZE, Sense::hover());
+        }
+        for (index, card) in hand.iter().enumerate() {
+            let (rect, _) = ui.allocate_exact_size(CARD_SIZE, Sense::hover());
+            let painter = ui.painter();
+            if hand.is_face_down(index) {
+                painter.rect_filled(rect, 6.0, CARD_BACK);
+                painter.rect_stroke(
+                    rect.shrink(4.0),
+                    4.0,
+                    Stroke::new(1.0, ansi_color(&theme.felt).unwrap_or(Color32::WHITE)),
+                    StrokeKind::Inside,
+                );
+                continue;
+            }
+            // White would vanish on the face of the card, so the suits are left black instead
+            let color = ansi_color(theme.suit_color(card.suit))
+                .filter(|&color| color != ANSI_COLORS[7] && color != BRIGHT_ANSI_COLORS[7])
+                .unwrap_or(Color32::BLACK);
+            let rank = card.rank.symbol();
+            painter.rect_filled(rect, 6.0, Color32::WHITE);
+            painter.rect_stroke(rect, 6.0, Stroke::new(1.0, edge), StrokeKind::Inside);
+            painter.text(
+                rect.left_top() + Vec2::new(6.0, 4.0),
+                Align2::LEFT_TOP,
+                rank,
+                FontId::proportional(18.0),
+                color,
+            );
+            painter.text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                theme.suits.symbol(card.suit),
+                FontId::proportional(28.0),
+                color,
+            );
+            painter.text(
+                rect.right_bottom() - Vec2::new(6.0, 4.0),
+                Align2::RIGHT_BOTTOM,
+                rank,
+                FontId::proportional(18.0),
+                color,
+            );
+        }
+    });
+}
+
+/// Returns the ANSI code of the `theme`'s colour for a hand's `outcome`, as the terminal writes
+/// the result in: blackjacks, other wins and busts each have their own, and other results none.
+fn outcome_color<'a>(outcome: &Outcome, hand: &Hand, theme: &'a Theme) -> &'a str {
+    match outcome {
+        Outcome::Win(_) if hand.is_blackjack() => &theme.blackjack,
+        Outcome::Win(_) => &theme.win,
+        Outcome::Lose if hand.is_bust() => &theme.bust,
+        _ => "",
+    }
+}
+
+/// Returns the colour of a theme's ANSI `code`, such as "31" or "1;91", or `None` if the code
+/// sets no colour.
+fn ansi_color(code: &str) -> Option<Color32> {
+    // The last colour in the code is the one a terminal would show
+    code.rsplit(';')
+        .find_map(|part| match part.parse::<usize>().ok()? {
+            code @ 30..=37 => Some(ANSI_COLORS[code - 30]),
+            code @ 90..=97 => Some(BRIGHT_ANSI_COLORS[code - 90]),
+            _ => None,
+        })
+}
@@ -0,0 +1,178 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Action, Bankroll, Classic, Engine, GameEvent, TableLimits, STARTING_BANKROLL,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::{self, BufRead};
+
+use crate::cli::{Cli, PlayArgs};
+use crate::config::Config;
+use crate::replay;
+
+/// An action sent on stdin in JSON mode, such as `{"action": "bet", "amount": 25}` or
+/// `{"action": "hit"}`. `quit` ends the game. The actions allowed are written the same way.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+enum Request {
+    Bet { amount: u32 },
+    Switch,
+    KeepHands,
+    Insure,
+    DeclineInsurance,
+    Hit,
+    Stand,
+    Double,
+    Split,
+    Surrender,
+    Continue,
+    SitOut { others: usize },
+    Quit,
+}
+
+/// Plays classic blackjack at the lowest table limits for a program rather than a person, with one
+/// JSON object per line in each direction.
+///
+/// Every line written to stdout has a `type`: a `state` gives the state of the round, the actions
+/// allowed, the table limits, the player's hands, the dealer's cards, the bankroll and the chips
+/// on insurance; an `event`
+/// is a `GameEvent` as it happens; an `error` explains why an input line was refused; and the last
+/// line, `end`, gives the session's statistics. A bet is listed among the allowed actions at the
+/// table minimum, but any bet within the limits is accepted, and the dealer's face-down card is
+/// written as `null` until it is revealed. A round can be sat out with
+/// `{"action": "sit_out", "others": 2}` instead of a bet, dealt to that many other players while
+/// the program watches; it is not listed among the allowed actions.
+/// Each line read from stdin is a `Request`, and the game ends on `quit` or when stdin is closed.
+/// The game is dealt by `--rules` and from a shoe seeded with `--seed` if given, and starts with the
+/// bankroll set by `--bankroll` or the config file. With `--record`, the game is written as a replay
+/// once it ends.
+pub fn run(cli: &Cli, args: &PlayArgs, config: &Config) -> Result<(), BlackjackError> {
+    let bankroll = args
+        .bankroll
+        .or(config.bankroll)
+        .unwrap_or(STARTING_BANKROLL);
+    let mut engine = Engine::new(
+        Box::new(Classic),
+        cli.rules.unwrap_or_default(),
+        TableLimits::TABLES[0],
+        Bankroll::new(bankroll),
+    );
+    if let Some(seed) = cli.seed {
+        engine.seed(seed);
+    }
+    engine.subscribe(Box::new(|event: &GameEvent| {
+        println!("{}", json!({ "type": "event", "event": hidden(event) }));
+    }));
+
+    println!("{}", state(&engine));
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let action = match serde_json::from_str::<Request>(&line) {
+            Ok(Request::Quit) => break,
+            Ok(request) => action(request),
+            Err(error) => {
+                println!(
+                    "{}",
+                    json!({ "type": "error", "message": error.to_string() })
+                );
+                continue;
+            }
+        };
+        match engine.step(action) {
+            Ok(_) => println!("{}", state(&engine)),
+            Err(error) => println!(
+                "{}",
+                json!({ "type": "error", "message": error.to_string() })
+            ),
+        }
+    }
+    println!("{}", json!({ "type": "end", "stats": engine.stats() }));
+    replay::record(cli.record.as_deref(), &engine, &config.locale())
+}
+
+/// Returns the engine action for a request other than `Quit`.
+fn action(request: Request) -> Action {
+    match request {
+        Request::Bet { amount } => Action::Bet(amount),
+        Request::Switch => Action::Switch,
+        Request::KeepHands => Action::KeepHands,
+        Request::Insure => Action::Insure,
+        Request::DeclineInsurance => Action::DeclineInsurance,
+        Request::Hit => Action::Hit,
+        Request::Stand => Action::Stand,
+        Request::Double => Action::Double,
+        Request::Split => Action::Split,
+        Request::Surrender => Action::Surrender,
+        Request::SitOut { others } => Action::SitOut(others),
+        Request::Continue | Request::Quit => Action::Continue,
+    }
+}
+
+/// Returns the request for an engine action.
+fn request(action: Action) -> Request {
+    match action {
+        Action::Bet(amount) => Request::Bet { amount },
+        Action::Switch => Request::Switch,
+        Action::KeepHands => Request::KeepHands,
+        Action::Insure => Request::Insure,
+        Action::DeclineInsurance => Request::DeclineInsurance,
+        Action::Hit => Request::Hit,
+        Action::Stand => Request::Stand,
+        Action::Double => Request::Double,
+        Action::Split => Request::Split,
+        Action::Surrender => Request::Surrender,
+        Action::Continue => Request::Continue,
+        Action::SitOut(others) => Request::SitOut { others },
+    }
+}
+
+/// Writes an event as JSON, leaving out the card of a card dealt face down.
+fn hidden(event: &GameEvent) -> Value {
+    let mut value = json!(event);
+    if let GameEvent::CardDealt { face_up: false, .. } = event {
+        value["CardDealt"]["card"] = Value::Null;
+    }
+    value
+}
+
+/// Describes the state of the round as JSON.
+fn state(engine: &Engine) -> Value {
+    let dealer = engine.dealer_hand();
+    let dealer_cards: Vec<Value> = dealer
+        .iter()
+        .enumerate()
+        .map(|(index, card)| match dealer.is_face_down(index) {
+            true => Value::Null,
+            false => json!(card),
+        })
+        .collect();
+    let hands: Vec<Value> = engine
+        .player_hands()
+        .iter()
+        .map(|hand| {
+            let value = hand.hand.value();
+            json!({
+                "id": hand.id,
+                "parent": hand.parent,
+                "cards": hand.hand.cards(),
+                "total": value.total,
+                "soft": value.soft,
+                "wager": hand.wager,
+                "surrendered": hand.surrendered,
+            })
+        })
+        .collect();
+    json!({
+        "type": "state",
+        "state": engine.state(),
+        "legal_actions": engine.legal_actions().into_iter().map(request).collect::<Vec<Request>>(),
+        "limits": { "minimum": engine.limits().minimum, "maximum": engine.limits().maximum },
+        "hands": hands,
+        "dealer": dealer_cards,
+        "bankroll": engine.bankroll().balance(),
+        "insurance": engine.insurance(),
+    })
+}
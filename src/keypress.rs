@@ -0,0 +1,176 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::StdinInput;
+use blackjack_core::PlayerInput;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// Reads the player's choices from the keyboard, answering a prompt with a single key press where
+/// it can.
+///
+/// At a prompt whose answers are known, such as a decision, the keyboard is read in raw mode and the
+/// answer is taken as soon as what was typed is one of them, so `h` hits without Enter. A key bound
+/// to a longer word is answered once the word is typed, and Enter sends whatever was typed, so a
+/// wrong key is still reported by the game. The arrow keys step through the answers, each written
+/// as if it were typed, so one can be picked with Enter instead. Every other prompt, and every prompt when stdin is not a
+/// terminal or raw mode cannot be turned on, for example when input is piped, is read a line at a
+/// time as by `StdinInput`.
+pub struct KeypressInput;
+
+/// Keeps the terminal in raw mode until dropped.
+struct RawMode;
+
+impl RawMode {
+    /// Turns on raw mode, or returns `None` if stdin is not a terminal or raw mode is unavailable.
+    fn enable() -> Option<RawMode> {
+        if !io::stdin().is_terminal() || terminal::enable_raw_mode().is_err() {
+            return None;
+        }
+        Some(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Returns true if `typed` is one of `keys` and the start of no other, so no more keys are needed.
+pub fn is_answer(typed: &str, keys: &[&str]) -> bool {
+    keys.contains(&typed)
+        && !keys
+            .iter()
+            .any(|key| key.len() > typed.len() && key.starts_with(typed))
+}
+
+/// Returns the answer `step` places away from `typed` among `keys`, wrapping around, so the answers
+/// can be stepped through with the arrow keys. Starts from the first or last answer if what was typed
+/// is none of them; an empty answer, which Enter gives anyway, is skipped.
+pub fn step_answer(typed: &str, keys: &[&str], step: isize) -> Option<String> {
+    let keys: Vec<&str> = keys.iter().copied().filter(|key| !key.is_empty()).collect();
+    if keys.is_empty() {
+        return None;
+    }
+    let count = keys.len() as isize;
+    let index = match keys.iter().position(|key| *key == typed) {
+        Some(index) => (index as isize + step).rem_euclid(count),
+        None if step > 0 => 0,
+        None => count - 1,
+    };
+    Some(keys[index as usize].to_string())
+}
+
+impl KeypressInput {
+    /// Reads key presses in raw mode, echoing them, until they answer the prompt or Enter is
+    /// pressed, for at most `limit` if one is given. Returns `None` if the time runs out.
+    fn read_keys(
+        &mut self,
+        keys: &[&str],
+        limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        let deadline = limit.map(|limit| Instant::now() + limit);
+        let mut out = io::stdout();
+        let mut typed = String::new();
+        loop {
+            out.flush()?;
+            if let Some(deadline) = deadline {
+                if !event::poll(deadline.saturating_duration_since(Instant::now()))? {
+                    write!(out, "\r\n")?;
+                    return Ok(None);
+                }
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let control = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                // Raw mode keeps Ctrl-C from stopping the game, and Ctrl-D from closing stdin
+                KeyCode::Char('c') if control => {
+                    write!(out, "\r\n")?;
+                    return Err(io::Error::from(io::ErrorKind::Interrupted).into());
+                }
+                KeyCode::Char('d') if control && typed.is_empty() => {
+                    write!(out, "\r\n")?;
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+                }
+                KeyCode::Enter => break,
+                KeyCode::Backspace if !typed.is_empty() => {
+                    typed.pop();
+                    write!(out, "\x08 \x08")?;
+                }
+                KeyCode::Up | KeyCode::Left | KeyCode::Down | KeyCode::Right => {
+                    let step = match key.code {
+                        KeyCode::Up | KeyCode::Left => -1,
+                        _ => 1,
+                    };
+                    if let Some(answer) = step_answer(&typed, keys, step) {
+                        for _ in typed.chars() {
+                            write!(out, "\x08 \x08")?;
+                        }
+                        write!(out, "{}", answer)?;
+                        typed = answer;
+                    }
+                }
+                KeyCode::Char(c) if !control => {
+                    typed.extend(c.to_lowercase());
+                    write!(out, "{}", c)?;
+                    if is_answer(&typed, keys) {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        write!(out, "\r\n")?;
+        Ok(Some(typed.trim().to_string()))
+    }
+}
+
+/// Waits at most `limit` for a key to be pressed, and returns true if one was. The key is read and
+/// dropped, so it answers no prompt. When stdin is not a terminal, a line is waited for instead.
+pub fn wait_for_key(limit: Duration) -> Result<bool, BlackjackError> {
+    let Some(_raw) = RawMode::enable() else {
+        return Ok(StdinInput.read_choice_within(limit)?.is_some());
+    };
+    let deadline = Instant::now() + limit;
+    while event::poll(deadline.saturating_duration_since(Instant::now()))? {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Err(io::Error::from(io::ErrorKind::Interrupted).into());
+        }
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+impl PlayerInput for KeypressInput {
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        StdinInput.read_choice()
+    }
+
+    fn read_choice_within(&mut self, limit: Duration) -> Result<Option<String>, BlackjackError> {
+        StdinInput.read_choice_within(limit)
+    }
+
+    /// Reads a single key press in raw mode, or a line if raw mode cannot be used.
+    fn read_key(
+        &mut self,
+        keys: &[&str],
+        limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        match RawMode::enable() {
+            Some(_raw) => self.read_keys(keys, limit),
+            None => StdinInput.read_key(keys, limit),
+        }
+    }
+}
@@ -1,383 +1,468 @@
-use rand::seq::SliceRandom;
-use rand::thread_rng;
-use std::io::{self, Write};
-
-/// The `Model` trait defines a common interface for data models in an application.
-///
-/// The `Model` trait is implemented for any type `T`, and provides two methods:
-/// - `get_data(&self) -> &T`: Returns a reference to the underlying data.
-/// - `set_data(&mut self, data: T)`: Updates the underlying data.
-///
-/// This trait allows data models to be used interchangeably throughout an application,
-/// without needing to know the specific implementation details of each model.
-trait Model<T> {
-    fn get_data(&self) -> &T;
-    fn set_data(&mut self, data: T);
-}
-
-/// The `View` trait defines a common interface for rendering a data model as a string.
-///
-/// The `View` trait is implemented for any type `T`, and provides one method:
-/// - `draw(&self, model: &T) -> String`: Renders the given data model as a string.
-///
-/// This trait allows different views to be used to display the same data model,
-/// without needing to know the specific implementation details of each view.
-trait View<T> {
-    fn draw(&self, model: &T) -> String;
+use blackjack_core::entropy;
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    CurrencyFormat, GameBuilder, Locale, TournamentController, STARTING_BANKROLL,
+};
+use blackjack_core::GameEngine;
+use clap::Parser;
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+mod chart;
+mod cli;
+mod config;
+mod count_drill;
+mod daily;
+mod demo;
+#[cfg(feature = "gui")]
+mod gui;
+mod json;
+mod keypress;
+mod profile;
+mod replay;
+mod save;
+mod script;
+mod serve;
+mod settings;
+mod simulate;
+mod trainer;
+#[cfg(feature = "tui")]
+mod tui;
+mod views;
+mod wong;
+
+use cli::{Cli, Command, ConfigCommand, PlayArgs, SimulateArgs, StatsArgs};
+use config::Config;
+use daily::DailyChallenge;
+use keypress::KeypressInput;
+use profile::{choose_profile, Profile};
+use settings::{prompt_choice, TableSettings};
+
+/// Returns the directory the game keeps its saved data in, under the user's home directory.
+fn data_directory() -> PathBuf {
+    let home = env::var_os("HOME").map_or_else(|| PathBuf::from("."), PathBuf::from);
+    home.join(".blackjack")
 }
 
-/// The `Controller` trait defines a common interface for controlling the application flow.
-///
-/// The `Controller` trait is implemented for any type `T`, and provides one method:
-/// - `run(&mut self) -> bool`: Executes the controller logic, returning a boolean
-///   indicating whether the application should continue running.
+/// The main entry point of the application.
 ///
-/// This trait allows different controllers to be used to drive the application,
-/// without needing to know the specific implementation details of each controller.
-trait Controller<T> {
-    fn run(&mut self) -> bool; // Return bool indicating whether to continue
-}
-
-// --- Card and Deck Models ---
+/// This function reads the command line and runs the chosen subcommand, or shows the main menu
+/// when none is given. `--rules` and `--seed` apply to every subcommand that deals cards. Defaults
+/// are read from the config file first, `BLACKJACK_*` environment variables override them, and
+/// flags on the command line override both. If the game cannot go on, for example because input
+/// can no longer be read, the error is reported and the program exits with a failure status.
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    // Until the config file is read, only the language named with `--lang` is known
+    let lang = cli
+        .lang
+        .as_deref()
+        .and_then(Locale::named)
+        .unwrap_or_default();
+    // A starter file can be written even when the existing one cannot be read
+    if let Some(Command::Config(ConfigCommand::Init { force })) = &cli.command {
+        return report(config::init(cli.config.as_deref(), *force, &lang), &lang);
+    }
+    let mut file = match Config::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!(
+                "{}",
+                lang.message("config-unreadable", &[("error", &error)])
+            );
+            return ExitCode::FAILURE;
+        }
+    };
 
-/// A playing card with a rank (1-13) and suit.
-///
-/// The `Card` struct represents a standard playing card with a numeric rank (1-13, representing Ace through King) and a suit (Hearts, Diamonds, Spades, or Clubs).
-/// The `Model` trait is implemented for `Card`, allowing it to be used as a data model in a larger application.
-struct Card {
-    rank: u8,
-    suit: &'static str,
+    let locale = locale(&cli, &file);
+    report(
+        match &cli.command {
+            None => main_menu(&cli, &mut file),
+            Some(command) => run(&cli, command, &mut file),
+        },
+        &locale,
+    )
 }
 
-impl Model<Card> for Card {
-    fn get_data(&self) -> &Card {
-        self
-    }
-
-    fn set_data(&mut self, data: Card) {
-        *self = data;
+/// Returns the locale named with `--lang`, or else in the config `file`.
+fn locale(cli: &Cli, file: &Config) -> Locale {
+    match cli.lang.as_deref().and_then(Locale::named) {
+        Some(locale) => locale,
+        None => file.locale(),
     }
 }
 
-/// The `Deck` struct represents a standard deck of 52 playing cards.
+/// Shows the main menu until the player quits, running whatever they choose from it.
 ///
-/// The `Deck` struct contains a `Vec` of `Card` instances, representing the cards in the deck.
-/// The `new()` method creates a new deck with all 52 cards, the `shuffle()` method shuffles the deck,
-/// and the `deal_card()` method removes and returns the top card from the deck.
-/// The `Deck` struct implements the `Model` trait, allowing it to be used as a data model in a larger application.
-struct Deck {
-    cards: Vec<Card>,
-}
-
-impl Deck {
-    /// Creates a new deck of 52 shuffled playing cards.
-    fn new() -> Self {
-        let suits = ["Hearts", "Diamonds", "Spades", "Clubs"];
-        let mut cards = Vec::new();
-
-        for &suit in &suits {
-            for rank in 1..=13 {
-                cards.push(Card { rank, suit });
+/// Settings changed from the menu are written to the config file and used by everything chosen
+/// afterwards, except where they are overridden by the environment or the command line.
+fn main_menu(cli: &Cli, file: &mut Config) -> Result<(), BlackjackError> {
+    loop {
+        let locale = locale(cli, file);
+        let choice = prompt_choice(
+            &locale.text("main-menu"),
+            &locale.text("main-menu-invalid"),
+            &["1", "2", "3", "4", "5", "q"],
+        )?;
+        let command = match choice.as_str() {
+            "1" => Command::Play(PlayArgs::default()),
+            "2" => Command::Trainer,
+            "3" => {
+                let bot = prompt_choice(
+                    &locale.text("bot-prompt"),
+                    &locale.text("bot-invalid"),
+                    &["b", "c", "d"],
+                )?;
+                let bot = match bot.as_str() {
+                    "c" => "counting",
+                    "d" => "dealer",
+                    _ => "basic",
+                };
+                Command::Simulate(SimulateArgs {
+                    bot: bot.to_string(),
+                    ..SimulateArgs::default()
+                })
             }
-        }
-
-        Deck { cards }
+            "4" => Command::Stats(StatsArgs::default()),
+            "5" => Command::Config(ConfigCommand::Edit),
+            _ => return Ok(()),
+        };
+        run(cli, &command, file)?;
     }
+}
 
-    /// Shuffles the deck using a random number generator.
-    fn shuffle(&mut self) {
-        let mut rng = thread_rng();
-        self.cards.shuffle(&mut rng);
+/// Runs a subcommand with the environment and command line layered over the config `file`.
+fn run(cli: &Cli, command: &Command, file: &mut Config) -> Result<(), BlackjackError> {
+    // The editor changes the file's own values, before the environment and flags are layered on
+    if let Command::Config(ConfigCommand::Edit) = command {
+        return config::edit(file, cli.config.as_deref(), &locale(cli, file));
     }
-
-    /// Deals the top card from the deck and removes it.
-    fn deal_card(&mut self) -> Card {
-        self.cards.pop().expect("The deck is empty!")
+    let mut cli = cli.clone();
+    let mut config = file.clone();
+    cli.rules = Some(config.table_rules(cli.rules.or(config.rules()))?);
+    config.plain |= cli.plain;
+    if cli.color.is_some() {
+        config.color = cli.color.clone();
     }
-}
-
-impl Model<Vec<Card>> for Deck {
-    fn get_data(&self) -> &Vec<Card> {
-        &self.cards
+    if cli.layout.is_some() {
+        config.layout = cli.layout.clone();
     }
-
-    fn set_data(&mut self, data: Vec<Card>) {
-        self.cards = data;
+    if cli.lang.is_some() {
+        config.lang = cli.lang.clone();
     }
-}
-
-// --- Hand Model ---
-
-/// The `Hand` struct represents a player's hand of cards in a card game.
-///
-/// The `Hand` struct contains a `Vec` of `Card` instances, representing the cards in the player's hand.
-/// The `new()` method creates a new empty hand, the `add()` method adds a card to the hand,
-/// the `calculate_hand_total()` method calculates the total value of the cards in the hand,
-/// and the `display()` method displays the cards in the hand using a provided `View` implementation.
-/// The `Hand` struct implements the `Model` trait, allowing it to be used as a data model in a larger application.
-struct Hand {
-    cards: Vec<Card>,
-}
-
-impl Hand {
-    /// Creates a new empty hand.
-    fn new() -> Self {
-        Hand { cards: Vec::new() }
+    if cli.terse {
+        config.chatter = None;
     }
-
-    /// Adds a card to the player's hand.
-    fn add(&mut self, card: Card) {
-        self.cards.push(card);
+    if cli.fast {
+        config.deal_delay = Some(0);
+        config.dealer_delay = Some(0);
     }
-
-    /// Calculates the total value of the hand, adjusting for Ace cards.
-    fn calculate_hand_total(&self) -> u32 {
-        let mut total = 0;
-        let mut ace_count = 0;
-
-        for card in &self.cards {
-            match card.rank {
-                1 => {
-                    total += 11;
-                    ace_count += 1;
-                }
-                11..=13 => total += 10,
-                _ => total += card.rank as u32,
-            }
-        }
-
-        // Adjust for Aces if the total is over 21
-        while total > 21 && ace_count > 0 {
-            total -= 10;
-            ace_count -= 1;
-        }
-
-        total
+    // A game is only recorded if it is dealt from a seed that can be written down
+    if cli.record.is_some() && cli.seed.is_none() {
+        cli.seed = Some(entropy::from_system().next_u64());
     }
-
-    /// Displays the hand using the specified viewer.
-    fn display(&self, viewer: &dyn View<Hand>) {
-        let output = viewer.draw(self);
-        println!("Hand: {}", output);
+    if config.plain && cli.view.is_none() {
+        cli.view = Some("plain".to_string());
     }
-}
-
-impl Model<Vec<Card>> for Hand {
-    fn get_data(&self) -> &Vec<Card> {
-        &self.cards
+    if cli.view.is_some() {
+        config.viewer = cli.view.clone();
     }
 
-    fn set_data(&mut self, data: Vec<Card>) {
-        self.cards = data;
+    match command {
+        Command::Play(args) => play(&cli, args, &config),
+        Command::Simulate(args) => simulate::run(&cli, args, &config),
+        Command::Demo(args) => {
+            demo::run(&cli, args, &config)?;
+            play(&cli, &PlayArgs::default(), &config)
+        }
+        Command::Stats(args) => profile::show_stats(args.profile.as_deref(), &config.locale()),
+        Command::Serve(args) => serve::run(&cli, args, &config),
+        Command::Trainer => trainer::run(&cli, &config),
+        Command::Drill(args) => trainer::drill(&cli, args, &config),
+        Command::CountDrill(args) => count_drill::run(&cli, args, &config),
+        Command::Wong(args) => wong::run(&cli, args, &config),
+        Command::Chart(args) => chart::run(&cli, args, &config),
+        Command::Script(args) => script::run(&cli, args, &config),
+        Command::Replay(args) => replay::run(args, &config),
+        Command::Config(_) => Ok(()),
     }
 }
 
-// --- View Implementations ---
-
-/// A viewer implementation that displays the cards in a hand using their alphabetic rank names.
-///
-/// This viewer is used to display the cards in a hand in a human-readable format, using the
-/// alphabetic rank names (e.g. "Ace", "Jack", "Queen", "King") instead of the numeric ranks.
-/// The suit of each card is also displayed.
-struct CardAlphaViewer;
-
-impl View<Hand> for CardAlphaViewer {
-    fn draw(&self, model: &Hand) -> String {
-        model
-            .get_data()
-            .iter()
-            .map(|card| {
-                let rank = match card.rank {
-                    1 => "Ace".to_string(),
-                    11 => "Jack".to_string(),
-                    12 => "Queen".to_string(),
-                    13 => "King".to_string(),
-                    _ => card.rank.to_string(),
-                };
-                format!("{} of {}", rank, card.suit)
-            })
-            .collect::<Vec<String>>()
-            .join(", ")
+/// Reports an error that stopped the game in `locale`, and returns the program's exit status.
+fn report(result: Result<(), BlackjackError>, locale: &Locale) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!(
+                "{}",
+                locale.message("game-stopped", &[("error", &locale.error(&error))])
+            );
+            ExitCode::FAILURE
+        }
     }
 }
 
-/// A viewer implementation that displays the cards in a hand using their rank glyphs and suit symbols.
-///
-/// This viewer is used to display the cards in a hand in a compact, graphical format, using the
-/// rank glyphs (e.g. "A", "J", "Q", "K") and suit symbols (♥, ♦, ♠, ♣) instead of the alphabetic
-/// rank names and suit names. This provides a more concise and visually appealing representation
-/// of the cards in the hand.
-struct CardGlyphViewer;
-
-impl View<Hand> for CardGlyphViewer {
-    fn draw(&self, model: &Hand) -> String {
-        model
-            .get_data()
-            .iter()
-            .map(|card| {
-                let rank = match card.rank {
-                    1 => "A".to_string(),
-                    11 => "J".to_string(),
-                    12 => "Q".to_string(),
-                    13 => "K".to_string(),
-                    _ => card.rank.to_string(),
-                };
-
-                let glyph = match card.suit {
-                    "Hearts" => "♥",
-                    "Diamonds" => "♦",
-                    "Spades" => "♠",
-                    "Clubs" => "♣",
-                    _ => "?",
-                };
-
-                format!("{} of {}", rank, glyph)
-            })
-            .collect::<Vec<String>>()
-            .join(", ")
+/// Asks whether to play a cash game, a tournament or the daily challenge and runs it until it is
+/// over, dealing from a shoe seeded with `--seed` if given; the daily challenge always uses the
+/// day's own seed, and tournaments and the daily challenge are always played by the classic rules.
+/// With `--resume`, a cash game saved part way through is carried on instead. Each mode is a
+/// `GameEngine`, which is run in a loop until the player chooses not to play again or the mode is over.
+/// With `--json`, a game is played by another program instead, over JSON lines.
+/// Tournaments and the daily challenge draw cards with the viewer named with `--view` or in the
+/// config file, and every mode reads decisions with the config file's keys, each taken as soon as
+/// it is pressed when playing at a terminal. With `--tui`, built
+/// with the `tui` feature, the game is played on a full screen of panels once it is set up, and
+/// with `--gui`, built with the `gui` feature, a cash game is played in a window instead.
+fn play(cli: &Cli, args: &PlayArgs, config: &Config) -> Result<(), BlackjackError> {
+    if args.json {
+        return json::run(cli, args, config);
+    }
+    #[cfg(feature = "gui")]
+    if args.gui {
+        return gui::run(cli, args, config);
+    }
+    // Only cash games can be saved, so resuming goes straight to one
+    if args.resume {
+        return play_cash_game(cli, args, config);
     }
-}
 
-// --- Game Controller ---
+    // The full screen is kept open until the game is over
+    #[cfg(feature = "tui")]
+    let mut _screen = None;
+    let locale = config.locale();
+    let mode = prompt_choice(
+        &locale.text("mode-prompt"),
+        &locale.text("mode-invalid"),
+        &["c", "t", "d"],
+    )?;
+    let mut game: Box<dyn GameEngine> = match mode.as_str() {
+        "t" => {
+            let mut tournament =
+                TournamentController::new(config.viewer(), CurrencyFormat::DOLLARS, 3)?;
+            if let Some(seed) = cli.seed {
+                tournament.game.seed(seed);
+            }
+            tournament.game.keys = config.keys();
+            tournament.game.chip_view.plain = config.plain;
+            tournament.game.color = config.color();
+            tournament.game.theme = config.theme();
+            tournament.game.locale = config.locale();
+            tournament.game.chatter = config.chatter();
+            tournament.game.table_view = config.table_view(CurrencyFormat::DOLLARS);
+            tournament.game.time_limit = args.time_limit();
+            tournament.game.deal_delay = config.deal_delay();
+            tournament.game.dealer_delay = config.dealer_delay();
+            tournament.game.input = Box::new(KeypressInput);
+            #[cfg(feature = "tui")]
+            if args.tui {
+                _screen = Some(tui::Tui::attach(&mut tournament.game)?);
+            }
+            Box::new(tournament)
+        }
+        "d" => {
+            let mut daily = DailyChallenge::new(config.viewer(), CurrencyFormat::DOLLARS)?;
+            daily.game.keys = config.keys();
+            daily.game.chip_view.plain = config.plain;
+            daily.game.color = config.color();
+            daily.game.theme = config.theme();
+            daily.game.locale = config.locale();
+            daily.game.chatter = config.chatter();
+            daily.game.table_view = config.table_view(CurrencyFormat::DOLLARS);
+            daily.game.time_limit = args.time_limit();
+            daily.game.deal_delay = config.deal_delay();
+            daily.game.dealer_delay = config.dealer_delay();
+            daily.game.input = Box::new(KeypressInput);
+            #[cfg(feature = "tui")]
+            if args.tui {
+                _screen = Some(tui::Tui::attach(&mut daily.game)?);
+            }
+            Box::new(daily)
+        }
+        _ => return play_cash_game(cli, args, config),
+    };
+    while game.run()? {}
+    Ok(())
+}
 
-/// A game controller that manages the game logic and flow for a card game.
+/// Plays a cash game for a player profile, chosen from a menu or with `--profile NAME`, which
+/// creates the profile if there is none by that name yet.
 ///
-/// The `GameController` struct is responsible for managing the game state, including the deck, player hand, and dealer hand. It also handles the game flow, such as dealing the initial hands, allowing the player to hit or stand, and determining the winner.
+/// The profile's bankroll and table settings are restored unless `--fresh` is passed, in which case,
+/// as for a new profile, the bankroll is set by `--bankroll` or the config file. They are saved
+/// again on exit after the session's accounts are shown, together with its lifetime statistics.
+/// The profile is saved even if the game stops with an error, so no progress is lost. Cards are
+/// drawn with the viewer chosen in the table settings, unless another is named with `--view`.
 ///
-/// The `GameController` uses a `View` implementation to display the cards in the player's and dealer's hands. This allows the game to be displayed in different formats, such as using card glyphs or alphabetic rank and suit names.
+/// If the player chooses to save and quit, the session is written to the profile's saved game
+/// instead, and the bankroll and lifetime statistics are only updated once a session resumed with
+/// `--resume` is finished.
 ///
-/// The `GameController` provides a `run()` method that encapsulates the entire game loop, allowing the game to be easily played and restarted.
-struct GameController {
-    deck: Deck,
-    player_hand: Hand,
-    dealer_hand: Hand,
-    viewer: Box<dyn View<Hand>>,
-}
-
-impl GameController {
-    /// Creates a new game controller with the specified viewer.
-    fn new(viewer: Box<dyn View<Hand>>) -> Self {
-        GameController {
-            deck: Deck::new(),
-            player_hand: Hand::new(),
-            dealer_hand: Hand::new(),
-            viewer,
+/// With `--record`, the game is written as a replay once it is over, even if it stopped with an
+/// error; a resumed game has none, as it was not dealt from the seed.
+fn play_cash_game(cli: &Cli, args: &PlayArgs, config: &Config) -> Result<(), BlackjackError> {
+    let locale = config.locale();
+    let name = match args.profile.clone() {
+        // A profile named for the first time is created, as long as the name can be saved
+        Some(name) if Profile::is_valid_name(&name) => name,
+        Some(name) => {
+            println!(
+                "{}",
+                locale.message("profile-name-refused", &[("name", &name)])
+            );
+            choose_profile(&locale)?
         }
-    }
-
-    /// Deals the initial hands for both the player and the dealer.
-    fn deal_initial_hands(&mut self) {
-        self.deck.shuffle();
-        self.player_hand.add(self.deck.deal_card());
-        self.dealer_hand.add(self.deck.deal_card());
-        self.player_hand.add(self.deck.deal_card());
-        self.dealer_hand.add(self.deck.deal_card());
-    }
+        None => choose_profile(&locale)?,
+    };
 
-    /// Prompts the player to either hit or stand, and processes their choice.
-    fn player_turn(&mut self) {
-        loop {
-            self.player_hand.display(&*self.viewer);
-            println!("Your total: {}", self.player_hand.calculate_hand_total());
-            println!("Do you want to (h)it or (s)tand?");
-            let mut choice = String::new();
-            io::stdout().flush().unwrap();
-            io::stdin().read_line(&mut choice).unwrap();
-            match choice.trim().to_lowercase().as_str() {
-                "h" => {
-                    self.player_hand.add(self.deck.deal_card());
-                    if self.player_hand.calculate_hand_total() > 21 {
-                        println!("You bust! Your total is over 21.");
-                        break;
-                    }
-                }
-                "s" => break,
-                _ => println!("Invalid choice, please choose 'h' to hit or 's' to stand."),
+    let mut profile = match Profile::load(&name).filter(|_| !args.fresh) {
+        Some(profile) => {
+            println!(
+                "{}",
+                locale.message(
+                    "welcome-back",
+                    &[
+                        ("name", &profile.name),
+                        ("lifetime", &profile.lifetime.describe(&locale)),
+                    ],
+                )
+            );
+            profile
+        }
+        None => {
+            println!("{}", locale.message("welcome", &[("name", &name)]));
+            let mut profile = Profile::new(&name);
+            if let Some(bankroll) = args.bankroll.or(config.bankroll) {
+                profile.bankroll = bankroll;
             }
+            profile
         }
-    }
+    };
+    let saved = if args.resume {
+        let saved = save::take_game(&name)?;
+        if saved.is_none() {
+            println!("{}", locale.message("no-saved-game", &[("name", &name)]));
+        }
+        saved
+    } else {
+        if save::exists(&name) {
+            println!("{}", locale.text("saved-game-kept"));
+        }
+        None
+    };
+    let settings = match profile.settings.take() {
+        Some(settings) => settings,
+        None => TableSettings::choose(config.viewer.as_deref().unwrap_or("glyph"), &locale)?,
+    };
+    let viewer = match cli.view {
+        Some(_) => config.viewer(),
+        None => settings.viewer(&config.theme(), &locale),
+    };
 
-    /// Plays the dealer's turn, where the dealer will automatically hit until their total is at least 17.
-    fn dealer_turn(&mut self) {
-        self.dealer_hand.display(&*self.viewer);
+    let variant = settings.variant();
+    println!(
+        "{}",
+        locale.message(
+            "playing-at",
+            &[("variant", &variant.name()), ("limits", &settings.limits())],
+        )
+    );
+    let mut builder = GameBuilder::new(viewer)
+        .variant(variant)
+        .side_bets(settings.side_bets())
+        .limits(settings.limits())
+        .bankroll(profile.bankroll)
+        .buy_in(
+            args.bankroll
+                .or(config.bankroll)
+                .unwrap_or(STARTING_BANKROLL),
+        )
+        .currency(settings.currency())
+        .table_view(config.table_view_for(
+            cli.view.as_deref().unwrap_or(&settings.viewer),
+            settings.currency(),
+        ))
+        .keys(config.keys())
+        .deal_delay(config.deal_delay())
+        .dealer_delay(config.dealer_delay())
+        .plain(config.plain)
+        .color(config.color())
+        .theme(config.theme())
+        .locale(locale.clone())
+        .grade_strategy(args.grade)
+        .hints(args.hints)
+        .index_plays(args.index_plays)
+        .deck_estimation(config.deck_estimation())
+        .ace_side_count(config.ace_side_count);
+    if let Some(rules) = cli.rules {
+        builder = builder.rules(rules);
+    }
+    if let Some(seed) = cli.seed {
+        builder = builder.seed(seed);
+    }
+    if let Some(limit) = args.time_limit() {
+        builder = builder.time_limit(limit);
+    }
+    if let Some(chatter) = config.chatter() {
+        builder = builder.chatter(chatter);
+    }
+    let mut controller = builder.build()?;
+    controller.progression = settings.progression();
+    controller.advisor = settings.advisor();
+    controller.input = Box::new(KeypressInput);
+    if let Some(snapshot) = saved {
+        controller.restore(snapshot);
+        let amount = controller.currency.draw(controller.bankroll().balance());
         println!(
-            "Dealer's total: {}",
-            self.dealer_hand.calculate_hand_total()
+            "{}",
+            locale.message("saved-game-resumed", &[("amount", &amount)])
         );
-        while self.dealer_hand.calculate_hand_total() < 17 {
-            println!("Dealer hits...");
-            self.dealer_hand.add(self.deck.deal_card());
-            self.dealer_hand.display(&*self.viewer);
-            println!(
-                "Dealer's total: {}",
-                self.dealer_hand.calculate_hand_total()
-            );
-        }
     }
 
-    /// Determines the winner of the game based on the final totals of the player's and dealer's hands.
-    fn determine_winner(&self) {
-        let player_total = self.player_hand.calculate_hand_total();
-        let dealer_total = self.dealer_hand.calculate_hand_total();
-
-        if player_total > 21 {
-            println!("You bust! Dealer wins.");
-        } else if dealer_total > 21 {
-            println!("Dealer busts! You win.");
-        } else if player_total > dealer_total {
-            println!("You win!");
-        } else if player_total < dealer_total {
-            println!("Dealer wins.");
-        } else {
-            println!("It's a tie!");
+    #[cfg(feature = "tui")]
+    let screen = if args.tui {
+        Some(tui::Tui::attach(&mut controller)?)
+    } else {
+        None
+    };
+    let result = loop {
+        match controller.run() {
+            Ok(true) => {}
+            Ok(false) => break Ok(()),
+            Err(error) => break Err(error),
         }
-    }
-}
-
-/// Runs the entire game, including the player's turn, dealer's turn, and winner determination.
-///
-/// The `run()` method encapsulates the game flow by calling methods to handle each phase of the game.
-impl Controller<Hand> for GameController {
-    fn run(&mut self) -> bool {
-        self.deal_initial_hands();
-        self.player_turn();
-        self.dealer_turn();
-        self.determine_winner();
-
-        // Reset hands for the next game
-        self.player_hand = Hand::new(); // Re-initialize the player's hand
-        self.dealer_hand = Hand::new(); // Re-initialize the dealer's hand
-
-        // Ask the user if they want to play again
-        println!("Do you want to play again? (y/n)");
-        let mut choice = String::new();
-        io::stdout().flush().unwrap();
-        io::stdin().read_line(&mut choice).unwrap();
-        choice.trim().to_lowercase() == "y"
-    }
-}
-
-/// The main entry point of the application.
-///
-/// This function sets up the game controller with either a glyph or alpha viewer,
-/// and then enters a loop where a new hand is created and the game is played.
-/// The loop continues until the user chooses not to play again.
-fn main() {
-    let is_glyph_view = true; // Toggle this to switch between glyph and alpha viewer
-
-    let viewer: Box<dyn View<Hand>> = if is_glyph_view {
-        Box::new(CardGlyphViewer)
+    };
+    // The session's accounts are shown once the terminal is given back
+    #[cfg(feature = "tui")]
+    drop(screen);
+    // A game that stopped with an error is recorded too, to report it
+    let result = result.and(replay::record(
+        cli.record.as_deref(),
+        &controller.engine,
+        &locale,
+    ));
+    let result = if controller.save_requested && result.is_ok() {
+        save::save_game(&profile.name, &controller.snapshot())
+            .map(|()| println!("{}", locale.text("game-saved")))
     } else {
-        Box::new(CardAlphaViewer)
+        profile.bankroll = controller.bankroll().balance();
+        profile.lifetime.add_session(controller.engine.stats());
+        result.and(controller.cash_out()).map(|()| {
+            if args.grade {
+                println!("{}", locale.text("graded-sessions"));
+                for line in profile.lifetime.accuracy_report(&locale) {
+                    println!("  {}", line);
+                }
+            }
+        })
     };
 
-    let mut controller = GameController::new(viewer);
-
-    loop {
-        let mut hand = Hand::new(); // Create the hand model
-        if !controller.run() {
-            // If play_again returns false, break the loop
-            break;
-        }
+    profile.settings = Some(settings);
+    if let Err(error) = profile.save() {
+        eprintln!(
+            "{}",
+            locale.message("profile-unsaved", &[("error", &error)])
+        );
     }
+    result
 }
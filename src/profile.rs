@@ -0,0 +1,368 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Accuracy, CurrencyFormat, Locale, SessionStats, HAND_KINDS, STARTING_BANKROLL, UP_CARD_NAMES,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::data_directory;
+use crate::settings::{prompt_choice, prompt_until_valid, TableSettings};
+
+/// The version of the profile format written by `Profile::save()`.
+pub const PROFILE_VERSION: u32 = 2;
+
+/// The migrations that bring a profile's values from each format version to the next, in order.
+const MIGRATIONS: [fn(&mut HashMap<&str, &str>); PROFILE_VERSION as usize] =
+    [fill_unversioned, fill_viewer];
+
+/// Migrates a profile saved before the format was versioned. Sessions saved before progressions,
+/// advisors and currencies existed bet manually, in dollars.
+fn fill_unversioned(values: &mut HashMap<&str, &str>) {
+    values.entry("progression").or_insert("n");
+    values.entry("advisor").or_insert("n");
+    values.entry("currency").or_insert("1");
+}
+
+/// Migrates a profile saved before the viewer could be chosen, when cards were always drawn with
+/// glyphs.
+fn fill_viewer(values: &mut HashMap<&str, &str>) {
+    values.entry("viewer").or_insert("glyph");
+}
+
+/// Hand results accumulated over every session a profile has played, and the decisions graded
+/// against basic strategy in them, in all and broken down as in `SessionStats`.
+#[derive(Clone, Copy, Default)]
+pub struct LifetimeStats {
+    pub rounds_played: u32,
+    pub hands_won: u32,
+    pub hands_lost: u32,
+    pub hands_pushed: u32,
+    pub blackjacks: u32,
+    pub accuracy: Accuracy,
+    pub by_hand: [Accuracy; 3],
+    pub by_up_card: [Accuracy; 10],
+}
+
+impl LifetimeStats {
+    /// Adds the results of a finished session.
+    pub fn add_session(&mut self, session: &SessionStats) {
+        self.rounds_played += session.rounds_played;
+        self.hands_won += session.hands_won;
+        self.hands_lost += session.hands_lost;
+        self.hands_pushed += session.hands_pushed;
+        self.blackjacks += session.blackjacks;
+        self.accuracy.add(&Accuracy {
+            decisions: session.decisions,
+            correct: session.basic_strategy_plays,
+        });
+        for (lifetime, session) in self.by_hand.iter_mut().zip(&session.by_hand) {
+            lifetime.add(session);
+        }
+        for (lifetime, session) in self.by_up_card.iter_mut().zip(&session.by_up_card) {
+            lifetime.add(session);
+        }
+    }
+
+    /// Returns the lines reporting the graded decisions in `locale`: how many matched basic
+    /// strategy, then by kind of hand and by the dealer's up card. There are none if no decision
+    /// was graded.
+    pub fn accuracy_report(&self, locale: &Locale) -> Vec<String> {
+        let Some(percentage) = self.accuracy.percentage() else {
+            return Vec::new();
+        };
+        let groups = |names: &[String], groups: &[Accuracy]| {
+            names
+                .iter()
+                .zip(groups)
+                .filter_map(|(name, group)| {
+                    let percentage = group.percentage()?;
+                    Some(locale.message(
+                        "strategy-group",
+                        &[
+                            ("name", name),
+                            ("correct", &group.correct),
+                            ("decisions", &group.decisions),
+                            ("percentage", &format!("{:.0}", percentage)),
+                        ],
+                    ))
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let kinds: Vec<String> = HAND_KINDS
+            .iter()
+            .map(|kind| locale.text(&format!("hand-kind-{}", kind)))
+            .collect();
+        let up_cards: Vec<String> = UP_CARD_NAMES.iter().map(|name| name.to_string()).collect();
+        vec![
+            locale.message(
+                "strategy-accuracy",
+                &[
+                    ("correct", &self.accuracy.correct),
+                    ("decisions", &self.accuracy.decisions),
+                    ("percentage", &format!("{:.1}", percentage)),
+                ],
+            ),
+            locale.message(
+                "strategy-by-hand",
+                &[("groups", &groups(&kinds, &self.by_hand))],
+            ),
+            locale.message(
+                "strategy-by-up-card",
+                &[("groups", &groups(&up_cards, &self.by_up_card))],
+            ),
+        ]
+    }
+
+    /// Returns the hand results in `locale`, such as "12 rounds, 5 won, 6 lost, 1 pushed, 1
+    /// blackjacks".
+    pub fn describe(&self, locale: &Locale) -> String {
+        locale.message(
+            "lifetime",
+            &[
+                ("rounds", &self.rounds_played),
+                ("won", &self.hands_won),
+                ("lost", &self.hands_lost),
+                ("pushed", &self.hands_pushed),
+                ("blackjacks", &self.blackjacks),
+            ],
+        )
+    }
+}
+
+/// A named player with their own bankroll, lifetime statistics and preferred table settings.
+///
+/// Each profile is saved as a `key=value` file in the profiles directory, so several people can
+/// keep separate progress on the same machine. The file starts with the version of its format, and
+/// profiles saved in older formats are migrated when they are loaded.
+pub struct Profile {
+    pub name: String,
+    pub bankroll: u32,
+    pub settings: Option<TableSettings>,
+    pub lifetime: LifetimeStats,
+}
+
+impl Profile {
+    /// Creates a profile with the starting bankroll and no saved settings.
+    pub fn new(name: &str) -> Self {
+        Profile {
+            name: name.to_string(),
+            bankroll: STARTING_BANKROLL,
+            settings: None,
+            lifetime: LifetimeStats::default(),
+        }
+    }
+
+    /// Returns the directory profiles are saved in, under the user's home directory.
+    pub fn directory() -> PathBuf {
+        data_directory().join("profiles")
+    }
+
+    /// Returns the path of the file the named profile is saved to.
+    pub fn path(name: &str) -> PathBuf {
+        Profile::directory().join(format!("{}.txt", name))
+    }
+
+    /// Returns true if `name` can be used as a profile name: letters, digits, '-' and '_' only.
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name.len() <= 32
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// Returns the names of every saved profile, sorted alphabetically.
+    pub fn list() -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(Profile::directory())
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                file_name.strip_suffix(".txt").map(str::to_string)
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Loads the named profile, or returns `None` if it has not been saved or cannot be read.
+    ///
+    /// Settings that are incomplete or refer to options that no longer exist are dropped,
+    /// so the player is asked to choose them again.
+    pub fn load(name: &str) -> Option<Profile> {
+        let contents = fs::read_to_string(Profile::path(name)).ok()?;
+        let mut values: HashMap<&str, &str> = contents
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .collect();
+        // Profiles written by a newer version are read as far as their values are understood
+        let version = values
+            .get("version")
+            .and_then(|version| version.parse::<usize>().ok())
+            .unwrap_or(0);
+        for migrate in MIGRATIONS.iter().skip(version) {
+            migrate(&mut values);
+        }
+        let number = |key: &str| values.get(key).and_then(|value| value.parse::<u32>().ok());
+        // Graded decisions are saved as the number that matched basic strategy over the number made
+        let accuracy = |key: &str| {
+            values
+                .get(key)
+                .and_then(|value| value.split_once('/'))
+                .and_then(|(correct, decisions)| {
+                    Some(Accuracy {
+                        decisions: decisions.parse().ok()?,
+                        correct: correct.parse().ok()?,
+                    })
+                })
+                .unwrap_or_default()
+        };
+
+        let settings = (|| {
+            Some(TableSettings {
+                variant: values.get("variant")?.to_string(),
+                lucky_ladies: values.get("lucky_ladies")?.to_string(),
+                royal_match: values.get("royal_match")?.to_string(),
+                table: values.get("table")?.parse::<usize>().ok()?,
+                progression: values.get("progression")?.to_string(),
+                advisor: values.get("advisor")?.to_string(),
+                currency: values.get("currency")?.to_string(),
+                viewer: values.get("viewer")?.to_string(),
+            })
+        })()
+        .filter(TableSettings::is_valid);
+
+        Some(Profile {
+            name: name.to_string(),
+            bankroll: number("bankroll")?,
+            settings,
+            lifetime: LifetimeStats {
+                rounds_played: number("rounds_played").unwrap_or(0),
+                hands_won: number("hands_won").unwrap_or(0),
+                hands_lost: number("hands_lost").unwrap_or(0),
+                hands_pushed: number("hands_pushed").unwrap_or(0),
+                blackjacks: number("blackjacks").unwrap_or(0),
+                accuracy: accuracy("accuracy"),
+                by_hand: HAND_KINDS.map(|kind| accuracy(&format!("accuracy_{}", kind))),
+                by_up_card: UP_CARD_NAMES.map(|card| accuracy(&format!("accuracy_{}", card))),
+            },
+        })
+    }
+
+    /// Saves the profile so the next session can pick up where this one left off.
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents = format!("version={}\nbankroll={}\n", PROFILE_VERSION, self.bankroll);
+        if let Some(settings) = &self.settings {
+            contents.push_str(&format!(
+                "variant={}\nlucky_ladies={}\nroyal_match={}\ntable={}\nprogression={}\nadvisor={}\ncurrency={}\nviewer={}\n",
+                settings.variant,
+                settings.lucky_ladies,
+                settings.royal_match,
+                settings.table,
+                settings.progression,
+                settings.advisor,
+                settings.currency,
+                settings.viewer
+            ));
+        }
+        let lifetime = &self.lifetime;
+        contents.push_str(&format!(
+            "rounds_played={}\nhands_won={}\nhands_lost={}\nhands_pushed={}\nblackjacks={}\n",
+            lifetime.rounds_played,
+            lifetime.hands_won,
+            lifetime.hands_lost,
+            lifetime.hands_pushed,
+            lifetime.blackjacks
+        ));
+        let accuracy = |key: &str, accuracy: &Accuracy| {
+            format!("{}={}/{}\n", key, accuracy.correct, accuracy.decisions)
+        };
+        contents.push_str(&accuracy("accuracy", &lifetime.accuracy));
+        for (kind, group) in HAND_KINDS.iter().zip(&lifetime.by_hand) {
+            contents.push_str(&accuracy(&format!("accuracy_{}", kind), group));
+        }
+        for (card, group) in UP_CARD_NAMES.iter().zip(&lifetime.by_up_card) {
+            contents.push_str(&accuracy(&format!("accuracy_{}", card), group));
+        }
+
+        fs::create_dir_all(Profile::directory())?;
+        fs::write(Profile::path(&self.name), contents)
+    }
+}
+
+/// Asks the player, in `locale`, to pick a saved profile or create a new one, and returns its name.
+pub fn choose_profile(locale: &Locale) -> Result<String, BlackjackError> {
+    let names = Profile::list();
+    if names.is_empty() {
+        return prompt_until_valid(
+            &locale.text("profile-name-prompt"),
+            &locale.text("profile-name-invalid"),
+            Profile::is_valid_name,
+        );
+    }
+
+    let options = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| format!("({}) {}", index + 1, name))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let mut choices: Vec<String> = (1..=names.len()).map(|index| index.to_string()).collect();
+    choices.push("n".to_string());
+    let choice = prompt_choice(
+        &locale.message("profile-prompt", &[("profiles", &options)]),
+        &locale.message("profile-invalid", &[("count", &names.len())]),
+        &choices.iter().map(String::as_str).collect::<Vec<&str>>(),
+    )?;
+
+    match choice.parse::<usize>() {
+        Ok(index) => Ok(names[index - 1].clone()),
+        Err(_) => prompt_until_valid(
+            &locale.text("profile-name-prompt"),
+            &locale.text("profile-name-taken"),
+            |name| Profile::is_valid_name(name) && !names.iter().any(|taken| taken == name),
+        ),
+    }
+}
+
+/// Prints the bankroll and lifetime statistics of the profile `name`, or of every profile if no
+/// name is given, in `locale`.
+pub fn show_stats(name: Option<&str>, locale: &Locale) -> Result<(), BlackjackError> {
+    let names = match name {
+        Some(name) => vec![name.to_string()],
+        None => Profile::list(),
+    };
+    if names.is_empty() {
+        println!("{}", locale.text("no-profiles"));
+    }
+    for name in names {
+        match Profile::load(&name) {
+            Some(profile) => {
+                let currency = profile
+                    .settings
+                    .as_ref()
+                    .map_or(CurrencyFormat::DOLLARS, TableSettings::currency);
+                println!(
+                    "{}",
+                    locale.message(
+                        "profile-stats",
+                        &[
+                            ("name", &profile.name),
+                            ("bankroll", &currency.draw(profile.bankroll)),
+                            ("lifetime", &profile.lifetime.describe(locale)),
+                        ],
+                    )
+                );
+                for line in profile.lifetime.accuracy_report(locale) {
+                    println!("  {}", line);
+                }
+            }
+            None => println!("{}", locale.message("no-profile", &[("name", &name)])),
+        }
+    }
+    Ok(())
+}
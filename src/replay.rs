@@ -0,0 +1,92 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{CurrencyFormat, Engine, Locale, Replay};
+use blackjack_core::PlayerInput;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::cli::ReplayArgs;
+use crate::config::Config;
+use crate::keypress::KeypressInput;
+
+/// Writes the replay `engine` has recorded to `path`, if one was named with `--record`.
+///
+/// Nothing is written to stdout, so the JSON mode's output is left as it is. A game carried on from
+/// a saved one has no replay, as it was not dealt from its seed, which is said in `locale`.
+pub fn record(path: Option<&Path>, engine: &Engine, locale: &Locale) -> Result<(), BlackjackError> {
+    let Some(path) = path else {
+        return Ok(());
+    };
+    match engine.replay() {
+        Some(replay) => Ok(fs::write(path, replay.to_json()?)?),
+        None => {
+            eprintln!(
+                "{}",
+                locale.message("replay-unavailable", &[("file", &path.display())])
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Plays the replay in `args.file` again on a fresh engine, saying each action as it is taken and
+/// drawing the table after it with the viewer and layout named with `--view` and `--layout` or in
+/// the config file.
+///
+/// With `args.step`, Enter is waited for before each action, and 'q' stops the replay. If the
+/// engine refuses an action, because the file was edited or written by another version, the replay
+/// stops there and says why. The game is dealt by the variant, rules, table limits and bankroll
+/// recorded in the file, whatever `--rules` says.
+pub fn run(args: &ReplayArgs, config: &Config) -> Result<(), BlackjackError> {
+    let replay = Replay::from_json(&fs::read_to_string(&args.file)?)?;
+    let mut engine = replay.engine(replay.variant()?);
+    let viewer = config.viewer();
+    let table_view = config.table_view(CurrencyFormat::DOLLARS);
+    let locale = config.locale();
+    let mut input = KeypressInput;
+    let mut stdout = io::stdout();
+
+    println!(
+        "{}",
+        locale.message(
+            "replay-start",
+            &[
+                ("variant", &replay.variant),
+                ("seed", &replay.seed),
+                ("actions", &replay.actions.len()),
+            ],
+        )
+    );
+    for (index, &action) in replay.actions.iter().enumerate() {
+        let number = index + 1;
+        if args.step {
+            println!("{}", locale.text("replay-next"));
+            if input.read_choice()?.trim() == "q" {
+                return Ok(());
+            }
+        }
+        println!(
+            "{}",
+            locale.message(
+                "replay-action",
+                &[("number", &number), ("action", &locale.action(action))],
+            )
+        );
+        if let Err(error) = engine.step(action) {
+            println!(
+                "{}",
+                locale.message(
+                    "replay-stopped",
+                    &[("number", &number), ("error", &locale.error(&error))],
+                )
+            );
+            return Ok(());
+        }
+        table_view.render(&engine.frame(), viewer.as_ref(), &mut stdout)?;
+    }
+    println!(
+        "{}",
+        locale.message("replay-end", &[("balance", &engine.bankroll().balance())])
+    );
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::Snapshot;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::data_directory;
+
+/// Returns the directory saved games are kept in, under the user's home directory.
+pub fn directory() -> PathBuf {
+    data_directory().join("saves")
+}
+
+/// Returns the path of the file the named profile's saved game is written to.
+pub fn path(name: &str) -> PathBuf {
+    directory().join(format!("{}.json", name))
+}
+
+/// Returns true if the named profile has a saved game waiting to be resumed.
+pub fn exists(name: &str) -> bool {
+    path(name).is_file()
+}
+
+/// Saves a cash game session part way through, replacing any game the profile saved before.
+pub fn save_game(name: &str, snapshot: &Snapshot) -> Result<(), BlackjackError> {
+    let json = snapshot.to_json()?;
+    fs::create_dir_all(directory())?;
+    fs::write(path(name), json)?;
+    Ok(())
+}
+
+/// Takes the named profile's saved game, removing the save so it can only be resumed once.
+/// Returns `None` if the profile has no saved game.
+pub fn take_game(name: &str) -> Result<Option<Snapshot>, BlackjackError> {
+    let json = match fs::read_to_string(path(name)) {
+        Ok(json) => json,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    let snapshot = Snapshot::from_json(&json)?;
+    fs::remove_file(path(name))?;
+    Ok(Some(snapshot))
+}
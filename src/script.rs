@@ -0,0 +1,67 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{CurrencyFormat, GameBuilder, ScriptedInput};
+use blackjack_core::GameEngine;
+use std::fs;
+use std::io::{self, Read};
+
+use crate::cli::{Cli, ScriptArgs};
+use crate::config::Config;
+use crate::replay;
+
+/// Plays a cash game at the classic table settings with every choice read from a script instead of
+/// the keyboard, until the player leaves or the script runs out.
+///
+/// The script is read from `args.file`, or from stdin if no file or "-" is given. It holds one
+/// choice per line, exactly as it would be typed at the prompt, such as `10` for a bet or `h` to
+/// hit; lines starting with '#' are comments. Together with `--seed`, a script replays the same
+/// game every time, which makes it easy to automate a session or to report a bug. The game is
+/// dealt by `--rules` and drawn with the viewer named with `--view` or in the config file, and the
+/// session's accounts are shown at the end. With `--record`, the game is written as a replay once
+/// the script is over.
+pub fn run(cli: &Cli, args: &ScriptArgs, config: &Config) -> Result<(), BlackjackError> {
+    let script = match args.file.as_deref() {
+        Some(path) if path.as_os_str() != "-" => fs::read_to_string(path)?,
+        _ => {
+            let mut script = String::new();
+            io::stdin().read_to_string(&mut script)?;
+            script
+        }
+    };
+    let choices = script
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'));
+
+    let mut builder = GameBuilder::new(config.viewer())
+        .table_view(config.table_view(CurrencyFormat::DOLLARS))
+        .keys(config.keys())
+        .plain(config.plain)
+        .color(config.color())
+        .theme(config.theme())
+        .locale(config.locale());
+    if let Some(rules) = cli.rules {
+        builder = builder.rules(rules);
+    }
+    if let Some(seed) = cli.seed {
+        builder = builder.seed(seed);
+    }
+    let mut controller = builder.build()?;
+    controller.input = Box::new(ScriptedInput::new(choices));
+
+    let result = loop {
+        match controller.run() {
+            Ok(true) => {}
+            Ok(false) => break Ok(()),
+            Err(BlackjackError::InputExhausted) => {
+                println!("{}", controller.locale.text("script-exhausted"));
+                break Ok(());
+            }
+            Err(error) => break Err(error),
+        }
+    };
+    let recorded = replay::record(
+        cli.record.as_deref(),
+        &controller.engine,
+        &controller.locale,
+    );
+    result.and(recorded).and(controller.cash_out())
+}
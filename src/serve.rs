@@ -0,0 +1,86 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{CurrencyFormat, GameBuilder, LineInput, RuleSet};
+use blackjack_core::GameEngine;
+use std::io::BufReader;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use crate::cli::{Cli, ServeArgs};
+use crate::config::Config;
+
+/// Listens on `args.address` and serves each player who connects a cash game of their own, on a
+/// thread of its own, until the program is stopped.
+///
+/// The game is played over plain lines of text, exactly as in the terminal, so any line-based
+/// client such as `nc` or `telnet` can play. Every connection starts with the starting bankroll at
+/// a classic table, dealt by `--rules` and from a shoe seeded with `--seed` if given, and draws the
+/// cards with the viewer named in the config file. The server's own messages are in its locale.
+pub fn run(cli: &Cli, args: &ServeArgs, config: &Config) -> Result<(), BlackjackError> {
+    let locale = config.locale();
+    let listener = TcpListener::bind(&args.address)?;
+    let address = listener.local_addr()?;
+    println!(
+        "{}",
+        locale.message("serve-start", &[("address", &address)])
+    );
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(error) => {
+                eprintln!(
+                    "{}",
+                    locale.message("serve-unaccepted", &[("error", &error)])
+                );
+                continue;
+            }
+        };
+        let peer = stream
+            .peer_addr()
+            .map_or_else(|_| locale.text("serve-a-player"), |peer| peer.to_string());
+        println!("{}", locale.message("serve-sat-down", &[("player", &peer)]));
+        let rules = cli.rules.unwrap_or_default();
+        let seed = cli.seed;
+        let mut config = config.clone();
+        // Whether the server's own stdout is a terminal says nothing about the player's
+        if config.color.as_deref() != Some("always") {
+            config.color = Some("never".to_string());
+        }
+        let locale = locale.clone();
+        thread::spawn(move || {
+            let message = match serve_player(stream, &config, rules, seed) {
+                Ok(()) => locale.message("serve-left", &[("player", &peer)]),
+                Err(error) => locale.message(
+                    "serve-left-with-error",
+                    &[("player", &peer), ("error", &locale.error(&error))],
+                ),
+            };
+            println!("{}", message);
+        });
+    }
+    Ok(())
+}
+
+/// Plays a cash game with the player connected on `stream` until they leave.
+fn serve_player(
+    stream: TcpStream,
+    config: &Config,
+    rules: RuleSet,
+    seed: Option<u64>,
+) -> Result<(), BlackjackError> {
+    let mut builder = GameBuilder::new(config.viewer())
+        .table_view(config.table_view(CurrencyFormat::DOLLARS))
+        .rules(rules)
+        .keys(config.keys())
+        .plain(config.plain)
+        .color(config.color())
+        .theme(config.theme())
+        .locale(config.locale());
+    if let Some(seed) = seed {
+        builder = builder.seed(seed);
+    }
+    let mut controller = builder.build()?;
+    controller.input = Box::new(LineInput::new(BufReader::new(stream.try_clone()?)));
+    controller.output = Box::new(stream);
+    while controller.run()? {}
+    controller.cash_out()
+}
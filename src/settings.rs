@@ -0,0 +1,302 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    BetAdvisor, BetRamp, BettingProgression, BlackjackSwitch, Classic, CurrencyFormat,
+    DoubleExposure, FreeBet, KellyAdvisor, Locale, LuckyLadies, LuckyLadiesPaytable, Martingale,
+    OneThreeTwoSix, Paroli, RoyalMatch, RoyalMatchPaytable, SideBet, StdinInput, SuperFun21,
+    TableLimits, Theme, Variant,
+};
+use blackjack_core::{PlayerInput, Renderer};
+
+use crate::keypress::KeypressInput;
+use crate::views::{viewer_from_name, CardGlyphViewer, VIEWER_NAMES};
+
+/// The choices made when sitting down at a table: the variant, side bet paytables, table limits and
+/// how the cards are drawn.
+///
+/// Settings are kept as the menu keys the player picked, so they can be saved between sessions
+/// and turned back into the rule objects used by the `GameController`. The viewer is kept by name,
+/// as it is in the config file.
+pub struct TableSettings {
+    pub variant: String,
+    pub lucky_ladies: String,
+    pub royal_match: String,
+    pub table: usize,
+    pub progression: String,
+    pub advisor: String,
+    pub currency: String,
+    pub viewer: String,
+}
+
+impl TableSettings {
+    /// Asks the player which variant, side bets and table to play, and how to draw the cards,
+    /// offering `default_viewer` for an empty answer. The questions are asked in `locale`.
+    pub fn choose(default_viewer: &str, locale: &Locale) -> Result<Self, BlackjackError> {
+        let variant = prompt_choice(
+            &locale.text("game-prompt"),
+            &locale.message("number-invalid", &[("count", &5)]),
+            &["1", "2", "3", "4", "5"],
+        )?;
+        let lucky_ladies = prompt_choice(
+            &locale.text("lucky-ladies-prompt"),
+            &locale.text("lucky-ladies-invalid"),
+            &["n", "c", "r"],
+        )?;
+        let royal_match = prompt_choice(
+            &locale.text("royal-match-prompt"),
+            &locale.text("royal-match-invalid"),
+            &["n", "c", "e"],
+        )?;
+
+        let tables = TableLimits::TABLES
+            .iter()
+            .enumerate()
+            .map(|(index, limits)| format!("({}) {}", index + 1, limits))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let numbers: Vec<String> = (1..=TableLimits::TABLES.len())
+            .map(|table| table.to_string())
+            .collect();
+        let table = prompt_choice(
+            &locale.message("table-prompt", &[("tables", &tables)]),
+            &locale.message("number-invalid", &[("count", &TableLimits::TABLES.len())]),
+            &numbers.iter().map(String::as_str).collect::<Vec<&str>>(),
+        )?;
+
+        let progression = prompt_choice(
+            &locale.text("progression-prompt"),
+            &locale.text("progression-invalid"),
+            &["n", "m", "p", "1"],
+        )?;
+
+        let advisor = match prompt_choice(
+            &locale.text("advisor-prompt"),
+            &locale.text("advisor-invalid"),
+            &["n", "r", "k"],
+        )?
+        .as_str()
+        {
+            "r" => {
+                let units = prompt_until_valid(
+                    &locale.text("ramp-prompt"),
+                    &locale.text("ramp-invalid"),
+                    |choice| {
+                        choice.is_empty()
+                            || advisor_from_setting(&format!("r {}", choice)).is_some()
+                    },
+                )?;
+                if units.is_empty() {
+                    "r 1 1 2 4 6 8".to_string()
+                } else {
+                    format!("r {}", units)
+                }
+            }
+            "k" => {
+                let fraction = prompt_until_valid(
+                    &locale.text("kelly-prompt"),
+                    &locale.text("kelly-invalid"),
+                    |choice| {
+                        choice.is_empty()
+                            || advisor_from_setting(&format!("k {}", choice)).is_some()
+                    },
+                )?;
+                if fraction.is_empty() {
+                    "k 0.5".to_string()
+                } else {
+                    format!("k {}", fraction)
+                }
+            }
+            _ => "n".to_string(),
+        };
+
+        let currency = prompt_choice(
+            &locale.text("currency-prompt"),
+            &locale.message("number-invalid", &[("count", &4)]),
+            &["1", "2", "3", "4"],
+        )?;
+
+        let viewer = match prompt_choice(
+            &locale.message("viewer-prompt", &[("default", &default_viewer)]),
+            &locale.text("viewer-invalid"),
+            &["", "g", "a", "s", "p", "b", "u", "w", "f"],
+        )?
+        .as_str()
+        {
+            "g" => "glyph",
+            "a" => "alpha",
+            "s" => "ascii",
+            "p" => "plain",
+            "b" => "box",
+            "u" => "unicode",
+            "w" => "spoken",
+            "f" => "auto",
+            _ => default_viewer,
+        }
+        .to_string();
+
+        Ok(TableSettings {
+            variant,
+            lucky_ladies,
+            royal_match,
+            // The choice has already been checked to be a table number
+            table: table.parse::<usize>().map_or(0, |table| table - 1),
+            progression,
+            advisor,
+            currency,
+            viewer,
+        })
+    }
+
+    /// Returns true if every setting refers to an option that exists.
+    pub fn is_valid(&self) -> bool {
+        variant_from_choice(&self.variant).is_some()
+            && matches!(self.lucky_ladies.as_str(), "n" | "c" | "r")
+            && matches!(self.royal_match.as_str(), "n" | "c" | "e")
+            && self.table < TableLimits::TABLES.len()
+            && matches!(self.progression.as_str(), "n" | "m" | "p" | "1")
+            && (self.advisor == "n" || advisor_from_setting(&self.advisor).is_some())
+            && currency_from_choice(&self.currency).is_some()
+            && VIEWER_NAMES.contains(&self.viewer.as_str())
+    }
+
+    /// Returns the chosen currency format.
+    pub fn currency(&self) -> CurrencyFormat {
+        currency_from_choice(&self.currency).unwrap_or(CurrencyFormat::DOLLARS)
+    }
+
+    /// Creates the chosen viewer, drawing the cards with `theme` and naming them in `locale`.
+    pub fn viewer(&self, theme: &Theme, locale: &Locale) -> Box<dyn Renderer> {
+        viewer_from_name(&self.viewer, theme, locale).unwrap_or_else(|| {
+            Box::new(CardGlyphViewer {
+                theme: theme.clone(),
+                locale: locale.clone(),
+            })
+        })
+    }
+
+    /// Creates the chosen variant.
+    pub fn variant(&self) -> Box<dyn Variant> {
+        variant_from_choice(&self.variant).unwrap_or_else(|| Box::new(Classic))
+    }
+
+    /// Creates the chosen side bets with their paytables.
+    pub fn side_bets(&self) -> Vec<Box<dyn SideBet>> {
+        let mut side_bets: Vec<Box<dyn SideBet>> = Vec::new();
+        match self.lucky_ladies.as_str() {
+            "c" => side_bets.push(Box::new(LuckyLadies {
+                paytable: LuckyLadiesPaytable::CLASSIC,
+            })),
+            "r" => side_bets.push(Box::new(LuckyLadies {
+                paytable: LuckyLadiesPaytable::REDUCED,
+            })),
+            _ => {}
+        }
+        match self.royal_match.as_str() {
+            "c" => side_bets.push(Box::new(RoyalMatch {
+                paytable: RoyalMatchPaytable::CLASSIC,
+            })),
+            "e" => side_bets.push(Box::new(RoyalMatch {
+                paytable: RoyalMatchPaytable::EASY_MATCH,
+            })),
+            _ => {}
+        }
+        side_bets
+    }
+
+    /// Returns the chosen table limits.
+    pub fn limits(&self) -> TableLimits {
+        TableLimits::TABLES[self.table]
+    }
+
+    /// Creates the chosen bet advisor.
+    pub fn advisor(&self) -> Option<Box<dyn BetAdvisor>> {
+        advisor_from_setting(&self.advisor)
+    }
+
+    /// Creates the chosen betting progression, using the table minimum as its unit.
+    pub fn progression(&self) -> Option<Box<dyn BettingProgression>> {
+        let unit = self.limits().minimum;
+        match self.progression.as_str() {
+            "m" => Some(Box::new(Martingale::new(unit))),
+            "p" => Some(Box::new(Paroli::new(unit))),
+            "1" => Some(Box::new(OneThreeTwoSix::new(unit))),
+            _ => None,
+        }
+    }
+}
+
+/// Creates the bet advisor described by a setting such as "r 1 2 4 8" or "k 0.5", or `None` if the
+/// setting is "n" or malformed.
+pub fn advisor_from_setting(setting: &str) -> Option<Box<dyn BetAdvisor>> {
+    let mut words = setting.split_whitespace();
+    match words.next()? {
+        "r" => {
+            let units = words
+                .map(|units| units.parse::<u32>())
+                .collect::<Result<Vec<u32>, _>>()
+                .ok()?;
+            (!units.is_empty()).then(|| Box::new(BetRamp { units }) as Box<dyn BetAdvisor>)
+        }
+        "k" => {
+            let fraction = words.next()?.parse::<f64>().ok()?;
+            (fraction > 0.0 && fraction <= 1.0)
+                .then(|| Box::new(KellyAdvisor { fraction }) as Box<dyn BetAdvisor>)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the currency format for a menu choice, or `None` if the choice is not on the menu.
+pub fn currency_from_choice(choice: &str) -> Option<CurrencyFormat> {
+    match choice {
+        "1" => Some(CurrencyFormat::DOLLARS),
+        "2" => Some(CurrencyFormat::DOLLARS_AND_CENTS),
+        "3" => Some(CurrencyFormat::EUROS),
+        "4" => Some(CurrencyFormat::POUNDS),
+        _ => None,
+    }
+}
+
+/// Creates the variant for a game menu choice, or `None` if the choice is not on the menu.
+pub fn variant_from_choice(choice: &str) -> Option<Box<dyn Variant>> {
+    match choice {
+        "1" => Some(Box::new(Classic)),
+        "2" => Some(Box::new(BlackjackSwitch)),
+        "3" => Some(Box::new(DoubleExposure)),
+        "4" => Some(Box::new(FreeBet)),
+        "5" => Some(Box::new(SuperFun21)),
+        _ => None,
+    }
+}
+
+/// Repeats `prompt` until the player picks one of `choices`, and returns it. At a terminal, a choice
+/// is taken as soon as its key is pressed, or picked from the others with the arrow keys and Enter.
+pub fn prompt_choice(
+    prompt: &str,
+    error: &str,
+    choices: &[&str],
+) -> Result<String, BlackjackError> {
+    loop {
+        println!("{}", prompt);
+        let choice = KeypressInput.read_key(choices, None)?.unwrap_or_default();
+        if choices.contains(&choice.as_str()) {
+            return Ok(choice);
+        }
+        println!("{}", error);
+    }
+}
+
+/// Repeats `prompt` until the player enters a choice accepted by `is_valid`, and returns that choice.
+pub fn prompt_until_valid(
+    prompt: &str,
+    error: &str,
+    is_valid: impl Fn(&str) -> bool,
+) -> Result<String, BlackjackError> {
+    loop {
+        println!("{}", prompt);
+        let choice = StdinInput.read_choice()?;
+        if is_valid(&choice) {
+            return Ok(choice);
+        }
+        println!("{}", error);
+    }
+}
@@ -0,0 +1,200 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Action, Bankroll, BasicStrategy, BetPlan, BetRamp, Classic, CountingBot, CountingSystem,
+    DealerMimic, DeckEstimation, Engine, GameState, Locale, PlayerStrategy, TableLimits,
+};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{Cli, SimulateArgs};
+use crate::config::Config;
+use crate::replay;
+
+/// Lets the chosen bot play `args.rounds` rounds on a headless `Engine` at the lowest table limits,
+/// then prints the bot's results and the return it got on the chips it wagered.
+///
+/// The counting bot plays the rounds once for each of `args.systems`, from the same seed, so the
+/// results of counting systems can be set side by side. It bets by the ramp read from `args.ramp`
+/// if one is given, sitting rounds out as the file says, which are dealt to `args.others` other
+/// players instead. The simulation stops early if the bot can no longer cover the table minimum.
+/// With `--record`, the rounds are written as a replay, one file for each counting system if
+/// several are compared. The results are reported in the locale of the config file.
+pub fn run(cli: &Cli, args: &SimulateArgs, config: &Config) -> Result<(), BlackjackError> {
+    let locale = config.locale();
+    if args.bot != "counting" {
+        return simulate(cli, args, &locale, None, None, cli.record.clone());
+    }
+    let plan = args.ramp.as_deref().map(read_plan).transpose()?;
+    for (index, name) in args.systems.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        let record = match args.systems.len() {
+            1 => cli.record.clone(),
+            _ => cli.record.as_deref().map(|path| record_path(path, name)),
+        };
+        simulate(
+            cli,
+            args,
+            &locale,
+            CountingSystem::from_name(name),
+            plan.as_ref(),
+            record,
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns where the replay of the run counting by `system` is written, when several systems are
+/// run: `path` with the system's name added to the file name, such as `run-hi-lo.json`.
+fn record_path(path: &Path, system: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, system, extension.to_string_lossy()),
+        None => format!("{}-{}", stem, system),
+    };
+    path.with_file_name(name)
+}
+
+/// Reads the bet plan in the ramp file at `path`.
+fn read_plan(path: &Path) -> Result<BetPlan, BlackjackError> {
+    let text = fs::read_to_string(path)?;
+    BetPlan::parse(&text).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), error),
+        )
+        .into()
+    })
+}
+
+/// Plays the simulation once, with the cards counted by `system` if the bot counts them, betting
+/// by `plan` if one is given, reports it in `locale`, and writes the rounds as a replay to `record`
+/// if it is given.
+fn simulate(
+    cli: &Cli,
+    args: &SimulateArgs,
+    locale: &Locale,
+    system: Option<CountingSystem>,
+    plan: Option<&BetPlan>,
+    record: Option<PathBuf>,
+) -> Result<(), BlackjackError> {
+    let seed = cli.seed.unwrap_or(0);
+    let units = plan.map_or_else(|| vec![1, 1, 2, 4, 6, 8], |plan| plan.ramp.units.clone());
+    let wonging = plan.and_then(|plan| plan.wonging);
+    let mut bot: Box<dyn PlayerStrategy> = match args.bot.as_str() {
+        "counting" => Box::new(CountingBot {
+            advisor: Box::new(BetRamp { units }),
+            index_plays: args.index_plays,
+        }),
+        "dealer" => Box::new(DealerMimic::new(seed)),
+        _ => Box::new(BasicStrategy),
+    };
+    let mut engine = Engine::new(
+        Box::new(Classic),
+        cli.rules.unwrap_or_default(),
+        TableLimits::TABLES[0],
+        Bankroll::new(args.bankroll),
+    );
+    if let Some(seed) = cli.seed {
+        engine.seed(seed);
+    }
+
+    let mut setup = vec![bot.name()];
+    if let Some(system) = system {
+        engine.set_counting_system(system);
+        engine.set_deck_estimation(
+            DeckEstimation::from_name(&args.deck_estimation).unwrap_or_default(),
+        );
+        engine.set_ace_side_count(args.ace_side_count);
+        setup.push(locale.message("simulate-counting", &[("system", &system.name)]));
+        if args.ace_side_count {
+            setup.push(locale.text("simulate-ace-side-count"));
+        }
+        if let Some(wonging) = wonging {
+            let enter = format!("{:+}", wonging.enter);
+            let exit = format!("{:+}", wonging.exit);
+            setup.push(locale.message("simulate-wonging", &[("enter", &enter), ("exit", &exit)]));
+        }
+    }
+    println!(
+        "{}",
+        locale.message(
+            "simulate-start",
+            &[("rounds", &args.rounds), ("setup", &setup.join(", "))],
+        )
+    );
+    let mut seated = wonging.is_none();
+    let mut watched = 0;
+    for _ in 0..args.rounds {
+        if let Some(wonging) = wonging {
+            seated = wonging.plays(seated, engine.counter().betting_count());
+            if !seated {
+                engine.step(Action::SitOut(args.others as usize))?;
+                watched += 1;
+                continue;
+            }
+        }
+        let bet = bot.bet(&engine.view())?;
+        if bet == 0 {
+            println!("{}", locale.text("simulate-cannot-cover"));
+            break;
+        }
+        let mut state = engine.step(Action::Bet(bet))?;
+        while !matches!(state, GameState::Resolved { .. }) {
+            let action = match state {
+                GameState::DealerPlaying => Action::Continue,
+                _ => bot.decide(&engine.view())?,
+            };
+            state = engine.step(action)?;
+        }
+    }
+
+    let stats = engine.stats();
+    if wonging.is_some() {
+        println!(
+            "{}",
+            locale.message("simulate-sat-out", &[("rounds", &watched)])
+        );
+    }
+    println!(
+        "{}",
+        locale.message(
+            "simulate-hands",
+            &[
+                ("rounds", &stats.rounds_played),
+                ("won", &stats.hands_won),
+                ("lost", &stats.hands_lost),
+                ("pushed", &stats.hands_pushed),
+                ("blackjacks", &stats.blackjacks),
+            ],
+        )
+    );
+    println!(
+        "{}",
+        locale.message(
+            "simulate-bankroll",
+            &[
+                ("balance", &engine.bankroll().balance()),
+                ("bankroll", &args.bankroll),
+                ("peak", &stats.peak_bankroll),
+            ],
+        )
+    );
+    if let Some(percentage) = stats.return_to_player() {
+        let percentage = format!("{:.2}", percentage);
+        println!(
+            "{}",
+            locale.message(
+                "simulate-return",
+                &[
+                    ("wagered", &stats.wagered),
+                    ("returned", &stats.returned),
+                    ("percentage", &percentage),
+                ],
+            )
+        );
+    }
+    replay::record(record.as_deref(), &engine, locale)
+}
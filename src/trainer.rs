@@ -0,0 +1,299 @@
+use blackjack_core::card::{Card, Rank, Suit};
+use blackjack_core::entropy::{self, EntropySource};
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Action, Bankroll, BasicStrategy, Classic, Engine, GameState, Locale, TableLimits,
+};
+use blackjack_core::hand::Hand;
+use blackjack_core::PlayerInput;
+
+use crate::chart::{sections, Row, UP_CARDS};
+use crate::cli::{Cli, DrillArgs};
+use crate::config::Config;
+use crate::keypress::KeypressInput;
+use crate::views::draw_hand;
+
+/// Deals hands for the player to practice basic strategy on, until they enter 'q'.
+///
+/// For every decision the player is shown the dealer's up card and their hand and asked how to play
+/// it. Each play is checked against `BasicStrategy` for the rules and then made, so the hand carries on
+/// as the player chose, and the running score is shown at the end of each round. No chips are at
+/// stake; the hands are dealt by `--rules` and from a shoe seeded with `--seed` if given, drawn
+/// with the viewer named in the config file and talked through in its locale.
+pub fn run(cli: &Cli, config: &Config) -> Result<(), BlackjackError> {
+    let limits = TableLimits::TABLES[0];
+    let mut engine = Engine::new(
+        Box::new(Classic),
+        cli.rules.unwrap_or_default(),
+        limits,
+        Bankroll::new(u32::MAX / 2),
+    );
+    if let Some(seed) = cli.seed {
+        engine.seed(seed);
+    }
+    let viewer = config.viewer();
+    let locale = config.locale();
+    let mut input = KeypressInput;
+    let (mut correct, mut decisions) = (0, 0);
+
+    println!("{}", locale.text("trainer-start"));
+    loop {
+        let mut state = engine.step(Action::Bet(limits.minimum))?;
+        while !matches!(state, GameState::Resolved { .. }) {
+            if state == GameState::DealerPlaying {
+                state = engine.step(Action::Continue)?;
+                continue;
+            }
+            let view = engine.view();
+            let (Some(hand), Some(up_card)) = (&view.hand, view.dealer_up_card) else {
+                state = engine.step(Action::KeepHands)?;
+                continue;
+            };
+            println!(
+                "{}",
+                locale.message(
+                    "trainer-hand",
+                    &[
+                        ("dealer", &draw_hand(engine.dealer_hand(), viewer.as_ref())?),
+                        ("hand", &draw_hand(hand, viewer.as_ref())?),
+                        ("total", &locale.value(hand.value())),
+                    ],
+                )
+            );
+            let Some(action) = ask_play(&mut input, &view.legal_actions, &locale)? else {
+                println!(
+                    "{}",
+                    locale.message(
+                        "trainer-score",
+                        &[("correct", &correct), ("decisions", &decisions)],
+                    )
+                );
+                return Ok(());
+            };
+            let expected = BasicStrategy::play(hand, up_card, &view.rules, &view.legal_actions);
+            decisions += 1;
+            if action == expected {
+                correct += 1;
+                println!("{}", locale.text("trainer-correct"));
+            } else {
+                println!(
+                    "{}",
+                    locale.message("trainer-expected", &[("action", &locale.action(expected))])
+                );
+            }
+            state = engine.step(action)?;
+        }
+        println!(
+            "{}",
+            locale.message(
+                "trainer-round",
+                &[
+                    ("total", &locale.value(engine.dealer_hand().value())),
+                    ("correct", &correct),
+                    ("decisions", &decisions),
+                ],
+            )
+        );
+    }
+}
+
+/// The most situations listed as the weakest at the end of a drill.
+const WEAKEST_SHOWN: usize = 5;
+
+/// One cell of the chart, a row's hand against an up card, and how the player has answered it.
+struct Cell {
+    row: usize,
+    up_card: Rank,
+    correct: u32,
+    missed: u32,
+}
+
+impl Cell {
+    /// Returns how much more often the cell is asked than one never answered, which counts 12: a
+    /// miss makes it much likelier to come up again, and every right answer since makes it less
+    /// likely, but never unlikely enough not to come up at all.
+    fn weight(&self) -> u32 {
+        (12 * (1 + 3 * self.missed) / (1 + self.correct)).max(1)
+    }
+}
+
+/// Flashes hands from the basic strategy chart against an up card for the player to say how to
+/// play, until they enter 'q' or have answered `args.questions`.
+///
+/// Every answer is checked against `BasicStrategy` for the rules played by and scored. The hands
+/// are drawn at random from every cell of the chart, as `chart` prints it, but the cells the player
+/// gets wrong come up more often until they are answered right again. At the end the score and the
+/// cells missed most are shown. The cards are picked from a source seeded with `--seed` if given,
+/// drawn with the viewer named in the config file and asked about in its locale.
+pub fn drill(cli: &Cli, args: &DrillArgs, config: &Config) -> Result<(), BlackjackError> {
+    let rules = cli.rules.unwrap_or_default();
+    let locale = config.locale();
+    let rows: Vec<Row> = sections(&locale)
+        .into_iter()
+        .flat_map(|(_, rows)| rows)
+        .collect();
+    let mut cells: Vec<Cell> = (0..rows.len())
+        .flat_map(|row| {
+            UP_CARDS.iter().map(move |&up_card| Cell {
+                row,
+                up_card,
+                correct: 0,
+                missed: 0,
+            })
+        })
+        .collect();
+    let mut entropy = match cli.seed {
+        Some(seed) => entropy::seeded(seed),
+        None => entropy::from_system(),
+    };
+    let viewer = config.viewer();
+    let mut input = KeypressInput;
+    let (mut correct, mut asked) = (0, 0);
+
+    println!("{}", locale.text("drill-start"));
+    while args.questions.is_none_or(|questions| asked < questions) {
+        let cell = pick(&cells, &mut *entropy);
+        let row = &rows[cells[cell].row];
+        let mut hand = Hand::new();
+        for rank in row.cards {
+            hand.add(deal(rank, &mut *entropy));
+        }
+        let up_card = deal(cells[cell].up_card, &mut *entropy);
+        let mut dealer = Hand::new();
+        dealer.add(up_card);
+
+        println!(
+            "{}",
+            locale.message(
+                "drill-hand",
+                &[
+                    ("dealer", &draw_hand(&dealer, viewer.as_ref())?),
+                    ("hand", &draw_hand(&hand, viewer.as_ref())?),
+                    ("total", &locale.value(hand.value())),
+                ],
+            )
+        );
+        let legal = row.legal_actions(&rules);
+        let Some(action) = ask_play(&mut input, &legal, &locale)? else {
+            break;
+        };
+        let expected = BasicStrategy::play(&hand, up_card, &rules, &legal);
+        asked += 1;
+        if action == expected {
+            correct += 1;
+            cells[cell].correct += 1;
+            println!("{}", locale.text("trainer-correct"));
+        } else {
+            cells[cell].missed += 1;
+            println!(
+                "{}",
+                locale.message("trainer-expected", &[("action", &locale.action(expected))])
+            );
+        }
+        println!(
+            "{}",
+            locale.message("drill-score", &[("correct", &correct), ("asked", &asked)])
+        );
+    }
+
+    println!(
+        "{}",
+        locale.message(
+            "trainer-score",
+            &[("correct", &correct), ("decisions", &asked)],
+        )
+    );
+    let mut missed: Vec<&Cell> = cells.iter().filter(|cell| cell.missed > 0).collect();
+    missed.sort_by_key(|cell| (std::cmp::Reverse(cell.missed), cell.correct));
+    if !missed.is_empty() {
+        println!("{}", locale.text("drill-weakest"));
+    }
+    for cell in missed.iter().take(WEAKEST_SHOWN) {
+        let message = locale.message(
+            "drill-missed",
+            &[
+                ("hand", &rows[cell.row].label),
+                ("up_card", &cell.up_card.symbol()),
+                ("missed", &cell.missed),
+                ("asked", &(cell.missed + cell.correct)),
+            ],
+        );
+        println!("  {}", message);
+    }
+    Ok(())
+}
+
+/// Asks in `locale` for a play from the `legal` actions until a legal one is entered, or returns
+/// `None` if the player enters 'q' to stop. Where insurance is offered, asks whether to take it
+/// instead.
+pub fn ask_play(
+    input: &mut dyn PlayerInput,
+    legal: &[Action],
+    locale: &Locale,
+) -> Result<Option<Action>, BlackjackError> {
+    if legal.contains(&Action::Insure) {
+        return ask_insurance(input, locale);
+    }
+    loop {
+        println!("{}", locale.text("play-prompt"));
+        let keys = ["h", "s", "d", "p", "r", "q"];
+        let action = match input.read_key(&keys, None)?.as_deref() {
+            Some("q") => return Ok(None),
+            Some("h") => Action::Hit,
+            Some("s") => Action::Stand,
+            Some("d") => Action::Double,
+            Some("p") => Action::Split,
+            Some("r") => Action::Surrender,
+            _ => {
+                println!("{}", locale.text("play-invalid"));
+                continue;
+            }
+        };
+        if legal.contains(&action) {
+            return Ok(Some(action));
+        }
+        println!("{}", locale.text("play-not-allowed"));
+    }
+}
+
+/// Asks in `locale` whether to take insurance against the dealer's Ace until 'y' or 'n' is entered,
+/// or returns `None` if the player enters 'q' to stop.
+fn ask_insurance(
+    input: &mut dyn PlayerInput,
+    locale: &Locale,
+) -> Result<Option<Action>, BlackjackError> {
+    loop {
+        println!("{}", locale.text("trainer-insurance-prompt"));
+        match input.read_key(&["y", "n", "q"], None)?.as_deref() {
+            Some("q") => return Ok(None),
+            Some("y") => return Ok(Some(Action::Insure)),
+            Some("n") => return Ok(Some(Action::DeclineInsurance)),
+            _ => println!("{}", locale.text("trainer-insurance-invalid")),
+        }
+    }
+}
+
+/// Picks the index of a cell at random, each as likely as its weight.
+fn pick(cells: &[Cell], entropy: &mut dyn EntropySource) -> usize {
+    let total: u32 = cells.iter().map(Cell::weight).sum();
+    let mut left = entropy.range(1, total);
+    for (index, cell) in cells.iter().enumerate() {
+        if left <= cell.weight() {
+            return index;
+        }
+        left -= cell.weight();
+    }
+    cells.len() - 1
+}
+
+/// Deals a card of `rank` in a random suit, a ten being any ten or face card.
+fn deal(rank: Rank, entropy: &mut dyn EntropySource) -> Card {
+    let rank = match rank {
+        Rank::Ten => [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King][entropy.range(0, 3) as usize],
+        rank => rank,
+    };
+    Card {
+        rank,
+        suit: Suit::ALL[entropy.range(0, 3) as usize],
+    }
+}
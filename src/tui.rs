@@ -0,0 +1,831 @@
+use blackjack_core::card::Card;
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Action, ChipView, CurrencyFormat, GameController, GameEvent, KeyBindings, Locale, Outcome,
+    Recipient, SuitSymbols, TableFrame, TableView, TextTableView,
+};
+use blackjack_core::hand::Hand;
+use blackjack_core::{PlayerInput, Renderer};
+use ratatui::crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+    MouseButton, MouseEventKind,
+};
+use ratatui::crossterm::execute;
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Sparkline};
+use ratatui::DefaultTerminal;
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::mem;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::keypress::{is_answer, step_answer, KeypressInput};
+use crate::views::set_card_columns;
+
+/// The most lines the message log keeps; older lines are dropped.
+const LOG_LINES: usize = 500;
+
+/// The width of the statistics panel beside the table.
+const STATS_WIDTH: u16 = 28;
+
+/// The most settled hands the history panel keeps; older hands are dropped.
+const HISTORY_HANDS: usize = 50;
+
+/// The width of the hand history panel beside the messages.
+const HISTORY_WIDTH: u16 = 36;
+
+/// The lines the hand history moves for each press of Page Up or Page Down or turn of the wheel.
+const SCROLL_LINES: usize = 4;
+
+/// A full-screen frontend that shows a game in panels instead of scrolling text: the table at the
+/// top beside the session's statistics and a sparkline of the bankroll, the choices the player can
+/// make as a row of buttons, the messages the game writes beside a history of the hands settled,
+/// and the line the player is typing.
+///
+/// The history holds the last hands settled, newest first, each with its cards, the player's
+/// actions, the dealer's cards and the result. It is scrolled with Page Up and Page Down or the
+/// mouse wheel, leaving the table as it is.
+///
+/// The buttons can be clicked as well as typed, and the arrow keys step through the answers to the
+/// question being asked: a choice the game is asking for is made by
+/// clicking its button, and a bet is made up by clicking chips, each adding its denomination to
+/// the amount typed, and then clicking Bet.
+///
+/// `attach()` puts the frontend's own output, input, table view and observer into a game, and the
+/// whole screen is drawn again on every `GameEvent`, every line written and every key pressed. The
+/// screen is closed, and the terminal given back, when the `Tui` is dropped; a game played on after
+/// that writes to stdout and reads from stdin as usual.
+pub struct Tui {
+    screen: Rc<RefCell<Screen>>,
+}
+
+impl Tui {
+    /// Opens the full screen and puts it into `game`, drawing the table with the game's own table
+    /// view inside the table panel.
+    pub fn attach(game: &mut GameController) -> Result<Tui, BlackjackError> {
+        let screen = Rc::new(RefCell::new(Screen {
+            terminal: Some(ratatui::try_init()?),
+            table: Vec::new(),
+            compact_table: Vec::new(),
+            bankroll_history: Vec::new(),
+            buttons: Vec::new(),
+            log: Vec::new(),
+            partial: String::new(),
+            answered: 0,
+            typed: String::new(),
+            keys: game.keys.clone(),
+            locale: game.locale.clone(),
+            currency: game.currency.clone(),
+            tally: Tally::default(),
+            history: History::default(),
+            suits: game.theme.suits,
+        }));
+        // Without clicks the game can still be played from the keyboard
+        let _ = execute!(io::stdout(), EnableMouseCapture);
+        let text_view = TextTableView {
+            currency: game.currency.clone(),
+            locale: game.locale.clone(),
+        };
+        let table_view = mem::replace(&mut game.table_view, Box::new(text_view));
+        game.table_view = Box::new(PanelTableView {
+            screen: Rc::clone(&screen),
+            view: table_view,
+        });
+        game.output = Box::new(PanelOutput(Rc::clone(&screen)));
+        game.input = Box::new(PanelInput(Rc::clone(&screen)));
+        let observer = Rc::clone(&screen);
+        game.subscribe(Box::new(move |event: &GameEvent| {
+            let mut screen = observer.borrow_mut();
+            screen.tally.record(event);
+            screen.history.record(event);
+            screen.draw();
+        }));
+        screen.borrow_mut().draw();
+        Ok(Tui { screen })
+    }
+}
+
+impl Drop for Tui {
+    /// Gives the terminal back and writes out what the game said after the player's last answer,
+    /// such as how the game ended, so it is not lost with the screen.
+    fn drop(&mut self) {
+        let mut screen = self.screen.borrow_mut();
+        if screen.terminal.take().is_none() {
+            return;
+        }
+        let _ = execute!(io::stdout(), DisableMouseCapture);
+        ratatui::restore();
+        for line in &screen.log[screen.answered..] {
+            println!("{}", line);
+        }
+        if !screen.partial.is_empty() {
+            println!("{}", screen.partial);
+        }
+    }
+}
+
+/// What the full screen shows, shared by the pieces put into the game.
+struct Screen {
+    /// The terminal drawn on, or `None` once the screen is closed.
+    terminal: Option<DefaultTerminal>,
+    /// The lines of the last frame of the table, with the cards drawn as wide as they go.
+    table: Vec<String>,
+    /// The lines of the same frame with the cards drawn as narrow as they go, shown when the wide
+    /// frame does not fit the table panel.
+    compact_table: Vec<String>,
+    /// The player's bankroll at the start of the session and after every round since, drawn as a
+    /// sparkline under the statistics.
+    bankroll_history: Vec<u32>,
+    /// Where each button was last drawn, with what clicking it does.
+    buttons: Vec<(Rect, Click)>,
+    /// The lines the game has written, oldest first.
+    log: Vec<String>,
+    /// The start of a line the game has not finished writing.
+    partial: String,
+    /// The number of lines in the log when the player last answered a prompt.
+    answered: usize,
+    /// What the player has typed since the last prompt.
+    typed: String,
+    keys: KeyBindings,
+    locale: Locale,
+    currency: CurrencyFormat,
+    tally: Tally,
+    history: History,
+    /// The suit symbols of the game's theme, which the hand history draws cards with.
+    suits: SuitSymbols,
+}
+
+impl Screen {
+    /// Returns the title of a panel, the text for `key` set off by a space on either side.
+    fn title(&self, key: &str) -> String {
+        format!(" {} ", self.locale.text(key))
+    }
+
+    /// Adds `text` written by the game to the log, without the escape codes that colour it.
+    fn print(&mut self, text: &str) {
+        self.partial.push_str(&strip_escapes(text));
+        while let Some(end) = self.partial.find('\n') {
+            let line = self.partial[..end].to_string();
+            self.partial.drain(..=end);
+            self.log.push(line);
+        }
+        if self.log.len() > LOG_LINES {
+            let dropped = self.log.len() - LOG_LINES;
+            self.log.drain(..dropped);
+            self.answered = self.answered.saturating_sub(dropped);
+        }
+    }
+
+    /// Returns the last line written, which is the question the game is waiting on.
+    fn prompt(&self) -> &str {
+        match self.log.last() {
+            Some(line) if self.partial.is_empty() => line,
+            _ => &self.partial,
+        }
+    }
+
+    /// Draws every panel again. A screen that can no longer be drawn on is left as it is, as the
+    /// game can still be played on with what is already shown.
+    fn draw(&mut self) {
+        let Some(mut terminal) = self.terminal.take() else {
+            return;
+        };
+        let mut clicks = Vec::new();
+        let _ = terminal.draw(|frame| {
+            // The frame is chosen again on every draw, so it fits a resized terminal
+            let inner_width = frame.area().width.saturating_sub(STATS_WIDTH + 2);
+            let fits = self
+                .table
+                .iter()
+                .all(|line| line.chars().count() <= usize::from(inner_width));
+            let shown = if fits {
+                &self.table
+            } else {
+                &self.compact_table
+            };
+            // The statistics panel needs room for its lines and the bankroll sparkline under them
+            let table_height = (shown.len() as u16 + 2).max(10);
+            let [top, actions, log, input] = Layout::vertical([
+                Constraint::Length(table_height),
+                Constraint::Length(4),
+                Constraint::Min(3),
+                Constraint::Length(3),
+            ])
+            .areas(frame.area());
+            let [table, stats] =
+                Layout::horizontal([Constraint::Min(30), Constraint::Length(STATS_WIDTH)])
+                    .areas(top);
+
+            let lines: Vec<Line> = shown.iter().map(|line| Line::raw(line.as_str())).collect();
+            frame.render_widget(
+                Paragraph::new(lines).block(Block::bordered().title(self.title("tui-table"))),
+                table,
+            );
+            let block = Block::bordered().title(self.title("tui-statistics"));
+            let [tally, trend] = Layout::vertical([Constraint::Length(6), Constraint::Min(0)])
+                .areas(block.inner(stats));
+            frame.render_widget(block, stats);
+            let mut lines = self.tally.lines(&self.locale, &self.currency);
+            lines.push(Line::raw(self.locale.text("tui-trend")));
+            frame.render_widget(Paragraph::new(lines), tally);
+            // The bars rise from the lowest bankroll shown, so small swings still show
+            let history = &self.bankroll_history[self
+                .bankroll_history
+                .len()
+                .saturating_sub(trend.width.into())..];
+            let low = history.iter().copied().min().unwrap_or(0);
+            let bars: Vec<u64> = history
+                .iter()
+                .map(|bankroll| u64::from(bankroll - low) + 1)
+                .collect();
+            frame.render_widget(Sparkline::default().data(&bars), trend);
+
+            let prompt = self.prompt();
+            let mut row = Row::new(actions.x + 1, actions.y + 1);
+            for (name, key) in self.keys.keys() {
+                let word = self.locale.text(&format!("choice-{}", name));
+                let label = self.keys.label_as(name, &word);
+                if prompt.contains(&label) {
+                    let style = Style::new().add_modifier(Modifier::REVERSED | Modifier::BOLD);
+                    row.button(label, style, Some(Click::Key(key.to_string())));
+                } else {
+                    row.button(label, Style::new().add_modifier(Modifier::DIM), None);
+                }
+            }
+            let mut chips = Row::new(actions.x + 1, actions.y + 2);
+            for denomination in ChipView::DENOMINATIONS.iter().rev() {
+                chips.button(
+                    self.currency.draw(*denomination),
+                    Style::new().add_modifier(Modifier::BOLD),
+                    Some(Click::Chip(*denomination)),
+                );
+            }
+            chips.button(
+                self.locale.text("tui-bet"),
+                Style::new().add_modifier(Modifier::REVERSED),
+                Some(Click::Send),
+            );
+            frame.render_widget(
+                Paragraph::new(vec![Line::from(row.spans), Line::from(chips.spans)])
+                    .block(Block::bordered().title(self.title("tui-actions"))),
+                actions,
+            );
+            clicks = row.clicks.into_iter().chain(chips.clicks).collect();
+
+            let [log, history] =
+                Layout::horizontal([Constraint::Min(30), Constraint::Length(HISTORY_WIDTH)])
+                    .areas(log);
+            let lines = self.history.lines(self.suits, &self.locale, &self.currency);
+            // The history cannot be scrolled past its oldest hand
+            let shown = history.height.saturating_sub(2) as usize;
+            self.history.scroll = self.history.scroll.min(lines.len().saturating_sub(shown));
+            frame.render_widget(
+                Paragraph::new(lines)
+                    .scroll((self.history.scroll as u16, 0))
+                    .block(Block::bordered().title(self.title("tui-history"))),
+                history,
+            );
+
+            let shown = log.height.saturating_sub(2) as usize;
+            let mut lines: Vec<&str> = self.log.iter().map(String::as_str).collect();
+            if !self.partial.is_empty() {
+                lines.push(&self.partial);
+            }
+            let lines: Vec<Line> = lines[lines.len().saturating_sub(shown)..]
+                .iter()
+                .map(|line| Line::raw(*line))
+                .collect();
+            frame.render_widget(
+                Paragraph::new(lines).block(Block::bordered().title(self.title("tui-messages"))),
+                log,
+            );
+
+            frame.render_widget(
+                Paragraph::new(format!("> {}", self.typed))
+                    .block(Block::bordered().title(self.title("tui-input"))),
+                input,
+            );
+            let column = input.x + 3 + self.typed.chars().count() as u16;
+            frame.set_cursor_position(Position::new(column, input.y + 1));
+        });
+        self.buttons = clicks;
+        self.terminal = Some(terminal);
+    }
+
+    /// Writes the player's answer to the log and returns it.
+    fn answer(&mut self, choice: String) -> String {
+        self.print(&format!("> {}\n", choice));
+        self.answered = self.log.len();
+        self.draw();
+        choice
+    }
+
+    /// Returns what clicking at `column` and `row` does, if there is a button there.
+    fn clicked(&self, column: u16, row: u16) -> Option<Click> {
+        self.buttons
+            .iter()
+            .find(|(area, _)| area.contains(Position::new(column, row)))
+            .map(|(_, click)| click.clone())
+    }
+}
+
+/// What clicking a button does.
+#[derive(Clone)]
+enum Click {
+    /// Answers the prompt with a key, as if it were typed.
+    Key(String),
+    /// Adds a chip of this denomination to the amount typed.
+    Chip(u32),
+    /// Sends what has been typed, as if Enter were pressed.
+    Send,
+}
+
+/// A row of buttons being laid out, with the area each one is drawn in.
+struct Row {
+    x: u16,
+    y: u16,
+    spans: Vec<Span<'static>>,
+    clicks: Vec<(Rect, Click)>,
+}
+
+impl Row {
+    /// Starts a row of buttons at `x` and `y`.
+    fn new(x: u16, y: u16) -> Row {
+        Row {
+            x,
+            y,
+            spans: Vec::new(),
+            clicks: Vec::new(),
+        }
+    }
+
+    /// Adds a button showing `label` in `style`, which does `click` when clicked, if anything.
+    fn button(&mut self, label: String, style: Style, click: Option<Click>) {
+        let width = label.chars().count() as u16 + 2;
+        if let Some(click) = click {
+            self.clicks
+                .push((Rect::new(self.x, self.y, width, 1), click));
+        }
+        self.spans.push(Span::styled(format!(" {} ", label), style));
+        self.spans.push(Span::raw(" "));
+        self.x += width + 1;
+    }
+}
+
+/// The results of the hands settled since the screen was opened.
+#[derive(Default)]
+struct Tally {
+    hands: u32,
+    won: u32,
+    lost: u32,
+    pushed: u32,
+    net: i64,
+}
+
+impl Tally {
+    /// Counts a hand once it is settled.
+    fn record(&mut self, event: &GameEvent) {
+        if let GameEvent::HandResolved { net, .. } = *event {
+            self.hands += 1;
+            match net.signum() {
+                1 => self.won += 1,
+                -1 => self.lost += 1,
+                _ => self.pushed += 1,
+            }
+            self.net += net;
+        }
+    }
+
+    /// Returns the lines of the statistics panel in `locale`, with amounts shown in `currency`.
+    fn lines(&self, locale: &Locale, currency: &CurrencyFormat) -> Vec<Line<'static>> {
+        vec![
+            Line::raw(locale.message("hands-played", &[("hands", &self.hands)])),
+            Line::raw(locale.message("tui-won", &[("count", &self.won)])),
+            Line::raw(locale.message("tui-lost", &[("count", &self.lost)])),
+            Line::raw(locale.message("tui-pushed", &[("count", &self.pushed)])),
+            Line::raw(locale.message("tui-net", &[("amount", &currency.draw_signed(self.net))])),
+        ]
+    }
+}
+
+/// A hand of the player's, as the hand history shows it.
+struct PlayedHand {
+    /// The ID the game gave the hand, which its events refer to.
+    id: usize,
+    /// The number of the hand among those settled since the screen was opened, from 1.
+    number: u32,
+    cards: Vec<Card>,
+    actions: Vec<Action>,
+    /// The dealer's cards once the hand was settled, with a card left face down as `None`.
+    dealer: Vec<Option<Card>>,
+    outcome: Option<Outcome>,
+    net: i64,
+}
+
+/// The player's hands settled since the screen was opened, newest last, and the cards of the round
+/// being played, followed from the game's events.
+#[derive(Default)]
+struct History {
+    hands: Vec<PlayedHand>,
+    /// The hands of the round being played that have not been settled yet.
+    playing: Vec<PlayedHand>,
+    /// The dealer's cards in the round being played, and whether each is face up.
+    dealer: Vec<(Card, bool)>,
+    /// The card taken from a hand that was split, which starts the next new hand dealt to.
+    split_card: Option<Card>,
+    settled: u32,
+    /// How many lines the panel is scrolled down from the newest hand.
+    scroll: usize,
+}
+
+impl History {
+    /// Follows the round being played, and moves each hand into the history once it is settled.
+    fn record(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::PlayerAction {
+                action: Action::Bet(_),
+                ..
+            } => {
+                self.playing.clear();
+                self.dealer.clear();
+                self.split_card = None;
+            }
+            GameEvent::PlayerAction { hand, action } => {
+                if matches!(
+                    action,
+                    Action::Switch
+                        | Action::KeepHands
+                        | Action::Insure
+                        | Action::DeclineInsurance
+                        | Action::Continue
+                ) {
+                    return;
+                }
+                let Some(played) = self.playing.iter_mut().find(|played| played.id == hand) else {
+                    return;
+                };
+                if action == Action::Split {
+                    self.split_card = played.cards.pop();
+                }
+                played.actions.push(action);
+            }
+            GameEvent::CardDealt {
+                card,
+                recipient: Recipient::Player(id),
+                ..
+            } => {
+                let index = match self.playing.iter().position(|played| played.id == id) {
+                    Some(index) => index,
+                    None => {
+                        self.playing.push(PlayedHand {
+                            id,
+                            number: 0,
+                            cards: self.split_card.take().into_iter().collect(),
+                            actions: Vec::new(),
+                            dealer: Vec::new(),
+                            outcome: None,
+                            net: 0,
+                        });
+                        self.playing.len() - 1
+                    }
+                };
+                self.playing[index].cards.push(card);
+            }
+            GameEvent::CardDealt {
+                card,
+                recipient: Recipient::Dealer,
+                face_up,
+            } => self.dealer.push((card, face_up)),
+            GameEvent::DealerReveal { card } => {
+                if let Some(hole) = self
+                    .dealer
+                    .iter_mut()
+                    .find(|(dealt, face_up)| *dealt == card && !face_up)
+                {
+                    hole.1 = true;
+                }
+            }
+            GameEvent::HandResolved {
+                hand, outcome, net, ..
+            } => {
+                let Some(index) = self.playing.iter().position(|played| played.id == hand) else {
+                    return;
+                };
+                let mut played = self.playing.remove(index);
+                self.settled += 1;
+                played.number = self.settled;
+                played.outcome = Some(outcome);
+                played.net = net;
+                played.dealer = self
+                    .dealer
+                    .iter()
+                    .map(|(card, face_up)| face_up.then_some(*card))
+                    .collect();
+                self.hands.push(played);
+                if self.hands.len() > HISTORY_HANDS {
+                    self.hands.remove(0);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Scrolls the panel by `lines`, down towards older hands if positive. The panel keeps it from
+    /// scrolling past the oldest hand when it is drawn.
+    fn scroll_by(&mut self, lines: isize) {
+        self.scroll = self.scroll.saturating_add_signed(lines);
+    }
+
+    /// Returns the lines of the panel, newest hand first, with cards drawn with `suits`, the words,
+    /// totals and actions in `locale` and amounts shown in `currency`.
+    fn lines(
+        &self,
+        suits: SuitSymbols,
+        locale: &Locale,
+        currency: &CurrencyFormat,
+    ) -> Vec<Line<'static>> {
+        let cards = |cards: &mut dyn Iterator<Item = Option<Card>>| {
+            let mut hand = Hand::new();
+            let mut names = Vec::new();
+            for card in cards {
+                match card {
+                    Some(card) => {
+                        hand.add(card);
+                        names.push(format!("{}{}", card.rank.symbol(), suits.symbol(card.suit)));
+                    }
+                    None => names.push("??".to_string()),
+                }
+            }
+            format!("{} ({})", names.join(" "), locale.value(hand.value()))
+        };
+        let mut lines = Vec::new();
+        for played in self.hands.iter().rev() {
+            let result = locale.outcome(played.outcome.as_ref().unwrap_or(&Outcome::Lose));
+            lines.push(Line::styled(
+                locale.message(
+                    "history-hand",
+                    &[
+                        ("hand", &played.number),
+                        ("result", &result),
+                        ("net", &currency.draw_signed(played.net)),
+                    ],
+                ),
+                Style::new().add_modifier(Modifier::BOLD),
+            ));
+            let you = cards(&mut played.cards.iter().copied().map(Some));
+            lines.push(Line::raw(format!(
+                "  {}",
+                locale.message("history-you", &[("cards", &you)])
+            )));
+            if !played.actions.is_empty() {
+                let actions: Vec<String> = played
+                    .actions
+                    .iter()
+                    .map(|action| locale.action(*action))
+                    .collect();
+                lines.push(Line::raw(format!("  {}", actions.join(", "))));
+            }
+            let dealer = cards(&mut played.dealer.iter().copied());
+            lines.push(Line::raw(format!(
+                "  {}",
+                locale.message("history-dealer", &[("cards", &dealer)])
+            )));
+        }
+        lines
+    }
+}
+
+/// Removes the ANSI escape codes from `text`, as the panels are coloured by the screen itself.
+fn strip_escapes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // An escape code ends with its first letter, as in "\x1b[31m"
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+/// The game's output while the screen is open, which adds each line to the message log.
+struct PanelOutput(Rc<RefCell<Screen>>);
+
+impl Write for PanelOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut screen = self.0.borrow_mut();
+        if screen.terminal.is_none() {
+            return io::stdout().write(buf);
+        }
+        screen.print(&String::from_utf8_lossy(buf));
+        if buf.contains(&b'\n') {
+            screen.draw();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut screen = self.0.borrow_mut();
+        if screen.terminal.is_none() {
+            return io::stdout().flush();
+        }
+        screen.draw();
+        Ok(())
+    }
+}
+
+/// The game's input while the screen is open, which reads keys into the choice line until Enter is
+/// pressed, or until they answer a prompt whose answers are known.
+struct PanelInput(Rc<RefCell<Screen>>);
+
+impl PanelInput {
+    /// Reads keys until the player presses Enter or has typed one of `keys`, for at most `limit`
+    /// if one is given, returning `None` if the time runs out. Ctrl-C stops the game, as raw mode
+    /// keeps it from interrupting.
+    fn read(
+        &mut self,
+        keys: &[&str],
+        limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        let deadline = limit.map(|limit| Instant::now() + limit);
+        self.0.borrow_mut().draw();
+        loop {
+            if let Some(deadline) = deadline {
+                if !event::poll(deadline.saturating_duration_since(Instant::now()))? {
+                    let mut screen = self.0.borrow_mut();
+                    screen.typed.clear();
+                    screen.draw();
+                    return Ok(None);
+                }
+            }
+            let key = match event::read()? {
+                Event::Key(key) => key,
+                Event::Mouse(mouse)
+                    if matches!(
+                        mouse.kind,
+                        MouseEventKind::ScrollDown | MouseEventKind::ScrollUp
+                    ) =>
+                {
+                    let mut screen = self.0.borrow_mut();
+                    let lines = match mouse.kind {
+                        MouseEventKind::ScrollDown => SCROLL_LINES as isize,
+                        _ => -(SCROLL_LINES as isize),
+                    };
+                    screen.history.scroll_by(lines);
+                    screen.draw();
+                    continue;
+                }
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    let mut screen = self.0.borrow_mut();
+                    match screen.clicked(mouse.column, mouse.row) {
+                        Some(Click::Key(key)) => {
+                            screen.typed.clear();
+                            return Ok(Some(screen.answer(key)));
+                        }
+                        Some(Click::Chip(denomination)) => {
+                            let amount = screen.typed.parse::<u32>().unwrap_or(0);
+                            screen.typed = amount.saturating_add(denomination).to_string();
+                        }
+                        Some(Click::Send) => {
+                            let choice = mem::take(&mut screen.typed);
+                            return Ok(Some(screen.answer(choice.trim().to_lowercase())));
+                        }
+                        None => {}
+                    }
+                    screen.draw();
+                    continue;
+                }
+                _ => {
+                    // The screen is drawn again to fit a resized terminal
+                    self.0.borrow_mut().draw();
+                    continue;
+                }
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let mut screen = self.0.borrow_mut();
+            match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Err(io::Error::from(io::ErrorKind::Interrupted).into());
+                }
+                KeyCode::Enter => {
+                    let choice = mem::take(&mut screen.typed);
+                    return Ok(Some(screen.answer(choice.trim().to_lowercase())));
+                }
+                KeyCode::Backspace => {
+                    screen.typed.pop();
+                }
+                KeyCode::Up | KeyCode::Left | KeyCode::Down | KeyCode::Right => {
+                    let step = match key.code {
+                        KeyCode::Up | KeyCode::Left => -1,
+                        _ => 1,
+                    };
+                    if let Some(answer) = step_answer(&screen.typed, keys, step) {
+                        screen.typed = answer;
+                    }
+                }
+                KeyCode::PageDown => screen.history.scroll_by(SCROLL_LINES as isize),
+                KeyCode::PageUp => screen.history.scroll_by(-(SCROLL_LINES as isize)),
+                KeyCode::Char(c) => {
+                    screen.typed.extend(c.to_lowercase());
+                    if is_answer(&screen.typed, keys) {
+                        let choice = mem::take(&mut screen.typed);
+                        return Ok(Some(screen.answer(choice)));
+                    }
+                }
+                _ => {}
+            }
+            screen.draw();
+        }
+    }
+
+    /// Returns whether the screen has been closed, after which the keyboard is read as usual.
+    fn closed(&self) -> bool {
+        self.0.borrow().terminal.is_none()
+    }
+}
+
+impl PlayerInput for PanelInput {
+    fn read_choice(&mut self) -> Result<String, BlackjackError> {
+        if self.closed() {
+            return KeypressInput.read_choice();
+        }
+        Ok(self.read(&[], None)?.unwrap_or_default())
+    }
+
+    fn read_choice_within(&mut self, limit: Duration) -> Result<Option<String>, BlackjackError> {
+        if self.closed() {
+            return KeypressInput.read_choice_within(limit);
+        }
+        self.read(&[], Some(limit))
+    }
+
+    fn read_key(
+        &mut self,
+        keys: &[&str],
+        limit: Option<Duration>,
+    ) -> Result<Option<String>, BlackjackError> {
+        if self.closed() {
+            return KeypressInput.read_key(keys, limit);
+        }
+        self.read(keys, limit)
+    }
+}
+
+/// The game's table view while the screen is open, which draws each frame with the game's own
+/// table view into the table panel. Every change to the table is drawn, as the panel only ever
+/// holds the latest frame. Cards drawn by `CardAutoViewer` are drawn both wide and compact, and
+/// the panel shows the widest that fits.
+struct PanelTableView {
+    screen: Rc<RefCell<Screen>>,
+    view: Box<dyn TableView>,
+}
+
+impl TableView for PanelTableView {
+    fn render(
+        &self,
+        table: &TableFrame,
+        cards: &dyn Renderer,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut screen = self.screen.borrow_mut();
+        if screen.terminal.is_none() {
+            return self.view.render(table, cards, out);
+        }
+        // The frame is drawn both ways, so the screen can show whichever fits the panel after the
+        // terminal is resized, without the game drawing it again
+        let mut drawn = Vec::new();
+        for columns in [usize::MAX, 1] {
+            set_card_columns(columns);
+            let mut frame = Vec::new();
+            let result = self.view.render(table, cards, &mut frame);
+            set_card_columns(0);
+            result?;
+            drawn.push(
+                strip_escapes(&String::from_utf8_lossy(&frame))
+                    .lines()
+                    .map(str::to_string)
+                    .collect(),
+            );
+        }
+        screen.compact_table = drawn.pop().unwrap_or_default();
+        screen.table = drawn.pop().unwrap_or_default();
+        screen.bankroll_history = table.bankroll_history.to_vec();
+        screen.draw();
+        Ok(())
+    }
+
+    fn redraws(&self) -> bool {
+        self.screen.borrow().terminal.is_some() || self.view.redraws()
+    }
+}
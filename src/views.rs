@@ -0,0 +1,422 @@
+use blackjack_core::card::{Card, Rank, Suit};
+use blackjack_core::game::{
+    Border, CardBack, CurrencyFormat, LayoutTableView, Locale, SpokenTableView, StatusTableView,
+    TableView, TextTableView, Theme,
+};
+use blackjack_core::hand::Hand;
+use blackjack_core::Renderer;
+use crossterm::terminal;
+use std::env;
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The columns set with `set_card_columns()`, or 0 if none are.
+static CARD_COLUMNS: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns `text` for `card` in the `theme`'s colour for the card's suit.
+fn paint(card: &Card, text: impl Display, theme: &Theme) -> String {
+    Theme::paint(&text.to_string(), theme.suit_color(card.suit))
+}
+
+/// A renderer that displays the cards in a hand using their alphabetic rank names.
+///
+/// This renderer is used to display the cards in a hand in a human-readable format, using the
+/// alphabetic rank names (e.g. "Ace", "Jack", "Queen", "King") instead of the numeric ranks.
+/// The suit of each card is also displayed, and face-down cards are described rather than revealed.
+/// Hearts and diamonds are written in the `theme`'s colour for red suits, and the names are those
+/// of the `locale`.
+pub struct CardAlphaViewer {
+    pub theme: Theme,
+    pub locale: Locale,
+}
+
+impl Renderer for CardAlphaViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        for (index, card) in hand.iter().enumerate() {
+            if index > 0 {
+                write!(out, ", ")?;
+            }
+            if hand.is_face_down(index) {
+                write!(out, "{}", self.locale.text("face-down-card"))?;
+            } else {
+                // Number cards keep their numbers, as in "7 of Clubs"
+                let rank = match card.rank {
+                    Rank::Ace | Rank::Jack | Rank::Queen | Rank::King => {
+                        self.locale.rank(card.rank)
+                    }
+                    rank => rank.symbol().to_string(),
+                };
+                let text = self.locale.card(&rank, &self.locale.suit(card.suit));
+                write!(out, "{}", paint(card, text, &self.theme))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A renderer that displays the cards in a hand using their rank glyphs and suit symbols.
+///
+/// This renderer is used to display the cards in a hand in a compact, graphical format, using the
+/// rank glyphs (e.g. "A", "J", "Q", "K") and suit symbols (♥, ♦, ♠, ♣) instead of the alphabetic
+/// rank names and suit names. This provides a more concise and visually appealing representation
+/// of the cards in the hand. Face-down cards are drawn with the `theme`'s card back, and the suits
+/// with its symbols and colours. The word between rank and suit is the `locale`'s.
+pub struct CardGlyphViewer {
+    pub theme: Theme,
+    pub locale: Locale,
+}
+
+impl Renderer for CardGlyphViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        for (index, card) in hand.iter().enumerate() {
+            if index > 0 {
+                write!(out, ", ")?;
+            }
+            if hand.is_face_down(index) {
+                write!(out, "{}", self.theme.back.text(false))?;
+            } else {
+                let suit = self.theme.suits.symbol(card.suit);
+                let text = self.locale.card(&card.rank.symbol(), &suit);
+                write!(out, "{}", paint(card, text, &self.theme))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A renderer that draws cards like `CardGlyphViewer`, but with suit letters instead of symbols.
+///
+/// Cards are written as "A of S" or "10 of H", using only ASCII, for terminals and fonts that
+/// cannot show the suit symbols. Face-down cards are drawn with the ASCII form of the `theme`'s
+/// card back, and hearts and diamonds in its colour for red suits. The word between rank and suit is the `locale`'s.
+pub struct CardAsciiViewer {
+    pub theme: Theme,
+    pub locale: Locale,
+}
+
+impl Renderer for CardAsciiViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        for (index, card) in hand.iter().enumerate() {
+            if index > 0 {
+                write!(out, ", ")?;
+            }
+            if hand.is_face_down(index) {
+                write!(out, "{}", self.theme.back.text(true))?;
+            } else {
+                let text = self.locale.card(&card.rank.symbol(), &card.suit.letter());
+                write!(out, "{}", paint(card, text, &self.theme))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A renderer that writes each card as its short name, such as "AS" or "10H", separated by spaces.
+///
+/// The output is as terse as possible and can be read back as cards, which suits logs and scripts.
+/// Face-down cards are written as "??".
+pub struct CardPlainViewer;
+
+impl Renderer for CardPlainViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        for (index, card) in hand.iter().enumerate() {
+            if index > 0 {
+                write!(out, " ")?;
+            }
+            if hand.is_face_down(index) {
+                write!(out, "??")?;
+            } else {
+                write!(out, "{}{}", card.rank.symbol(), card.suit.letter())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A renderer for screen readers, which names each card in words, such as "Seven of Clubs",
+/// separated by commas.
+///
+/// Nothing is written but words: no glyphs, symbols or colours, which screen readers read out
+/// badly or not at all. Face-down cards are called "a face-down card", and the words are those of
+/// the `locale`.
+pub struct CardSpokenViewer {
+    pub locale: Locale,
+}
+
+impl Renderer for CardSpokenViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        for (index, card) in hand.iter().enumerate() {
+            if index > 0 {
+                write!(out, ", ")?;
+            }
+            if hand.is_face_down(index) {
+                write!(out, "{}", self.locale.text("face-down-card"))?;
+            } else {
+                write!(out, "{}", self.locale.card_in_words(card))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A renderer that draws each card as a box of ASCII art, with the hand's cards side by side.
+///
+/// The rank is written in the top-left and bottom-right corners and the suit symbol in the middle,
+/// and face-down cards are filled with the `theme`'s card back, in ASCII if the border is. The
+/// boxes are drawn with the theme's border in its felt colour, and the suits with its symbols and
+/// colours. The boxes start on a line of
+/// their own, and whatever is written after the hand follows the bottom of the boxes.
+pub struct CardBoxViewer {
+    pub theme: Theme,
+}
+
+impl CardBoxViewer {
+    /// The lines of text each card is drawn with, from top to bottom.
+    const ROWS: usize = 5;
+}
+
+impl Renderer for CardBoxViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        let border = self.theme.border;
+        let felt = |text: String| Theme::paint(&text, &self.theme.felt);
+        let side = felt(border.vertical().to_string());
+        let [top_left, top_right, bottom_left, bottom_right] = border.corners();
+        let line = border.horizontal().to_string().repeat(5);
+        let head = felt(format!("{}{}{}", top_left, line, top_right));
+        let foot = felt(format!("{}{}{}", bottom_left, line, bottom_right));
+        let cards: Vec<[String; CardBoxViewer::ROWS]> = hand
+            .iter()
+            .enumerate()
+            .map(|(index, card)| {
+                let row = |text: String| format!("{}{}{}", side, text, side);
+                let middle = if hand.is_face_down(index) {
+                    let ascii = border == Border::Ascii;
+                    [0, 1, 2].map(|line| row(self.theme.back.row(ascii, 5, line)))
+                } else {
+                    let rank = card.rank.symbol();
+                    let symbol = self.theme.suits.symbol(card.suit);
+                    [
+                        row(paint(card, format!("{:<5}", rank), &self.theme)),
+                        row(paint(card, format!("{:^5}", symbol), &self.theme)),
+                        row(paint(card, format!("{:>5}", rank), &self.theme)),
+                    ]
+                };
+                let [top, centre, bottom] = middle;
+                [head.clone(), top, centre, bottom, foot.clone()]
+            })
+            .collect();
+        for row in 0..CardBoxViewer::ROWS {
+            writeln!(out)?;
+            let line: Vec<&str> = cards.iter().map(|card| card[row].as_str()).collect();
+            write!(out, "{}", line.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl CardBoxViewer {
+    /// Returns the columns the boxes of a hand of `cards` cards take up.
+    pub fn width(cards: usize) -> usize {
+        (cards * 8).saturating_sub(1)
+    }
+}
+
+/// A renderer that fits the cards to the terminal: a hand is drawn as boxes side by side, as by
+/// `wide`, when they fit across the terminal, and on a single line of glyphs, as by `compact`, when
+/// they do not.
+///
+/// The width is looked up each time a hand is drawn, so after the terminal is resized the next hand
+/// drawn fits it. A frontend that draws the cards in part of the terminal gives its width with
+/// `set_card_columns()`. Output that does not go to a terminal is always drawn with glyphs.
+pub struct CardAutoViewer {
+    pub wide: CardBoxViewer,
+    pub compact: CardGlyphViewer,
+}
+
+impl Renderer for CardAutoViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        match card_columns() {
+            Some(columns) if CardBoxViewer::width(hand.len()) <= columns => {
+                self.wide.render(hand, out)
+            }
+            _ => self.compact.render(hand, out),
+        }
+    }
+}
+
+/// Sets the columns `CardAutoViewer` fits the cards of a hand into, for a frontend that draws them
+/// in less than the whole terminal, or 0 to fit them to the terminal again.
+#[cfg(feature = "tui")]
+pub fn set_card_columns(columns: usize) {
+    CARD_COLUMNS.store(columns, Ordering::Relaxed);
+}
+
+/// Returns the columns the cards of a hand can take up: those set with `set_card_columns()`, or
+/// else the width of the terminal, or `None` if the output does not go to a terminal.
+fn card_columns() -> Option<usize> {
+    match CARD_COLUMNS.load(Ordering::Relaxed) {
+        0 if io::stdout().is_terminal() => terminal::size()
+            .ok()
+            .map(|(columns, _)| usize::from(columns)),
+        0 => None,
+        columns => Some(columns),
+    }
+}
+
+/// A renderer that draws each card as its own character from Unicode's playing cards block, such
+/// as 🂡 for the Ace of Spades, separated by spaces. Face-down cards are drawn as the card back, 🂠,
+/// or with a character of the `theme`'s card back if it has a pattern, and the cards are drawn in
+/// the theme's colours for their suits.
+///
+/// Few fonts have these characters, so `viewer_from_name` only gives this viewer when the terminal
+/// looks able to show them; see `shows_card_characters`.
+pub struct CardUnicodeViewer {
+    pub theme: Theme,
+}
+
+impl CardUnicodeViewer {
+    /// The character for the back of a card.
+    const BACK: char = '\u{1F0A0}';
+
+    /// Returns the character for `card`.
+    fn character(card: &Card) -> char {
+        let suit = match card.suit {
+            Suit::Spades => 0x1F0A0,
+            Suit::Hearts => 0x1F0B0,
+            Suit::Diamonds => 0x1F0C0,
+            Suit::Clubs => 0x1F0D0,
+        };
+        // Each suit has a knight between the jack and the queen, which blackjack does not use
+        let rank = match card.rank {
+            Rank::Queen => 13,
+            Rank::King => 14,
+            rank => {
+                Rank::ALL
+                    .iter()
+                    .position(|&other| other == rank)
+                    .unwrap_or(0) as u32
+                    + 1
+            }
+        };
+        char::from_u32(suit + rank).unwrap_or(CardUnicodeViewer::BACK)
+    }
+}
+
+impl Renderer for CardUnicodeViewer {
+    fn render(&self, hand: &Hand, out: &mut dyn Write) -> io::Result<()> {
+        for (index, card) in hand.iter().enumerate() {
+            if index > 0 {
+                write!(out, " ")?;
+            }
+            if hand.is_face_down(index) {
+                match self.theme.back {
+                    CardBack::Plain => write!(out, "{}", CardUnicodeViewer::BACK)?,
+                    back => write!(out, "{}", back.row(false, 1, 0))?,
+                }
+            } else {
+                let character = CardUnicodeViewer::character(card);
+                write!(out, "{}", paint(card, character, &self.theme))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether the locale is UTF-8, taking the first of `LC_ALL`, `LC_CTYPE` and `LANG` that is
+/// set, as POSIX does.
+fn locale_is_utf8() -> bool {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .is_some_and(|locale| {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+/// Returns whether the terminal looks able to show the playing card characters: it must be in a
+/// UTF-8 locale, and not the Linux console or a dumb terminal, whose fonts never have them. A
+/// terminal cannot be asked which characters its font has, so this is a best guess.
+fn shows_card_characters() -> bool {
+    let term = env::var("TERM").unwrap_or_default();
+    locale_is_utf8() && !matches!(term.as_str(), "linux" | "dumb" | "vt100" | "vt220")
+}
+
+/// The names of the viewers that can be chosen with `--view`, in the config file or in the table
+/// settings.
+pub const VIEWER_NAMES: [&str; 8] = [
+    "glyph", "alpha", "ascii", "plain", "box", "unicode", "spoken", "auto",
+];
+
+/// Returns the cards of `hand` as drawn by `viewer`, to be written as part of a line.
+pub fn draw_hand(hand: &Hand, viewer: &dyn Renderer) -> io::Result<String> {
+    let mut drawn = Vec::new();
+    viewer.render(hand, &mut drawn)?;
+    Ok(String::from_utf8_lossy(&drawn).into_owned())
+}
+
+/// Creates the viewer with the given name, or returns `None` if there is no viewer by that name.
+/// Every viewer but the plain and spoken ones draws the cards in the colours of `theme`, the glyph,
+/// box and auto viewers draw the suits with its symbols, and the viewers that write words take them
+/// from `locale`.
+///
+/// Where the terminal cannot show the playing card characters, `unicode` falls back to the glyph
+/// viewer, or to the ASCII viewer outside a UTF-8 locale.
+pub fn viewer_from_name(name: &str, theme: &Theme, locale: &Locale) -> Option<Box<dyn Renderer>> {
+    let theme = theme.clone();
+    let locale = locale.clone();
+    match name {
+        "glyph" => Some(Box::new(CardGlyphViewer { theme, locale })),
+        "alpha" => Some(Box::new(CardAlphaViewer { theme, locale })),
+        "ascii" => Some(Box::new(CardAsciiViewer { theme, locale })),
+        "plain" => Some(Box::new(CardPlainViewer)),
+        "spoken" => Some(Box::new(CardSpokenViewer { locale })),
+        "box" => Some(Box::new(CardBoxViewer { theme })),
+        "auto" => Some(Box::new(CardAutoViewer {
+            wide: CardBoxViewer {
+                theme: theme.clone(),
+            },
+            compact: CardGlyphViewer { theme, locale },
+        })),
+        "unicode" if shows_card_characters() => Some(Box::new(CardUnicodeViewer { theme })),
+        "unicode" if locale_is_utf8() => Some(Box::new(CardGlyphViewer { theme, locale })),
+        "unicode" => Some(Box::new(CardAsciiViewer { theme, locale })),
+        _ => None,
+    }
+}
+
+/// The names of the table layouts that can be chosen with `--layout` or in the config file: `lines`
+/// writes one line per hand as the round is played, `table` draws the whole table again after
+/// every card and decision, and `spoken` says the table in sentences for screen readers.
+pub const LAYOUT_NAMES: [&str; 4] = ["lines", "table", "spoken", "status"];
+
+/// Creates the table view for the layout with the given name, showing chips with `currency`,
+/// drawing any rules and cards with `theme` and writing the words of `locale`, or returns `None` if
+/// there is no layout by that name. The status line is kept on `status_row` of the terminal, if
+/// given.
+pub fn table_view_from_name(
+    name: &str,
+    currency: CurrencyFormat,
+    theme: &Theme,
+    locale: &Locale,
+    status_row: Option<u16>,
+) -> Option<Box<dyn TableView>> {
+    let locale = locale.clone();
+    match name {
+        "lines" => Some(Box::new(TextTableView { currency, locale })),
+        "table" => Some(Box::new(LayoutTableView {
+            currency,
+            theme: theme.clone(),
+            locale,
+        })),
+        "spoken" => Some(Box::new(SpokenTableView { currency, locale })),
+        "status" => Some(Box::new(StatusTableView {
+            theme: theme.clone(),
+            locale,
+            row: status_row,
+        })),
+        _ => None,
+    }
+}
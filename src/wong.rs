@@ -0,0 +1,267 @@
+use blackjack_core::error::BlackjackError;
+use blackjack_core::game::{
+    Action, Bankroll, BasicStrategy, Classic, CountingSystem, Engine, GameState, Locale, Seat,
+    TableLimits, Wonging, OPPONENT_NAMES, STARTING_BANKROLL,
+};
+use blackjack_core::{PlayerInput, Renderer};
+
+use crate::cli::{Cli, WongArgs};
+use crate::config::Config;
+use crate::keypress::KeypressInput;
+use crate::trainer::ask_play;
+use crate::views::draw_hand;
+
+/// Practices back counting: the player watches the game from behind the table without betting,
+/// keeping the count, and sits in only once the true count reaches `args.enter`, playing every
+/// round from then on until it falls below `args.exit`.
+///
+/// `args.others` other players sit at the table throughout, betting the minimum and playing their
+/// hands by basic strategy, so the shoe runs down as it does at a busy table whether the player
+/// watches or plays. While the player watches, their hands and the dealer's are shown after each
+/// round, and Enter deals the next. Once seated, the player bets and plays their own hands. The
+/// true count is only shown as the player sits in or leaves, unless `args.show_count` is set, so
+/// the count is the player's to keep. Entering 'q' at any question stops, and the rounds watched
+/// and played and the chips won or lost are shown at the end. The count is kept by `args.system`,
+/// reckoning the decks left and side counting Aces as the config file says, everything is said
+/// in the config file's locale, and the game is dealt by `--rules` and from a shoe seeded with
+/// `--seed` if given.
+pub fn run(cli: &Cli, args: &WongArgs, config: &Config) -> Result<(), BlackjackError> {
+    let limits = TableLimits::TABLES[0];
+    let bankroll = config.bankroll.unwrap_or(STARTING_BANKROLL);
+    let mut engine = Engine::new(
+        Box::new(Classic),
+        cli.rules.unwrap_or_default(),
+        limits,
+        Bankroll::new(bankroll),
+    );
+    if let Some(seed) = cli.seed {
+        engine.seed(seed);
+    }
+    engine.set_counting_system(CountingSystem::from_name(&args.system).unwrap_or_default());
+    engine.set_deck_estimation(config.deck_estimation());
+    engine.set_ace_side_count(config.ace_side_count);
+    let mut names = OPPONENT_NAMES.to_vec();
+    engine.entropy_mut().shuffle(&mut names);
+    let opponents = names
+        .into_iter()
+        .take(args.others as usize)
+        .map(|name| Seat::new(name, bankroll, Some(Box::new(BasicStrategy))))
+        .collect();
+    engine.seat_opponents(opponents);
+    let wonging = Wonging {
+        enter: args.enter,
+        exit: args.exit,
+    };
+    let viewer = config.viewer();
+    let locale = config.locale();
+    let mut input = KeypressInput;
+    let (mut watched, mut played) = (0, 0);
+    let mut seated = false;
+    let mut last_bet = limits.minimum;
+
+    println!(
+        "{}",
+        locale.message(
+            "wong-start",
+            &[
+                ("system", &engine.counter().system.name),
+                ("enter", &format!("{:+}", wonging.enter)),
+                ("exit", &format!("{:+}", wonging.exit)),
+            ],
+        )
+    );
+    loop {
+        let true_count = format!("{:+.1}", engine.counter().betting_count());
+        let plays = wonging.plays(seated, engine.counter().betting_count());
+        match (seated, plays) {
+            (false, true) => println!(
+                "{}",
+                locale.message("wong-sit-in", &[("count", &true_count)])
+            ),
+            (true, false) => println!(
+                "{}",
+                locale.message("wong-leave", &[("count", &true_count)])
+            ),
+            _ => {}
+        }
+        seated = plays;
+
+        if !seated {
+            engine.step(Action::SitOut(0))?;
+            watched += 1;
+            show_opponents(&engine, viewer.as_ref(), &locale)?;
+            println!(
+                "{}",
+                locale.message(
+                    "wong-dealer",
+                    &[
+                        ("cards", &draw_hand(engine.dealer_hand(), viewer.as_ref())?),
+                        ("total", &locale.value(engine.dealer_hand().value())),
+                    ],
+                )
+            );
+            if args.show_count {
+                let count = format!("{:+.1}", engine.counter().true_count());
+                println!(
+                    "{}",
+                    locale.message("wong-true-count", &[("count", &count)])
+                );
+            }
+            println!("{}", locale.text("wong-next"));
+            if input.read_choice()?.trim() == "q" {
+                break;
+            }
+            continue;
+        }
+
+        if engine.bankroll().balance() < limits.minimum {
+            println!("{}", locale.text("wong-cannot-cover"));
+            break;
+        }
+        let Some(bet) = ask_bet(&mut input, &engine, last_bet, &locale)? else {
+            break;
+        };
+        let mut state = match engine.step(Action::Bet(bet)) {
+            Ok(state) => state,
+            Err(error) => {
+                println!("{}", locale.error(&error));
+                continue;
+            }
+        };
+        last_bet = bet;
+        played += 1;
+        while !matches!(state, GameState::Resolved { .. }) {
+            if state == GameState::DealerPlaying {
+                state = engine.step(Action::Continue)?;
+                continue;
+            }
+            let view = engine.view();
+            let Some(hand) = &view.hand else {
+                state = engine.step(Action::KeepHands)?;
+                continue;
+            };
+            println!(
+                "{}",
+                locale.message(
+                    "wong-hand",
+                    &[
+                        ("dealer", &draw_hand(engine.dealer_hand(), viewer.as_ref())?),
+                        ("hand", &draw_hand(hand, viewer.as_ref())?),
+                        ("total", &locale.value(hand.value())),
+                    ],
+                )
+            );
+            let Some(action) = ask_play(&mut input, &view.legal_actions, &locale)? else {
+                return summary(&engine, watched, played, bankroll, &locale);
+            };
+            state = engine.step(action)?;
+        }
+        if let GameState::Resolved { net, .. } = state {
+            show_opponents(&engine, viewer.as_ref(), &locale)?;
+            let result = match net {
+                0 => locale.text("wong-push"),
+                net if net < 0 => locale.message("wong-lose", &[("chips", &-net)]),
+                net => locale.message("wong-win", &[("chips", &net)]),
+            };
+            println!(
+                "{}",
+                locale.message(
+                    "wong-round",
+                    &[
+                        ("cards", &draw_hand(engine.dealer_hand(), viewer.as_ref())?),
+                        ("total", &locale.value(engine.dealer_hand().value())),
+                        ("result", &result),
+                        ("balance", &engine.bankroll().balance()),
+                    ],
+                )
+            );
+        }
+    }
+    summary(&engine, watched, played, bankroll, &locale)
+}
+
+/// Shows the hands the other players at the table finished the round with, drawn with `viewer`.
+fn show_opponents(
+    engine: &Engine,
+    viewer: &dyn Renderer,
+    locale: &Locale,
+) -> Result<(), BlackjackError> {
+    let table = engine.table();
+    for (_, seat) in table
+        .seats
+        .iter()
+        .enumerate()
+        .filter(|(seat, _)| *seat != table.player)
+    {
+        for player_hand in &seat.hands {
+            println!(
+                "{}",
+                locale.message(
+                    "wong-opponent",
+                    &[
+                        ("name", &seat.name),
+                        ("cards", &draw_hand(&player_hand.hand, viewer)?),
+                        ("total", &locale.value(player_hand.hand.value())),
+                    ],
+                )
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Asks for a bet within the table limits until one is entered, Enter betting `last_bet` again,
+/// or returns `None` if the player enters 'q'.
+fn ask_bet(
+    input: &mut dyn PlayerInput,
+    engine: &Engine,
+    last_bet: u32,
+    locale: &Locale,
+) -> Result<Option<u32>, BlackjackError> {
+    let limits = engine.limits();
+    loop {
+        println!(
+            "{}",
+            locale.message(
+                "wong-bet-prompt",
+                &[
+                    ("minimum", &limits.minimum),
+                    ("maximum", &limits.maximum),
+                    ("last", &last_bet),
+                ],
+            )
+        );
+        let answer = input.read_choice()?;
+        match answer.trim() {
+            "q" => return Ok(None),
+            "" => return Ok(Some(last_bet)),
+            answer => match answer.parse() {
+                Ok(bet) => return Ok(Some(bet)),
+                Err(_) => println!("{}", locale.text("invalid-amount")),
+            },
+        }
+    }
+}
+
+/// Prints how many rounds were watched and played, and the chips won or lost from `bankroll`.
+fn summary(
+    engine: &Engine,
+    watched: u32,
+    played: u32,
+    bankroll: u32,
+    locale: &Locale,
+) -> Result<(), BlackjackError> {
+    let net = engine.bankroll().balance() as i64 - bankroll as i64;
+    println!(
+        "{}",
+        locale.message(
+            "wong-summary",
+            &[
+                ("watched", &watched),
+                ("played", &played),
+                ("net", &format!("{:+}", net)),
+            ],
+        )
+    );
+    Ok(())
+}